@@ -0,0 +1,203 @@
+//! This module contains the code
+//! for the solution of the first day challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/1).
+use crate::generate::lines;
+use crate::{AocError, Part, Result, Rng, Sample, Solution, Streamer};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+/// The solution to the first day's challenges.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        day_1(input, 2, 2020)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        day_1(input, 3, 2020)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(expense_report(rng, size))
+    }
+
+    fn streamer(part: Part) -> Option<Streamer> {
+        Some(match part {
+            Part::One => |lines| Ok(day_1_stream(crate::stream_data(lines), 2, 2020)?.to_string()),
+            Part::Two => |lines| Ok(day_1_stream(crate::stream_data(lines), 3, 2020)?.to_string()),
+        })
+    }
+}
+
+/// The solution to task 1 of day 1
+///
+/// Task: Find the combination of `elements_to_sum` values whose sum
+/// is equal to `sum_target` and return their product.
+///
+/// The input values are stored in the vector `data`.
+pub fn day_1(data: &[u32], elements_to_sum: usize, sum_target: u32) -> Result<u32> {
+    day_1_stream(data.iter().copied().map(Ok), elements_to_sum, sum_target)
+}
+
+/// Like [`day_1`], but the `entries` are processed one at a time as they
+/// are read, and reading stops as soon as the combination is complete.
+///
+/// For each entry, the combinations of fewer entries read before it are
+/// looked up by their sum, so only the sums of up to `elements_to_sum - 1`
+/// entries are kept.
+pub fn day_1_stream<I>(entries: I, elements_to_sum: usize, sum_target: u32) -> Result<u32>
+where
+    I: IntoIterator<Item = Result<u32>>,
+{
+    if elements_to_sum == 0 && sum_target == 0 {
+        return Ok(1);
+    }
+    // The products of the combinations of `i` entries, by their sum, at index `i`.
+    let mut products = vec![BTreeMap::new(); elements_to_sum.max(1)];
+    products[0].insert(0, 1);
+    for entry in entries {
+        let entry = entry?;
+        if elements_to_sum > 0 {
+            let rest = sum_target.checked_sub(entry);
+            if let Some(product) = rest.and_then(|rest| products[elements_to_sum - 1].get(&rest)) {
+                return Ok(product * entry);
+            }
+        }
+        for size in (1..elements_to_sum.max(1)).rev() {
+            let extended = products[size - 1]
+                .iter()
+                .filter_map(|(&sum, &product)| {
+                    let sum = sum.checked_add(entry).filter(|&sum| sum <= sum_target)?;
+                    Some((sum, product * entry))
+                })
+                .collect::<Vec<_>>();
+            for (sum, product) in extended {
+                products[size].entry(sum).or_insert(product);
+            }
+        }
+    }
+    Err(AocError::unsolvable(format!(
+        "no {} entries sum to {}",
+        elements_to_sum, sum_target
+    )))
+}
+
+/// The smallest entry of the pair and the triple planted in an [`expense_report`].
+const MIN_PLANTED: u32 = 600;
+
+/// The largest number of entries of an [`expense_report`].
+const MAX_ENTRIES: usize = 600;
+
+/// A random expense report of `size` entries, in which exactly one pair
+/// and exactly one triple of entries sum to 2020.
+///
+/// The planted entries are at least [`MIN_PLANTED`] and all others are drawn
+/// from `2021 - MIN_PLANTED..2020`, such that any pair or triple involving
+/// one of the others sums to more than 2020 or needs an entry below
+/// [`MIN_PLANTED`]. This bounds the size to [`MAX_ENTRIES`].
+pub fn expense_report(rng: &mut Rng, size: usize) -> Sample {
+    let sums = |entries: &[u32], count| {
+        entries
+            .iter()
+            .combinations(count)
+            .filter(|v| v.iter().copied().sum::<u32>() == 2020)
+            .count()
+    };
+    let (pair, triple) = loop {
+        let low = MIN_PLANTED as usize;
+        let a = rng.range(low..=2020 - low) as u32;
+        let b = rng.range(low..=2020 - 2 * low) as u32;
+        let c = rng.range(low..=2020 - low - b as usize) as u32;
+        let pair = [a, 2020 - a];
+        let triple = [b, c, 2020 - b - c];
+        let planted = pair.iter().chain(&triple).copied().collect::<Vec<_>>();
+        if planted.iter().unique().count() == 5 && sums(&planted, 2) == 1 && sums(&planted, 3) == 1
+        {
+            break (pair, triple);
+        }
+    };
+
+    let mut others = (2021 - MIN_PLANTED..2020).collect::<Vec<_>>();
+    rng.shuffle(&mut others);
+    let mut entries = pair.iter().chain(&triple).copied().collect::<Vec<_>>();
+    entries.extend(&others[..size.clamp(5, MAX_ENTRIES) - 5]);
+    rng.shuffle(&mut entries);
+
+    Sample::new(lines(&entries))
+        .with_answer(Part::One, pair.iter().product::<u32>())
+        .with_answer(Part::Two, triple.iter().product::<u32>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_1() {
+        assert_eq!(
+            514579,
+            day_1(&[1721, 979, 366, 299, 675, 1456], 2, 2020).unwrap()
+        );
+        assert!(day_1(&[1721, 979, 366], 2, 2020).is_err());
+        assert_eq!(
+            day_1(&[1721, 979, 366, 299, 675, 1456], 3, 2020).unwrap(),
+            241861950
+        );
+        assert_eq!(day_1(&[1010, 5, 1010], 2, 2020).unwrap(), 1020100);
+        assert!(day_1(&[1010, 5], 2, 2020).is_err());
+    }
+
+    #[test]
+    fn test_expense_report() {
+        for seed in 0..5 {
+            let sample = expense_report(&mut Rng::new(seed), MAX_ENTRIES);
+            let entries = crate::parse_lines::<u32>(&sample.data).unwrap();
+            assert_eq!(entries.len(), MAX_ENTRIES);
+            assert_eq!(entries.iter().unique().count(), MAX_ENTRIES);
+            // The pairs and triples are counted once, by their largest entry.
+            let (mut pairs, mut triples) = (vec![], vec![]);
+            for (a, b) in entries.iter().tuple_combinations() {
+                let (low, high) = (a.min(b), a.max(b));
+                if low + high == 2020 {
+                    pairs.push(low * high);
+                } else if low + high < 2020 {
+                    let c = 2020 - low - high;
+                    if c > *high && entries.contains(&c) {
+                        triples.push(low * high * c);
+                    }
+                }
+            }
+            assert_eq!(pairs.len(), 1, "seed {}", seed);
+            assert_eq!(triples.len(), 1, "seed {}", seed);
+            assert_eq!(
+                sample.answer(Part::One),
+                Some(pairs[0].to_string().as_str())
+            );
+            assert_eq!(
+                sample.answer(Part::Two),
+                Some(triples[0].to_string().as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn test_day_1_stream() {
+        // The entries after the combination are not read anymore.
+        let entries = vec![Ok(1721), Ok(299), Err(AocError::unsolvable("unreadable"))];
+        assert_eq!(day_1_stream(entries, 2, 2020).unwrap(), 514579);
+
+        let lines = ["1721", "979", "x"].iter().map(Ok);
+        assert!(day_1_stream(crate::stream_data(lines), 2, 2020).is_err());
+    }
+}
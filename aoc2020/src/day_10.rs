@@ -0,0 +1,191 @@
+//! This module contains the code
+//! for the solution of the tenth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/10).
+use crate::generate::lines;
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// The solution to the tenth day's challenges.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(adapter_bag(rng, size))
+    }
+}
+
+/// Compute the product of number of 1-jolt and 3-jolt differences
+/// in a chain connecting all adapters.
+pub fn task_1(data: &[u32]) -> Result<u32> {
+    let acc = data
+        .iter()
+        .sorted()
+        .try_fold((0, 0, 0), |acc, x| match x - acc.2 {
+            1 => Ok((acc.0 + 1, acc.1, *x)),
+            4.. => Err(AocError::unsolvable(format!(
+                "no adapter connects {} jolts to {} jolts",
+                acc.2, x
+            ))),
+            _ => Ok((acc.0, acc.1 + 1, *x)), // Assume that only difference 1 and 2 can appear
+        })?;
+    // Last one is always 3 jolts higher
+    Ok(acc.0 * (acc.1 + 1))
+}
+
+/// Compute the number of valid adaptor chains.
+pub fn task_2(data: &[u32]) -> Result<usize> {
+    let sorted = data.iter().sorted().copied().collect::<Vec<_>>();
+    Ok(valid_next(0, 0, &sorted, &mut HashMap::new()))
+}
+
+/// Compute the number of valid adaptor combinations that can be
+/// obtained starting from the index `start` in `data`. The previous
+/// value `prev` is in general the entry in `data` with index `start-1`,
+/// except for the first call where it is `0`, the charging outlet.
+/// This parameter could be omitted by including 0 in `data` manually.
+fn valid_next(prev: u32, start: usize, data: &[u32], cache: &mut HashMap<u32, usize>) -> usize {
+    if start == data.len() {
+        return 1;
+    }
+    if cache.contains_key(&prev) {
+        return cache[&prev];
+    }
+
+    let sum = (start..data.len())
+        .filter(|&index| data[index] - prev <= 3)
+        .map(|index| valid_next(data[index], index + 1, data, cache))
+        .sum();
+
+    cache.insert(prev, sum);
+    sum
+}
+
+/// A random bag of `size` adapters, which form a chain with differences of
+/// one or three jolts.
+pub fn adapter_bag(rng: &mut Rng, size: usize) -> Sample {
+    let mut adapters = vec![];
+    let (mut ones, mut threes) = (0, 0);
+    // The last joltages of the chain, starting with the outlet, along with
+    // the number of arrangements that reach them.
+    let mut last = vec![(0, 1usize)];
+    while adapters.len() < size.max(1) {
+        let arrangements = |joltage: u32| {
+            last.iter()
+                .filter(|&&(j, _)| joltage - j <= 3)
+                .try_fold(0usize, |sum, &(_, count)| sum.checked_add(count))
+        };
+        let joltage = last[last.len() - 1].0;
+        let run = last
+            .windows(2)
+            .rev()
+            .take_while(|w| w[1].0 - w[0].0 == 1)
+            .count();
+        // Long runs of single steps multiply the arrangements, so they are
+        // limited, also to avoid overflows.
+        let (step, count) = match arrangements(joltage + 1) {
+            Some(count) if run < 4 && rng.chance(0.7) => (1, count),
+            _ => (3, last[last.len() - 1].1),
+        };
+        match step {
+            1 => ones += 1,
+            _ => threes += 1,
+        }
+        adapters.push(joltage + step);
+        last.push((joltage + step, count));
+        if last.len() > 5 {
+            last.remove(0);
+        }
+    }
+    let arrangements = last[last.len() - 1].1;
+    rng.shuffle(&mut adapters);
+
+    Sample::new(lines(&adapters))
+        .with_answer(Part::One, ones * (threes + 1))
+        .with_answer(Part::Two, arrangements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_10_task_1() {
+        let input = [
+            28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35,
+            8, 17, 7, 9, 4, 2, 34, 10, 3,
+        ];
+        assert_eq!(task_1(&input).unwrap(), 220);
+        assert!(task_1(&[1, 2, 7]).is_err());
+    }
+
+    #[test]
+    fn test_valid_next() {
+        let mut input_1 = [16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
+        input_1.sort();
+        assert_eq!(valid_next(0, 0, &input_1, &mut HashMap::new()), 8);
+
+        let mut input_2 = [
+            28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35,
+            8, 17, 7, 9, 4, 2, 34, 10, 3,
+        ];
+        input_2.sort();
+        assert_eq!(valid_next(0, 0, &input_2, &mut HashMap::new()), 19208);
+    }
+
+    /// The number of chains by enumerating all subsets of the adapters, and
+    /// checking whether they lead from the outlet to the highest adapter in
+    /// steps of at most three jolts.
+    fn oracle(data: &[u32]) -> usize {
+        let sorted = data.iter().sorted().copied().collect::<Vec<_>>();
+        (0..1usize << sorted.len())
+            .filter(|mask| {
+                let chain = (0..sorted.len())
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(|i| sorted[i])
+                    .collect::<Vec<_>>();
+                chain.last() == sorted.last()
+                    && std::iter::once(0)
+                        .chain(chain.iter().copied())
+                        .tuple_windows()
+                        .all(|(a, b)| b - a <= 3)
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_day_10_oracle() {
+        let mut rng = Rng::new(10);
+        for _ in 0..300 {
+            let highest = rng.range(0..=20) as u32;
+            let mut joltages = (1..=highest)
+                .filter(|_| rng.chance(0.7))
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut joltages);
+            assert_eq!(
+                task_2(&joltages).unwrap(),
+                oracle(&joltages),
+                "{:?}",
+                joltages
+            );
+        }
+    }
+}
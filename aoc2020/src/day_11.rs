@@ -0,0 +1,315 @@
+//! This module contains the code
+//! for the solution of the eleventh day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/11).
+use crate::animate::{self, Frame, Frames, Glyph};
+use crate::automaton::{Automaton, Dense, Graph, Life};
+use crate::grid::{Grid, Pos, DIRECTIONS_8};
+use crate::render::Scene;
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+use std::convert::TryFrom;
+
+/// The solution to the eleventh day's challenges.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1_2(input, &Strategy::Adjacent, 4)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_1_2(input, &Strategy::Visible, 5)
+    }
+
+    fn render(input: &Self::Input, part: Part) -> Result<Option<Scene>> {
+        let (strategy, threshold) = rules(part);
+        let room = Room::try_from(input.as_str())?;
+        let seating = room.settle(&strategy, threshold)?;
+        Ok(Some(room.scene(&seating)))
+    }
+
+    fn animate(input: &Self::Input, part: Part) -> Result<Option<Frames>> {
+        let (strategy, threshold) = rules(part);
+        let room = Room::try_from(input.as_str())?;
+        let seating = room.seating(&strategy, threshold);
+        Ok(Some(animate::simulate(
+            (room, seating),
+            |(_, seating)| seating.generation() < MAX_ROUNDS && seating.step(),
+            |(room, seating)| room.frame(seating),
+        )))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(seat_layout(rng, size))
+    }
+}
+
+/// The strategy and the threshold of occupied seats of the given `part`.
+fn rules(part: Part) -> (Strategy, u32) {
+    match part {
+        Part::One => (Strategy::Adjacent, 4),
+        Part::Two => (Strategy::Visible, 5),
+    }
+}
+
+/// The maximum number of rounds until the seats settle.
+const MAX_ROUNDS: usize = 10_000;
+
+/// The seats as a cellular automaton, whose alive cells are the occupied seats.
+type Seating = Automaton<Graph<Pos>, Dense, Life>;
+
+/// Count the number of occupied seats after the equilibrium state has been
+/// reached and the statuses of all seats remain constant, i.e. no people move
+/// around. One can specify the strategy to use for defining which seats shall
+/// be considered in the updating process and what the threshold for surrounding
+/// occupied seats is.
+pub fn task_1_2(data: &str, strategy: &Strategy, threshold: u32) -> Result<usize> {
+    let seating = Room::try_from(data)?.settle(strategy, threshold)?;
+    Ok(seating.population())
+}
+
+/// Choose whether to consider adjacent occupied seats or visible occupied seats.
+#[derive(Debug)]
+pub enum Strategy {
+    Adjacent,
+    Visible,
+}
+
+/// The different states a spot can have.
+#[derive(Debug, Clone, PartialEq)]
+enum Spot {
+    Floor,
+    OccupiedSeat,
+    EmptySeat,
+}
+
+/// A `Room` is characterized by the initial state of all its spots.
+#[derive(Debug)]
+struct Room {
+    spots: Grid<Spot>,
+}
+
+// Construct a `Room` from a `&str`, rejecting unknown spots and ragged rows.
+impl TryFrom<&str> for Room {
+    type Error = AocError;
+
+    fn try_from(string: &str) -> Result<Self> {
+        let spots = Grid::parse(string, |c| match c {
+            '.' => Some(Spot::Floor),
+            'L' => Some(Spot::EmptySeat),
+            '#' => Some(Spot::OccupiedSeat),
+            _ => None,
+        })?;
+        Ok(Self { spots })
+    }
+}
+
+impl Room {
+    /// Find the first seat in the direction `dir` starting from the seat located at `coord`,
+    /// looking past the floor.
+    fn visible_seat(&self, coord: Pos, dir: (isize, isize)) -> Option<Pos> {
+        self.spots
+            .ray(coord, dir)
+            .find(|&p| self.spots[p] != Spot::Floor)
+    }
+
+    /// The seats whose occupants are taken into account by the occupant of
+    /// each seat, according to the `strategy`. The floor never changes, so
+    /// it is left out.
+    fn neighbourhood(&self, strategy: &Strategy) -> Graph<Pos> {
+        Graph::new(
+            self.spots
+                .iter()
+                .filter(|(_, spot)| **spot != Spot::Floor)
+                .map(|(pos, _)| {
+                    let seats = match strategy {
+                        Strategy::Adjacent => self
+                            .spots
+                            .neighbours_8(pos)
+                            .filter(|&p| self.spots[p] != Spot::Floor)
+                            .collect(),
+                        Strategy::Visible => DIRECTIONS_8
+                            .iter()
+                            .filter_map(|&dir| self.visible_seat(pos, dir))
+                            .collect(),
+                    };
+                    (pos, seats)
+                }),
+        )
+    }
+
+    /// The seating process, in which an empty seat is taken if none of the
+    /// seats of its neighbourhood is occupied, and an occupied seat is left
+    /// if at least `threshold` of them are.
+    fn seating(&self, strategy: &Strategy, threshold: u32) -> Seating {
+        let survival = (0..threshold as usize).collect::<Vec<_>>();
+        let occupied = self
+            .spots
+            .iter()
+            .filter(|(_, spot)| **spot == Spot::OccupiedSeat)
+            .map(|(pos, _)| pos);
+        Automaton::dense(
+            self.neighbourhood(strategy),
+            Life::new(&[0], &survival),
+            occupied,
+        )
+    }
+
+    /// Run the seating process until the seats settle.
+    fn settle(&self, strategy: &Strategy, threshold: u32) -> Result<Seating> {
+        let mut seating = self.seating(strategy, threshold);
+        seating
+            .run_until_stable(MAX_ROUNDS)
+            .ok_or_else(|| AocError::unsolvable("the seats never settle"))?;
+        Ok(seating)
+    }
+
+    /// Draw the seat layout in the state of the `seating`, with the empty
+    /// seats in the first color, the occupied ones in the second and the
+    /// floor left to the background.
+    fn scene(&self, seating: &Seating) -> Scene {
+        let spots = self.spots.map(|pos, spot| match spot {
+            Spot::Floor => None,
+            _ if seating.is_alive(pos) => Some(1),
+            _ => Some(0),
+        });
+        Scene::grid(&spots, |&color| color)
+    }
+
+    /// Draw the seat layout in the state of the `seating` as it appears in
+    /// the challenge, with the empty and the occupied seats in the same
+    /// colors as in the [`scene`](Room::scene).
+    fn frame(&self, seating: &Seating) -> Frame {
+        let glyphs = self.spots.map(|pos, spot| match spot {
+            Spot::Floor => Glyph::plain('.'),
+            _ if seating.is_alive(pos) => Glyph::new('#', 1),
+            _ => Glyph::new('L', 0),
+        });
+        let caption = format!(
+            "round {}: {} occupied seats",
+            seating.generation(),
+            seating.population()
+        );
+        Frame::new(caption, glyphs)
+    }
+
+    /// This function is only needed for unit testing, to compare the
+    /// state of the `seating` to the examples provided in the challenge.
+    fn _to_string(&self, seating: &Seating) -> String {
+        self.spots
+            .map(|pos, spot| match spot {
+                Spot::Floor => '.',
+                _ if seating.is_alive(pos) => '#',
+                _ => 'L',
+            })
+            .render(|&c| c)
+    }
+}
+
+/// A random seat layout of `size` rows and columns, with empty seats and floor.
+pub fn seat_layout(rng: &mut Rng, size: usize) -> Sample {
+    let side = size.clamp(1, 200);
+    let layout = Grid::from_fn(side, side, |_| rng.chance(0.85));
+    Sample::new(layout.render(|&seat| if seat { 'L' } else { '.' }) + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::Shape;
+
+    #[test]
+    fn test_room() {
+        let room = Room::try_from("#.LL.L#.##\n#LLLLLL.L#\nL.L.L..L..\n#LLL.LL.L#\n#.LL.LL.LL\n#.LLLL#.##\n..L.L.....\n#LLLLLLLL#\n#.LLLLLL.L\n#.#LLLL.##").unwrap();
+
+        assert_eq!(room.spots.rows(), 10);
+        assert_eq!(room.spots.cols(), 10);
+        let adjacent = room.seating(&Strategy::Adjacent, 4);
+        assert_eq!(adjacent.live_neighbours((0, 0)), 1);
+        assert_eq!(adjacent.live_neighbours((1, 1)), 2);
+        let visible = room.seating(&Strategy::Visible, 5);
+        assert_eq!(visible.live_neighbours((6, 4)), 0);
+        assert_eq!(visible.live_neighbours((0, 0)), 1);
+    }
+
+    #[test]
+    fn test_room_malformed() {
+        assert!(Room::try_from("L.L\nL#x").is_err());
+        assert!(Room::try_from("L.L\nL#").is_err());
+    }
+
+    #[test]
+    fn test_room_update() {
+        let start = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\nL.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL";
+        let room = Room::try_from(start).unwrap();
+        let mut seating = room.seating(&Strategy::Adjacent, 4);
+
+        let iteration_1 = "#.##.##.##\n#######.##\n#.#.#..#..\n####.##.##\n#.##.##.##\n#.#####.##\n..#.#.....\n##########\n#.######.#\n#.#####.##";
+        assert_eq!(&room._to_string(&seating), start);
+        assert!(seating.step());
+
+        assert_eq!(&room._to_string(&seating), iteration_1);
+    }
+
+    #[test]
+    fn test_day_11_task_1() {
+        let start = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\nL.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL";
+        assert_eq!(task_1_2(start, &Strategy::Adjacent, 4).unwrap(), 37);
+    }
+
+    #[test]
+    fn test_day_11_task_2() {
+        let start = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\nL.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL";
+        assert_eq!(task_1_2(start, &Strategy::Visible, 5).unwrap(), 26);
+    }
+
+    #[test]
+    fn test_render() {
+        let start = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\nL.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL";
+        let scene = Day11::render(&start.to_string(), Part::One)
+            .unwrap()
+            .unwrap();
+        assert_eq!((scene.width(), scene.height()), (10.0, 10.0));
+        let colors = scene
+            .shapes()
+            .iter()
+            .map(|shape| match shape {
+                Shape::Rect { color, .. } => *color,
+                _ => panic!("seats are drawn as squares"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(colors.len(), start.matches('L').count());
+        assert_eq!(colors.iter().filter(|&&c| c == 1).count(), 37);
+    }
+
+    #[test]
+    fn test_animate() {
+        let start = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\nL.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL";
+        let frames = Day11::animate(&start.to_string(), Part::One)
+            .unwrap()
+            .unwrap()
+            .collect::<Vec<_>>();
+        // The seats settle after five rounds.
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[0].glyphs.render(|g| g.symbol), start);
+        assert_eq!(frames[1].glyphs[(0, 0)], Glyph::new('#', 1));
+        assert_eq!(frames[5].caption, "round 5: 37 occupied seats");
+    }
+
+    #[test]
+    fn test_find_occupied_in_direction() {
+        let iteration_1 = "#.L#.L#.L#\n#LLLLLL.LL\nL.L.L..#..\n##L#.#L.L#\nL.L#.LL.L#\n#.LLLL#.LL\n..#.L.....\nLLL###LLL#\n#.LLLLL#.L\n#.L#LL#.L#";
+        let room = Room::try_from(iteration_1).unwrap();
+        assert_eq!(room.visible_seat((2, 4), (0, 1)), Some((2, 7)));
+        assert!(room.seating(&Strategy::Visible, 5).is_alive((2, 7)));
+    }
+}
@@ -3,55 +3,152 @@
 //!
 //! The problem formulation for these challenges can
 //! be found [here](https://adventofcode.com/2020/day/12).
-use regex::Regex;
+use crate::generate::lines;
+use crate::parse::{self, Cursor, PResult};
+use crate::render::Scene;
+use crate::{Part, Result, Rng, Sample, Solution, Streamer};
 
-lazy_static::lazy_static! {
-    static ref RE: Regex = Regex::new(r"^(\w)(\d*)$").unwrap();
+/// The solution to the twelfth day's challenges.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<String>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn render(input: &Self::Input, part: Part) -> Result<Option<Scene>> {
+        let points = trajectory(input, part)?
+            .into_iter()
+            .map(|(east, north)| (east, -north))
+            .collect::<Vec<_>>();
+        Ok(Some(Scene::path(&points, 0)))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(navigation_instructions(rng, size))
+    }
+
+    fn streamer(part: Part) -> Option<Streamer> {
+        Some(match part {
+            Part::One => |lines| Ok(task_1_stream(lines)?.to_string()),
+            Part::Two => |lines| Ok(task_2_stream(lines)?.to_string()),
+        })
+    }
 }
 
 /// Compute the Manhattan distance of the ship after performing
 /// all the navigation actions provided in `data`. In this task
 /// the ship is rotated.
-pub fn task_1(data: &[String]) -> isize {
-    let position = data
-        .iter()
-        .map(|l| extract_action(&l))
-        .fold(Ship::new(), |mut ship, action| {
-            ship.apply_action(action);
-            ship
-        });
-
-    position.north_south.abs() + position.east_west.abs()
+pub fn task_1(data: &[String]) -> Result<isize> {
+    task_1_stream(data.iter().map(Ok))
 }
 
 /// Compute the Manhattan distance of the ship after performing
 /// all the navigation actions provided in `data`. In this task
 /// the waypoint is rotated.
-pub fn task_2(data: &[String]) -> isize {
-    let position =
-        data.iter()
-            .map(|l| extract_action(&l))
-            .fold(WayPoint::new(), |mut waypoint, action| {
+pub fn task_2(data: &[String]) -> Result<isize> {
+    task_2_stream(data.iter().map(Ok))
+}
+
+/// Like [`task_1`], but the actions are applied one at a time as the `lines`
+/// are read, and the first malformed line aborts the navigation.
+pub fn task_1_stream<S, I>(lines: I) -> Result<isize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    let position = parse::stream_each_line(lines, extract_action).try_fold(
+        Ship::new(),
+        |mut ship, action| -> Result<Ship> {
+            ship.apply_action(action?);
+            Ok(ship)
+        },
+    )?;
+
+    Ok(position.north_south.abs() + position.east_west.abs())
+}
+
+/// Like [`task_2`], but the actions are applied one at a time as the `lines`
+/// are read, and the first malformed line aborts the navigation.
+pub fn task_2_stream<S, I>(lines: I) -> Result<isize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    let position = parse::stream_each_line(lines, extract_action).try_fold(
+        WayPoint::new(),
+        |mut waypoint, action| -> Result<WayPoint> {
+            waypoint.apply_action(action?);
+            Ok(waypoint)
+        },
+    )?;
+
+    Ok(position.ship_position.0.abs() + position.ship_position.1.abs())
+}
+
+/// The positions `(east, north)` the ship passes through while performing
+/// the navigation actions provided in `data`, as interpreted in the given `part`.
+pub fn trajectory(data: &[String], part: Part) -> Result<Vec<(isize, isize)>> {
+    let actions = parse_actions(data)?;
+    let mut positions = vec![(0, 0)];
+    match part {
+        Part::One => {
+            let mut ship = Ship::new();
+            for action in actions {
+                ship.apply_action(action);
+                positions.push((ship.east_west, ship.north_south));
+            }
+        }
+        Part::Two => {
+            let mut waypoint = WayPoint::new();
+            for action in actions {
                 waypoint.apply_action(action);
-                waypoint
-            });
+                let (north, east) = waypoint.ship_position;
+                positions.push((east, north));
+            }
+        }
+    }
+    positions.dedup();
+    Ok(positions)
+}
 
-    position.ship_position.0.abs() + position.ship_position.1.abs()
+/// Parse the actions of all the lines of the input.
+fn parse_actions(data: &[String]) -> Result<Vec<Action>> {
+    parse::each_line(data, extract_action)
 }
 
 /// Extract the action from its string representation in the input file.
-fn extract_action(string: &str) -> Action {
-    let captures = RE.captures(string).unwrap();
-    let amount = captures.get(2).unwrap().as_str().parse::<isize>().unwrap();
-    match captures.get(1).unwrap().as_str() {
-        "N" => Action::N(amount),
-        "S" => Action::S(amount),
-        "E" => Action::E(amount),
-        "W" => Action::W(amount),
-        "L" => Action::L(amount),
-        "R" => Action::R(amount),
-        _ => Action::F(amount),
+///
+/// Turns need to be a multiple of 90 degrees.
+fn extract_action(cursor: &mut Cursor) -> PResult<Action> {
+    let turn = matches!(cursor.peek(), Some('L') | Some('R'));
+    let action = cursor.keyword::<fn(isize) -> Action>(&[
+        ("N", Action::N),
+        ("S", Action::S),
+        ("E", Action::E),
+        ("W", Action::W),
+        ("L", Action::L),
+        ("R", Action::R),
+        ("F", Action::F),
+    ])?;
+    let start = *cursor;
+    let amount = cursor.int::<u32>()?;
+    if turn && amount % 90 != 0 {
+        return Err(start.error("a multiple of 90 degrees"));
     }
+    Ok(action(amount as isize))
 }
 
 /// The direction in which the ship can face.
@@ -183,20 +280,36 @@ impl WayPoint {
     }
 }
 
+/// A random list of `size` navigation instructions.
+pub fn navigation_instructions(rng: &mut Rng, size: usize) -> Sample {
+    let instructions = (0..size).map(|_| match rng.below(10) {
+        0..=3 => format!("F{}", rng.range(1..=100)),
+        4..=7 => format!("{}{}", rng.pick(&['N', 'S', 'E', 'W']), rng.range(1..=5)),
+        _ => format!("{}{}", rng.pick(&['L', 'R']), 90 * rng.range(1..=3)),
+    });
+    Sample::new(lines(instructions))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_extract_action() {
-        assert_eq!(extract_action("N4"), Action::N(4));
-        assert_eq!(extract_action("S4"), Action::S(4));
-        assert_eq!(extract_action("E5"), Action::E(5));
-        assert_eq!(extract_action("W3"), Action::W(3));
-        assert_eq!(extract_action("L180"), Action::L(180));
-        assert_eq!(extract_action("R90"), Action::R(90));
-        assert_eq!(extract_action("R90"), Action::R(90));
-        assert_eq!(extract_action("F96"), Action::F(96));
+        let action = |string| parse::parse(string, extract_action);
+        assert_eq!(action("N4"), Ok(Action::N(4)));
+        assert_eq!(action("S4"), Ok(Action::S(4)));
+        assert_eq!(action("E5"), Ok(Action::E(5)));
+        assert_eq!(action("W3"), Ok(Action::W(3)));
+        assert_eq!(action("L180"), Ok(Action::L(180)));
+        assert_eq!(action("R90"), Ok(Action::R(90)));
+        assert_eq!(action("R90"), Ok(Action::R(90)));
+        assert_eq!(action("F96"), Ok(Action::F(96)));
+        assert_eq!(
+            action("R45").unwrap_err().to_string(),
+            "column 2: expected a multiple of 90 degrees"
+        );
+        assert!(action("X3").is_err());
     }
 
     #[test]
@@ -299,7 +412,7 @@ mod tests {
             "R90".to_string(),
             "F11".to_string(),
         ];
-        assert_eq!(task_1(&input), 25);
+        assert_eq!(task_1(&input).unwrap(), 25);
     }
 
     #[test]
@@ -311,6 +424,40 @@ mod tests {
             "R90".to_string(),
             "F11".to_string(),
         ];
-        assert_eq!(task_2(&input), 286);
+        assert_eq!(task_2(&input).unwrap(), 286);
+    }
+
+    #[test]
+    fn test_day_12_stream() {
+        let data = "F10\nN3\nF7\nR90\nF11\n";
+        assert_eq!(
+            task_1_stream(crate::read_lines(data.as_bytes())).unwrap(),
+            25
+        );
+        assert_eq!(
+            task_2_stream(crate::read_lines(data.as_bytes())).unwrap(),
+            286
+        );
+        assert!(task_1_stream(["F10", "R45"].iter().map(Ok)).is_err());
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let input = [
+            "F10".to_string(),
+            "N3".to_string(),
+            "F7".to_string(),
+            "R90".to_string(),
+            "F11".to_string(),
+        ];
+        assert_eq!(
+            trajectory(&input, Part::One).unwrap(),
+            vec![(0, 0), (10, 0), (10, 3), (17, 3), (17, -8)]
+        );
+        assert_eq!(
+            trajectory(&input, Part::Two).unwrap(),
+            vec![(0, 0), (100, 10), (170, 38), (214, -72)]
+        );
+        assert!(Day12::render(&input.to_vec(), Part::Two).unwrap().is_some());
     }
 }
@@ -0,0 +1,223 @@
+//! This module contains the code
+//! for the solution of the thirteenth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/13).
+use crate::math::{modulo, solve_congruences};
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+
+/// The solution to the thirteenth day's challenges.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = i128;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(bus_notes(rng, size))
+    }
+}
+
+/// Compute the product of bus number and waiting time for the
+/// bus that minimizes waiting time.
+pub fn task_1(data: &str) -> Result<usize> {
+    let (time, buses) = parse_notes(data)?;
+
+    let time: usize = time
+        .parse()
+        .map_err(|_| AocError::malformed_in(data, time, "expected the earliest departure time"))?;
+    let result = buses
+        .iter()
+        .filter_map(|&(_, x)| x)
+        .map(|x| {
+            (
+                x,
+                (time / x) * x + if !time.is_multiple_of(x) { x } else { 0 } - time,
+            )
+        })
+        .min_by(|x, y| x.1.cmp(&y.1))
+        .ok_or_else(|| AocError::unsolvable("there are no buses in service"))?;
+    Ok(result.0 * result.1)
+}
+
+/// Find the earliest timestamp at which the first bus departs in such a way that
+/// each subsequent bus departs on the subsequent minute.
+pub fn task_2(data: &str) -> Result<i128> {
+    let (_, buses) = parse_notes(data)?;
+    solve_congruences(
+        buses
+            .iter()
+            .filter_map(|&(i, x)| x.map(|val| (modulo(-(i as i128), val as i128), val as i128))),
+    )
+    .map(|c| c.0)
+    .ok_or_else(|| AocError::unsolvable("the buses never depart at consecutive offsets"))
+}
+
+/// A bus given by its position in the list of buses and its id, if it is in service.
+type Bus = (usize, Option<usize>);
+
+/// Split the notes into the earliest departure time and the list of buses.
+///
+/// Each bus is given by its position in the list together with its id, where
+/// buses that are out of service (`x`) do not have an id.
+fn parse_notes(data: &str) -> Result<(&str, Vec<Bus>)> {
+    let split = data.split_whitespace().collect::<Vec<_>>();
+    match split.len() {
+        0 => {
+            return Err(AocError::unexpected_end(
+                data,
+                "expected the earliest departure time",
+            ))
+        }
+        1 => return Err(AocError::unexpected_end(data, "expected the list of buses")),
+        2 => (),
+        _ => {
+            return Err(AocError::malformed_in(
+                data,
+                split[2],
+                "expected only the departure time and the list of buses",
+            ))
+        }
+    }
+
+    let buses = split[1]
+        .split(',')
+        .enumerate()
+        .map(|(i, s)| match s {
+            "x" => Ok((i, None)),
+            _ => match s.parse::<usize>() {
+                Ok(id) if id > 0 => Ok((i, Some(id))),
+                _ => Err(AocError::malformed_in(
+                    data,
+                    split[1],
+                    format!("invalid bus id `{}`", s),
+                )),
+            },
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((split[0], buses))
+}
+
+/// Random notes on `size` buses in service, at most twelve, whose ids are
+/// distinct primes.
+pub fn bus_notes(rng: &mut Rng, size: usize) -> Sample {
+    let mut ids = vec![
+        7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    ];
+    rng.shuffle(&mut ids);
+    ids.truncate(size.clamp(1, 12));
+
+    // Start with the answer to the second part, from which the position of
+    // each bus in the list follows.
+    let period = ids.iter().map(|&id| id as i128).product::<i128>();
+    let (timestamp, positions) = loop {
+        let timestamp = rng.range(1..=(period - 1).min(1 << 60) as usize);
+        let positions = ids
+            .iter()
+            .map(|&id| (id - timestamp % id) % id)
+            .collect::<Vec<_>>();
+        let mut distinct = positions.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() == positions.len() {
+            break (timestamp, positions);
+        }
+    };
+    let mut buses = vec![None; positions.iter().max().unwrap() + 1];
+    for (&id, &position) in ids.iter().zip(&positions) {
+        buses[position] = Some(id);
+    }
+
+    let earliest = rng.range(1000..=1_000_000);
+    let (id, wait) = buses
+        .iter()
+        .flatten()
+        .map(|&id| (id, (id - earliest % id) % id))
+        .min_by_key(|&(_, wait)| wait)
+        .unwrap();
+    let buses = buses
+        .iter()
+        .map(|bus| bus.map_or("x".to_string(), |id| id.to_string()))
+        .collect::<Vec<_>>();
+    Sample::new(format!("{}\n{}\n", earliest, buses.join(",")))
+        .with_answer(Part::One, id * wait)
+        .with_answer(Part::Two, timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_13_task_1() {
+        let input = "939\n7,13,x,x,59,x,31,19";
+        assert_eq!(task_1(input).unwrap(), 295);
+        assert!(task_1("939").is_err());
+        assert!(task_1("939\nx,x").is_err());
+        assert!(task_1("939\n7,y,13").is_err());
+    }
+
+    #[test]
+    fn test_day_13_task_2() {
+        assert_eq!(task_2("939\n7,13,x,x,59,x,31,19").unwrap(), 1068781);
+        assert_eq!(task_2("1\n17,x,13,19").unwrap(), 3417);
+        assert_eq!(task_2("1\n67,7,59,61").unwrap(), 754018);
+        assert_eq!(task_2("1\n67,x,7,59,61").unwrap(), 779210);
+        assert_eq!(task_2("1\n67,7,x,59,61").unwrap(), 1261476);
+        assert_eq!(task_2("1\n1789,37,47,1889").unwrap(), 1202161486);
+        assert_eq!(task_2("1\n6,x,4").unwrap(), 6);
+        assert!(task_2("1\n6,4").is_err());
+    }
+
+    /// The earliest timestamp by trying them one by one up to the least common
+    /// multiple of the ids, after which the departures repeat.
+    fn oracle(buses: &[Option<i128>]) -> Option<i128> {
+        let period = buses
+            .iter()
+            .flatten()
+            .fold(1, |lcm, &id| lcm * id / crate::math::gcd(lcm, id));
+        (0..period).find(|t| {
+            buses
+                .iter()
+                .zip(0..)
+                .all(|(bus, i)| bus.is_none_or(|id| (t + i) % id == 0))
+        })
+    }
+
+    #[test]
+    fn test_day_13_oracle() {
+        let mut rng = Rng::new(13);
+        for _ in 0..500 {
+            let buses = (0..rng.range(1..=6))
+                .map(|_| match rng.chance(0.3) {
+                    true => None,
+                    false => Some(rng.range(1..=12) as i128),
+                })
+                .collect::<Vec<_>>();
+            let notes = buses
+                .iter()
+                .map(|bus| bus.map_or("x".to_string(), |id| id.to_string()))
+                .collect::<Vec<_>>()
+                .join(",");
+            let data = format!("939\n{}", notes);
+            match oracle(&buses) {
+                Some(timestamp) => assert_eq!(task_2(&data).unwrap(), timestamp, "{}", notes),
+                None => assert!(task_2(&data).is_err(), "{}", notes),
+            }
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! This module contains the code
+//! for the solution of the fourteenth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/14).
+use crate::generate::lines;
+use crate::parse::{self, Cursor, PResult};
+use crate::{AocError, Part, Result, Rng, Sample, Solution, Streamer};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// The solution to the fourteenth day's challenges.
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(initialization_program(rng, size))
+    }
+
+    fn streamer(part: Part) -> Option<Streamer> {
+        Some(match part {
+            Part::One => |lines| Ok(task_1_stream(lines)?.to_string()),
+            Part::Two => |lines| Ok(task_2_stream(lines)?.to_string()),
+        })
+    }
+}
+
+/// Compute the sum of the entries present in the memory after all writing operations.
+pub fn task_1(data: &[String]) -> Result<usize> {
+    task_1_stream(data.iter().map(Ok))
+}
+
+/// The most floating bits a mask may have in the second task, where a write
+/// goes to `2^n` addresses if the mask has `n` floating bits.
+const MAX_FLOATING_BITS: usize = 12;
+
+/// Compute the sum of the entries present in the memory after all writing operations.
+pub fn task_2(data: &[String]) -> Result<usize> {
+    task_2_stream(data.iter().map(Ok))
+}
+
+/// Like [`task_1`], but the program is run one line at a time as the `lines`
+/// are read, and the first malformed line aborts it.
+pub fn task_1_stream<S, I>(lines: I) -> Result<usize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    run_program(lines, |memory, mask, address, value| {
+        memory.insert(address, apply_mask(mask, value));
+        Ok(())
+    })
+}
+
+/// Like [`task_2`], but the program is run one line at a time as the `lines`
+/// are read, and the first malformed line aborts it.
+pub fn task_2_stream<S, I>(lines: I) -> Result<usize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    run_program(lines, |memory, mask, address, value| {
+        check_floating_bits(mask)?;
+        for add in get_addresses(mask, address) {
+            memory.insert(add, value);
+        }
+        Ok(())
+    })
+}
+
+/// Run the program given by the `lines` as they are read, where `write`
+/// performs a memory write with the current mask, and compute the sum of
+/// the entries present in the memory at the end.
+fn run_program<S, I, F>(lines: I, mut write: F) -> Result<usize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+    F: FnMut(&mut HashMap<usize, usize>, &str, usize, usize) -> Result<()>,
+{
+    let mut mask: Option<String> = None;
+    let mut memory = HashMap::new();
+    for (i, line) in lines.into_iter().enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+        let malformed = |reason: String| AocError::malformed(i + 1, line, reason);
+        match parse::parse(line, parse_input).map_err(|err| malformed(err.to_string()))? {
+            Input::Mask(new_mask) => mask = Some(new_mask.to_string()),
+            Input::Memory((address, value)) => match &mask {
+                Some(mask) => write(&mut memory, mask, address, value)?,
+                None => {
+                    let reason = "expected a mask before the first memory write";
+                    return Err(malformed(reason.to_string()));
+                }
+            },
+        }
+    }
+    Ok(memory.values().sum())
+}
+
+/// Apply the `mask` to the `value`, overwriting its bits where the mask is not `X`.
+fn apply_mask(mask: &str, value: usize) -> usize {
+    usize::from_str_radix(
+        &format!("{:0>36b}", value)
+            .chars()
+            .zip(mask.chars())
+            .map(|(c, mask)| if mask == 'X' { c } else { mask })
+            .collect::<String>(),
+        2,
+    )
+    .unwrap()
+}
+
+/// Make sure the `mask` has at most [`MAX_FLOATING_BITS`] floating bits.
+fn check_floating_bits(mask: &str) -> Result<()> {
+    if mask.matches('X').count() > MAX_FLOATING_BITS {
+        return Err(AocError::unsolvable(format!(
+            "the mask {} has more than {} floating bits",
+            mask, MAX_FLOATING_BITS
+        )));
+    }
+    Ok(())
+}
+
+/// Parse an input line either to a mask or a memory access.
+///
+/// Masks need to consist of 36 bits (`0`, `1` or `X`) and addresses as well
+/// as values have to fit into 36 bits.
+fn parse_input<'a>(cursor: &mut Cursor<'a>) -> PResult<Input<'a>> {
+    if cursor.keyword(&[("mask = ", true), ("mem[", false)])? {
+        let start = *cursor;
+        let mask = cursor.take_while(|c| matches!(c, '0' | '1' | 'X'));
+        if mask.len() != 36 {
+            return Err(start.error("a mask of 36 bits"));
+        }
+        Ok(Input::Mask(mask))
+    } else {
+        let address = parse_36_bits(cursor)?;
+        cursor.tag("] = ")?;
+        let value = parse_36_bits(cursor)?;
+        Ok(Input::Memory((address, value)))
+    }
+}
+
+/// Parse an unsigned integer that fits into 36 bits.
+fn parse_36_bits(cursor: &mut Cursor) -> PResult<usize> {
+    let start = *cursor;
+    match cursor.int::<usize>()? {
+        value if value >> 36 == 0 => Ok(value),
+        _ => Err(start.error("a 36-bit integer")),
+    }
+}
+
+/// Get all the addresses that can be obtained from a mask and the initially passes address.
+#[allow(clippy::needless_collect)]
+fn get_addresses(mask: &str, address: usize) -> Vec<usize> {
+    // Collect the indices of the floating point bits
+    let floating_bits = mask
+        .chars()
+        .enumerate()
+        .filter_map(|(i, val)| if val == 'X' { Some(i) } else { None })
+        .collect::<Vec<_>>();
+    // Reference address where all `X` are set to 0
+    let reference = mask
+        .chars()
+        .zip(format!("{:0>36b}", address).chars())
+        .map(|(m, c)| match m {
+            'X' => '0',
+            '0' => c,
+            _ => '1',
+        })
+        .collect::<Vec<_>>();
+
+    // Loop over all length of combinations of floating indices
+    (0..=floating_bits.len())
+        // For all the combinations of that length
+        .flat_map(|number| {
+            // Create vector with entries corresponding to indices in the combination set to 1
+            floating_bits
+                .iter()
+                .combinations(number)
+                .map(|combination| {
+                    let mut clone = reference.clone();
+                    combination.into_iter().for_each(|i| clone[*i] = '1');
+                    usize::from_str_radix(&clone.into_iter().collect::<String>(), 2).unwrap()
+                })
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Enumerate the possible inputs, they either designate
+/// a mask or a memory writing operation.
+#[derive(Debug, PartialEq)]
+enum Input<'a> {
+    /// Read a new mask.
+    Mask(&'a str),
+    /// Memory access, first entry is the location, second entry
+    /// is the value to be written.
+    Memory((usize, usize)),
+}
+
+/// A random initialization program of `size` lines, in which every mask is
+/// followed by a few writes and has at most eight floating bits.
+pub fn initialization_program(rng: &mut Rng, size: usize) -> Sample {
+    let mut program = vec![];
+    while program.len() < size.max(1) {
+        let floating = rng.range(1..=8);
+        let mut mask = (0..36)
+            .map(|bit| match bit < floating {
+                true => 'X',
+                false => *rng.pick(&['0', '1']),
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut mask);
+        program.push(format!("mask = {}", mask.iter().collect::<String>()));
+        for _ in 0..rng.range(1..=5) {
+            let (address, value) = (rng.below(1 << 16), rng.below(1 << 30));
+            program.push(format!("mem[{}] = {}", address, value));
+        }
+    }
+    program.truncate(size.max(1));
+    Sample::new(lines(&program))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let parse_input = |line| parse::parse(line, parse_input).ok();
+        assert_eq!(
+            parse_input("mask = 01X10101X11X01XX01X000011X1000110110"),
+            Some(Input::Mask("01X10101X11X01XX01X000011X1000110110"))
+        );
+        assert_eq!(
+            parse_input("mem[30135] = 4799584"),
+            Some(Input::Memory((30135, 4799584)))
+        );
+        assert_eq!(parse_input("mask = 01X1"), None);
+        assert_eq!(parse_input("mem[1] = 68719476736"), None);
+        assert_eq!(parse_input("mem[a] = 1"), None);
+        assert_eq!(
+            parse::parse("mem[1] = 68719476736", super::parse_input)
+                .unwrap_err()
+                .to_string(),
+            "column 10: expected a 36-bit integer"
+        );
+    }
+
+    #[test]
+    fn test_bytes_representation() {
+        assert_eq!(
+            &format!("{:0>36b}", 11),
+            "000000000000000000000000000000001011"
+        );
+    }
+
+    #[test]
+    fn test_day_14_task_1() {
+        let input = [
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X".to_string(),
+            "mem[8] = 11".to_string(),
+            "mem[7] = 101".to_string(),
+            "mem[8] = 0".to_string(),
+        ];
+        assert_eq!(task_1(&input).unwrap(), 165);
+        assert!(task_1(&input[1..]).is_err());
+        assert_eq!(task_1_stream(input.iter().map(Ok)).unwrap(), 165);
+        assert!(task_1_stream(input[1..].iter().map(Ok)).is_err());
+    }
+
+    #[test]
+    fn test_get_addresses() {
+        let mask = "000000000000000000000000000000X1001X";
+        let address = 42;
+        let addresses = get_addresses(mask, address);
+        assert_eq!(addresses.len(), 4);
+        assert!(addresses.iter().find(|&&addr| addr == 26usize).is_some());
+        assert!(addresses.iter().find(|&&addr| addr == 27usize).is_some());
+        assert!(addresses.iter().find(|&&addr| addr == 58usize).is_some());
+        assert!(addresses.iter().find(|&&addr| addr == 59usize).is_some());
+        assert!(addresses.iter().find(|&&addr| addr == 60usize).is_none());
+    }
+
+    #[test]
+    fn test_day_14_task_2() {
+        let input = [
+            "mask = 000000000000000000000000000000X1001X".to_string(),
+            "mem[42] = 100".to_string(),
+            "mask = 00000000000000000000000000000000X0XX".to_string(),
+            "mem[26] = 1".to_string(),
+        ];
+        assert_eq!(task_2(&input).unwrap(), 208);
+        assert_eq!(task_2_stream(input.iter().map(Ok)).unwrap(), 208);
+
+        let input = [
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X".to_string(),
+            "mem[8] = 11".to_string(),
+        ];
+        assert!(task_2(&input).is_err());
+        assert!(task_2_stream(input.iter().map(Ok)).is_err());
+    }
+}
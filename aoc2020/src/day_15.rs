@@ -0,0 +1,106 @@
+//! This module contains the code
+//! for the solution of the fifteenth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/15).
+use crate::{AocError, Result, Rng, Sample, Solution};
+use std::collections::HashMap;
+
+/// The solution to the fifteenth day's challenges.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        let numbers = data.trim();
+        numbers
+            .split(',')
+            .map(|n| {
+                n.trim().parse::<usize>().map_err(|_| {
+                    AocError::malformed_in(
+                        data,
+                        numbers,
+                        format!("expected comma-separated numbers, found `{}`", n),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1_2(input, 2020)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_1_2(input, 30000000)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(starting_numbers(rng, size))
+    }
+}
+
+/// Compute the final (`end`th) number said in the game.
+pub fn task_1_2(data: &[usize], end: usize) -> Result<usize> {
+    if data.is_empty() || end == 0 {
+        return Err(AocError::unsolvable(
+            "the game needs starting numbers and turns",
+        ));
+    }
+    if end <= data.len() {
+        return Ok(data[end - 1]);
+    }
+    let mut memory = HashMap::new();
+
+    // Insert all the starting numbers into the memory, except for the last one
+    // which is stored in `previous`.
+    for (i, &item) in data.iter().enumerate().take(data.len() - 1) {
+        memory.insert(item, i + 1);
+    }
+
+    Ok(
+        (data.len()..end).fold(data[data.len() - 1], |previous, index| {
+            match memory.insert(previous, index) {
+                None => 0,
+                Some(val) => index - val,
+            }
+        }),
+    )
+}
+
+/// A random list of `size` distinct starting numbers, at most ten.
+pub fn starting_numbers(rng: &mut Rng, size: usize) -> Sample {
+    let mut numbers = (0..20).map(|n| n.to_string()).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(size.clamp(1, 10));
+    Sample::new(numbers.join(",") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_15_parse() {
+        assert_eq!(Day15::parse("0,3,6\n").unwrap(), vec![0, 3, 6]);
+        assert!(Day15::parse("0,a,6").is_err());
+    }
+
+    #[test]
+    fn test_day_15_task_1_2() {
+        let input = [0, 3, 6];
+        assert_eq!(task_1_2(&input, 4).unwrap(), 0);
+        assert_eq!(task_1_2(&input, 5).unwrap(), 3);
+        assert_eq!(task_1_2(&input, 6).unwrap(), 3);
+        assert_eq!(task_1_2(&input, 7).unwrap(), 1);
+        assert_eq!(task_1_2(&input, 8).unwrap(), 0);
+        assert_eq!(task_1_2(&input, 9).unwrap(), 4);
+        assert_eq!(task_1_2(&input, 10).unwrap(), 0);
+        assert_eq!(task_1_2(&input, 2020).unwrap(), 436);
+        assert_eq!(task_1_2(&input, 2).unwrap(), 3);
+        assert!(task_1_2(&[], 2020).is_err());
+    }
+}
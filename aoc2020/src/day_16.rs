@@ -0,0 +1,473 @@
+//! This module contains the code
+//! for the solution of the sixteenth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/16).
+use crate::generate::lines;
+use crate::parse::{self, Cursor, PResult};
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+use std::collections::HashSet;
+
+/// The solution to the sixteenth day's challenges.
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(ticket_notes(rng, size))
+    }
+}
+
+/// Compute the sum of all invalid numbers in nearby tickets.
+pub fn task_1(data: &[String]) -> Result<usize> {
+    let valid_numbers: HashSet<usize> = collect_fields(data)?
+        .iter()
+        .flat_map(|f| (f.range_1.0..=f.range_1.1).chain(f.range_2.0..=f.range_2.1))
+        .collect();
+
+    Ok(collect_nearby_tickets(data)?
+        .iter()
+        .flat_map(|v| v.iter())
+        .filter_map(|n| {
+            if !valid_numbers.contains(n) {
+                Some(*n)
+            } else {
+                None
+            }
+        })
+        .sum())
+}
+
+/// Compute the sum of all six departure values on your ticket.
+pub fn task_2(data: &[String]) -> Result<usize> {
+    // Read the fields on the tickets
+    let fields = collect_fields(data)?;
+
+    // Get a set of all valid numbers.
+    let valid_numbers: HashSet<usize> = fields
+        .iter()
+        .flat_map(|f| (f.range_1.0..=f.range_1.1).chain(f.range_2.0..=f.range_2.1))
+        .collect();
+
+    // Filter the nearby tickets, retaining only the valid ones.
+    let valid_nearby = collect_nearby_tickets(data)?
+        .into_iter()
+        .filter(|t| t.iter().all(|v| valid_numbers.contains(v)))
+        .collect::<Vec<_>>();
+
+    // Get the content of your ticket.
+    let your_ticket = collect_your_ticket(data)?;
+
+    // For each field, find the columns whose entries fullfil the field's ranges.
+    let field_index_candidates = fields
+        .iter()
+        .map(|f| {
+            let mut matches = vec![];
+            for i in 0..your_ticket.len() {
+                let condition = valid_nearby.iter().all(|v| {
+                    v[i] >= f.range_1.0 && v[i] <= f.range_1.1
+                        || v[i] >= f.range_2.0 && v[i] <= f.range_2.1
+                });
+                if condition {
+                    matches.push(i);
+                }
+            }
+            crate::trace!("candidates", field = f.name, columns = matches);
+            (&f.name, matches)
+        })
+        .collect::<Vec<_>>();
+
+    // By elimination, find the unambiguous columns associated to each field.
+    let mut identified_cols = HashSet::new();
+    let mut identified_fields = HashSet::new();
+    let mut target_indices = vec![];
+    let mut round = 0;
+    while identified_cols.len() < fields.len() {
+        let identified = identified_cols.len();
+        round += 1;
+        for (f, m) in &field_index_candidates {
+            if identified_fields.contains(&f) {
+                continue;
+            }
+            let count = m
+                .iter()
+                .filter(|v| !identified_cols.contains(*v))
+                .collect::<Vec<_>>();
+            if count.len() != 1 {
+                continue;
+            } else {
+                crate::trace!("identify", round = round, field = f, column = count[0]);
+                identified_fields.insert(f);
+                identified_cols.insert(count[0]);
+                if f.starts_with("departure") {
+                    target_indices.push(count[0]);
+                }
+            }
+        }
+        if identified_cols.len() == identified {
+            return Err(AocError::unsolvable(
+                "the fields cannot be assigned to the columns unambiguously",
+            ));
+        }
+    }
+
+    Ok(target_indices.iter().map(|&&v| your_ticket[v]).product())
+}
+
+/// Field with name and two ranges of valid numbers.
+#[derive(Debug)]
+struct Field {
+    name: String,
+    range_1: (usize, usize),
+    range_2: (usize, usize),
+}
+
+/// Collect all the possible fields using the input data.
+fn collect_fields(data: &[String]) -> Result<Vec<Field>> {
+    let count = data.iter().take_while(|l| !l.is_empty()).count();
+    parse::each_line(&data[..count], parse_field)
+}
+
+/// Parse a field of the format `<name>: <from>-<to> or <from>-<to>`.
+fn parse_field(cursor: &mut Cursor) -> PResult<Field> {
+    let name = cursor.take_until(": ");
+    if name.is_empty() {
+        return Err(cursor.error("a field name"));
+    }
+    cursor.tag(": ")?;
+    let range_1 = parse_range(cursor)?;
+    cursor.tag(" or ")?;
+    let range_2 = parse_range(cursor)?;
+
+    Ok(Field {
+        name: name.to_string(),
+        range_1,
+        range_2,
+    })
+}
+
+/// Parse a range of valid numbers of the format `<from>-<to>`.
+fn parse_range(cursor: &mut Cursor) -> PResult<(usize, usize)> {
+    let from = cursor.int()?;
+    cursor.tag("-")?;
+    Ok((from, cursor.int()?))
+}
+
+/// Collect the information on your ticket using the input data.
+fn collect_your_ticket(data: &[String]) -> Result<Vec<usize>> {
+    let position = find_section(data, "your ticket:")?;
+    match data.get(position + 1) {
+        Some(_) => parse_ticket(data, position + 1),
+        None => Err(AocError::malformed(
+            data.len() + 1,
+            "",
+            "expected the numbers on your ticket",
+        )),
+    }
+}
+
+/// Collect the numbers stored in the nearby tickets.
+fn collect_nearby_tickets(data: &[String]) -> Result<Vec<Vec<usize>>> {
+    let position = find_section(data, "nearby tickets:")?;
+    (position + 1..data.len())
+        .filter(|&i| !data[i].is_empty())
+        .map(|i| parse_ticket(data, i))
+        .collect()
+}
+
+/// Find the index of the line introducing the section with the given `header`.
+fn find_section(data: &[String], header: &str) -> Result<usize> {
+    data.iter()
+        .position(|l| l == header)
+        .ok_or_else(|| AocError::malformed(data.len() + 1, "", format!("expected `{}`", header)))
+}
+
+/// Parse the ticket on line `index` of the input data.
+///
+/// A ticket needs to have a value for each of the fields.
+fn parse_ticket(data: &[String], index: usize) -> Result<Vec<usize>> {
+    let fields = data.iter().take_while(|l| !l.is_empty()).count();
+    let line = &data[index];
+    parse::parse(line, |c| {
+        let ticket = c.list(",", |c| c.int::<usize>())?;
+        if ticket.len() == fields {
+            Ok(ticket)
+        } else {
+            Err(Cursor::new(line).error(format!("{} comma-separated numbers", fields)))
+        }
+    })
+    .map_err(|err| AocError::malformed(index + 1, line, err.to_string()))
+}
+
+/// The names of the fields on the tickets.
+const FIELD_NAMES: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+/// Random notes on the twenty ticket fields and `size` nearby tickets, about a
+/// quarter of which are invalid, such that the fields can be assigned to the
+/// columns by elimination.
+///
+/// The valid numbers of the fields are nested, i.e. the `k`-th field accepts
+/// all the numbers of the fields after it. Each column holds the numbers of
+/// one field, with at least one that is rejected by all the following
+/// fields, such that the last field fits one column only, the one before it
+/// two columns, and so on.
+pub fn ticket_notes(rng: &mut Rng, size: usize) -> Sample {
+    let count = FIELD_NAMES.len();
+    let mut names = FIELD_NAMES.to_vec();
+    rng.shuffle(&mut names);
+    let mut columns = (0..count).collect::<Vec<_>>();
+    rng.shuffle(&mut columns);
+
+    let (mut low, mut high) = (rng.range(25..=50), rng.range(950..=975));
+    let mut gap_low = rng.range(300..=700);
+    let mut gap_high = gap_low + rng.range(2..=4);
+    let mut fields = vec![];
+    for name in &names {
+        fields.push(Field {
+            name: name.to_string(),
+            range_1: (low, gap_low),
+            range_2: (gap_high, high),
+        });
+        low += rng.range(1..=3);
+        gap_low -= rng.range(1..=5);
+        gap_high += rng.range(1..=5);
+        high -= rng.range(1..=3);
+    }
+
+    let valid_ticket = |rng: &mut Rng| {
+        let mut ticket = vec![0; count];
+        for (field, &column) in fields.iter().zip(&columns) {
+            let (from, to) = *rng.pick(&[field.range_1, field.range_2]);
+            ticket[column] = rng.range(from..=to);
+        }
+        ticket
+    };
+    let your_ticket = valid_ticket(rng);
+    let mut nearby = vec![];
+    let mut error_rate = 0;
+    for i in 0..size.max(1) {
+        let mut ticket = valid_ticket(rng);
+        if i > 0 && rng.chance(0.25) {
+            let (low, gap, high) = (fields[0].range_1, fields[0].range_2.0, fields[0].range_2.1);
+            let invalid = match rng.below(3) {
+                0 => rng.range(0..=low.0 - 1),
+                1 => rng.range(low.1 + 1..=gap - 1),
+                _ => rng.range(high + 1..=999),
+            };
+            ticket[rng.below(count)] = invalid;
+            error_rate += invalid;
+            nearby.push((ticket, false));
+        } else {
+            nearby.push((ticket, true));
+        }
+    }
+    // The lowest number of a field is rejected by all the following ones.
+    let valid = (0..nearby.len())
+        .filter(|&i| nearby[i].1)
+        .collect::<Vec<_>>();
+    for (field, &column) in fields.iter().zip(&columns) {
+        nearby[*rng.pick(&valid)].0[column] = field.range_1.0;
+    }
+
+    let departures = fields
+        .iter()
+        .zip(&columns)
+        .filter(|(field, _)| field.name.starts_with("departure"))
+        .map(|(_, &column)| your_ticket[column])
+        .product::<usize>();
+
+    let mut notes = fields
+        .iter()
+        .map(|f| {
+            let (r1, r2) = (f.range_1, f.range_2);
+            format!("{}: {}-{} or {}-{}", f.name, r1.0, r1.1, r2.0, r2.1)
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut notes);
+    let ticket = |numbers: &[usize]| numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+    notes.push(String::new());
+    notes.push("your ticket:".to_string());
+    notes.push(ticket(&your_ticket).join(","));
+    notes.push(String::new());
+    notes.push("nearby tickets:".to_string());
+    notes.extend(nearby.iter().map(|(numbers, _)| ticket(numbers).join(",")));
+
+    Sample::new(lines(&notes))
+        .with_answer(Part::One, error_rate)
+        .with_answer(Part::Two, departures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace;
+
+    #[test]
+    fn test_collect_fields() {
+        let input = [
+            "class: 1-3 or 5-7".to_string(),
+            "row: 6-11 or 33-44".to_string(),
+            "seat: 13-40 or 45-50".to_string(),
+            "".to_string(),
+            "your ticket:".to_string(),
+            "7,1,14".to_string(),
+            "".to_string(),
+            "nearby tickets:".to_string(),
+            "7,3,47".to_string(),
+            "40,4,50".to_string(),
+            "55,2,20".to_string(),
+            "38,6,12".to_string(),
+        ];
+        let fields = collect_fields(&input).unwrap();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[0].name, "class".to_string());
+        assert_eq!(fields[0].range_1, (1, 3));
+        assert_eq!(fields[0].range_2, (5, 7));
+        assert_eq!(fields[1].name, "row".to_string());
+        assert_eq!(fields[2].name, "seat".to_string());
+
+        let err = collect_fields(&["class: 1-3 or 5".to_string()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "1 malformed lines\n  line 1: malformed input `class: 1-3 or 5`: column 16: expected `-`"
+        );
+    }
+
+    #[test]
+    fn test_collect_your_ticket() {
+        let input = [
+            "class: 1-3 or 5-7".to_string(),
+            "row: 6-11 or 33-44".to_string(),
+            "seat: 13-40 or 45-50".to_string(),
+            "".to_string(),
+            "your ticket:".to_string(),
+            "7,1,14".to_string(),
+            "".to_string(),
+            "nearby tickets:".to_string(),
+            "7,3,47".to_string(),
+            "40,4,50".to_string(),
+            "55,2,20".to_string(),
+            "38,6,12".to_string(),
+        ];
+        assert_eq!(collect_your_ticket(&input).unwrap(), vec![7, 1, 14]);
+        assert!(collect_your_ticket(&input[..4]).is_err());
+    }
+
+    #[test]
+    fn test_collect_nearby_tickets() {
+        let input = [
+            "class: 1-3 or 5-7".to_string(),
+            "row: 6-11 or 33-44".to_string(),
+            "seat: 13-40 or 45-50".to_string(),
+            "".to_string(),
+            "your ticket:".to_string(),
+            "7,1,14".to_string(),
+            "".to_string(),
+            "nearby tickets:".to_string(),
+            "7,3,47".to_string(),
+            "40,4,50".to_string(),
+            "55,2,20".to_string(),
+            "38,6,12".to_string(),
+        ];
+        let nearby = collect_nearby_tickets(&input).unwrap();
+        assert_eq!(nearby[0], vec![7, 3, 47]);
+        assert_eq!(nearby[1], vec![40, 4, 50]);
+        assert_eq!(nearby[2], vec![55, 2, 20]);
+        assert_eq!(nearby[3], vec![38, 6, 12]);
+        assert_eq!(nearby.len(), 4);
+    }
+
+    #[test]
+    fn test_day_16_task_1() {
+        let input = [
+            "class: 1-3 or 5-7".to_string(),
+            "row: 6-11 or 33-44".to_string(),
+            "seat: 13-40 or 45-50".to_string(),
+            "".to_string(),
+            "your ticket:".to_string(),
+            "7,1,14".to_string(),
+            "".to_string(),
+            "nearby tickets:".to_string(),
+            "7,3,47".to_string(),
+            "40,4,50".to_string(),
+            "55,2,20".to_string(),
+            "38,6,12".to_string(),
+        ];
+        assert_eq!(task_1(&input).unwrap(), 71);
+
+        let mut malformed = input.clone();
+        malformed[9] = "40,4".to_string();
+        assert!(task_1(&malformed).is_err());
+    }
+
+    #[test]
+    fn test_trace() {
+        let input = [
+            "class: 0-1 or 4-19",
+            "row: 0-5 or 8-19",
+            "seat: 0-13 or 16-19",
+            "",
+            "your ticket:",
+            "11,12,13",
+            "",
+            "nearby tickets:",
+            "3,9,18",
+            "15,1,5",
+            "5,14,9",
+        ]
+        .iter()
+        .map(|l| l.to_string())
+        .collect::<Vec<_>>();
+        let (answer, events) = trace::with_sink(vec![], || task_2(&input));
+        // None of the fields is a departure field.
+        assert_eq!(answer.unwrap(), 1);
+        let events = events.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                "candidates field=class columns=[1,2]",
+                "candidates field=row columns=[0,1,2]",
+                "candidates field=seat columns=[2]",
+                "identify round=1 field=seat column=2",
+                "identify round=2 field=class column=1",
+                "identify round=2 field=row column=0",
+            ]
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! This module contains the code
+//! for the solution of the seventeenth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/17).
+use crate::animate::{self, Frame, Frames, Glyph};
+use crate::automaton::{Automaton, Lattice, Life, Sparse};
+use crate::render::Scene;
+use crate::{Grid, Part, Result, Rng, Sample, Solution};
+
+/// The solution to the seventeenth day's challenges.
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input, 6)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input, 6)
+    }
+
+    fn render(input: &Self::Input, part: Part) -> Result<Option<Scene>> {
+        Ok(Some(match part {
+            Part::One => {
+                let mut pocket = pocket_dimension::<3>(input)?;
+                pocket.run(6);
+                slice_scene(&pocket)
+            }
+            Part::Two => {
+                let mut pocket = pocket_dimension::<4>(input)?;
+                pocket.run(6);
+                slice_scene(&pocket)
+            }
+        }))
+    }
+
+    fn animate(input: &Self::Input, part: Part) -> Result<Option<Frames>> {
+        Ok(Some(match part {
+            Part::One => cycles(pocket_dimension::<3>(input)?, 6),
+            Part::Two => cycles(pocket_dimension::<4>(input)?, 6),
+        }))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(initial_slice(rng, size))
+    }
+}
+
+/// Compute the number of active cubes after 6 steps in a 3D simulation.
+pub fn task_1(data: &str, cycles: usize) -> Result<usize> {
+    let mut pocket = pocket_dimension::<3>(data)?;
+    pocket.run(cycles);
+    Ok(pocket.population())
+}
+
+/// Compute the number of active cubes after 6 steps in a 4D simulation.
+pub fn task_2(data: &str, cycles: usize) -> Result<usize> {
+    let mut pocket = pocket_dimension::<4>(data)?;
+    pocket.run(cycles);
+    Ok(pocket.population())
+}
+
+/// The pocket dimension with `N` dimensions as a cellular automaton, whose
+/// alive cells are the active cubes.
+type PocketDimension<const N: usize> = Automaton<Lattice<N>, Sparse<[isize; N]>, Life>;
+
+/// Create the pocket dimension from the string representation of its
+/// initial two-dimensional slice (puzzle input).
+fn pocket_dimension<const N: usize>(data: &str) -> Result<PocketDimension<N>> {
+    let slice = Grid::parse(data, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let actives = slice
+        .iter()
+        .filter(|(_, &active)| active)
+        .map(|((y, x), _)| {
+            let mut cube = [0; N];
+            cube[0] = x as isize;
+            cube[1] = y as isize;
+            cube
+        });
+    Ok(Automaton::sparse(
+        Lattice,
+        Life::new(&[3], &[2, 3]),
+        actives,
+    ))
+}
+
+/// Draw the two-dimensional slice of the `pocket` dimension that held the
+/// initial state, with the active cubes in the first color.
+fn slice_scene<const N: usize>(pocket: &PocketDimension<N>) -> Scene {
+    Scene::grid(
+        &slice(pocket),
+        |&active| if active { Some(0) } else { None },
+    )
+}
+
+/// The frames of the first `count` cycles of the `pocket` dimension, showing
+/// the slice that held the initial state as it appears in the challenge.
+fn cycles<const N: usize>(pocket: PocketDimension<N>, count: usize) -> Frames {
+    animate::simulate(
+        pocket,
+        move |pocket| {
+            pocket.generation() < count && {
+                pocket.run(1);
+                true
+            }
+        },
+        |pocket| {
+            let glyphs = slice(pocket).map(|_, &active| match active {
+                true => Glyph::new('#', 0),
+                false => Glyph::plain('.'),
+            });
+            let caption = format!(
+                "cycle {}: {} active cubes",
+                pocket.generation(),
+                pocket.population()
+            );
+            Frame::new(caption, glyphs)
+        },
+    )
+}
+
+/// The two-dimensional slice of the `pocket` dimension that held the initial
+/// state. It covers the extent of all active cubes in the first two dimensions.
+fn slice<const N: usize>(pocket: &PocketDimension<N>) -> Grid<bool> {
+    let actives = pocket.alive();
+    let xs = actives.iter().map(|cube| cube[0]);
+    let ys = actives.iter().map(|cube| cube[1]);
+    let (min_x, max_x) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+    let (min_y, max_y) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+    Grid::from_fn(
+        (max_y - min_y + 1) as usize,
+        (max_x - min_x + 1) as usize,
+        |(y, x)| {
+            let mut cube = [0; N];
+            cube[0] = min_x + x as isize;
+            cube[1] = min_y + y as isize;
+            pocket.is_alive(cube)
+        },
+    )
+}
+
+/// A random initial slice of `size` rows and columns, at most sixteen, of
+/// active and inactive cubes.
+pub fn initial_slice(rng: &mut Rng, size: usize) -> Sample {
+    let side = size.clamp(1, 16);
+    let slice = Grid::from_fn(side, side, |_| rng.chance(0.5));
+    Sample::new(slice.render(|&active| if active { '#' } else { '.' }) + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pocket_dimension() {
+        let pocket = pocket_dimension::<3>(".#.\n..#\n###").unwrap();
+        assert!(pocket_dimension::<3>(".#.\n..?\n###").is_err());
+
+        // Test actives
+        assert!(pocket.is_alive([1, 0, 0]));
+        assert!(pocket.is_alive([2, 1, 0]));
+        assert!(pocket.is_alive([0, 2, 0]));
+        assert!(pocket.is_alive([1, 2, 0]));
+        assert!(pocket.is_alive([2, 2, 0]));
+        assert!(!pocket.is_alive([0, 0, 0]));
+
+        // Test active neightbours
+        assert_eq!(pocket.live_neighbours([0, 0, 0]), 1);
+        assert_eq!(pocket.live_neighbours([1, 2, 0]), 3);
+    }
+
+    #[test]
+    fn test_slice_scene() {
+        let mut pocket = pocket_dimension::<3>(".#.\n..#\n###").unwrap();
+        let scene = slice_scene(&pocket);
+        assert_eq!((scene.width(), scene.height()), (3.0, 3.0));
+        assert_eq!(scene.shapes().len(), 5);
+
+        // The slice reads `#.#`, `.##` and `.#.` after the first cycle.
+        pocket.run(1);
+        let scene = slice_scene(&pocket);
+        assert_eq!((scene.width(), scene.height()), (3.0, 3.0));
+        assert_eq!(scene.shapes().len(), 5);
+    }
+
+    #[test]
+    fn test_cycles() {
+        let pocket = pocket_dimension::<3>(".#.\n..#\n###").unwrap();
+        let frames = cycles(pocket, 3).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0].glyphs.render(|g| g.symbol), ".#.\n..#\n###");
+        assert_eq!(frames[1].glyphs.render(|g| g.symbol), "#.#\n.##\n.#.");
+        assert_eq!(frames[3].caption, "cycle 3: 38 active cubes");
+    }
+
+    #[test]
+    fn test_day_17_task_1() {
+        let input = ".#.\n..#\n###";
+        assert_eq!(task_1(input, 3).unwrap(), 38);
+    }
+
+    #[test]
+    fn test_day_17_task_2() {
+        let input = ".#.\n..#\n###";
+        assert_eq!(task_2(input, 6).unwrap(), 848);
+    }
+}
@@ -0,0 +1,304 @@
+//! This module contains the code
+//! for the solution of the eighteenth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/18).
+use crate::generate::lines;
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+
+/// The solution to the eighteenth day's challenges.
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(homework(rng, size))
+    }
+}
+
+/// Compute the sum of all the evaluated expressions in the input.
+pub fn task_1(data: &[String]) -> Result<usize> {
+    evaluate_all(data, eval)
+}
+
+/// Compute the sum of all the evaluated expressions in the input.
+/// This time addition has precedence over multiplication.
+pub fn task_2(data: &[String]) -> Result<usize> {
+    evaluate_all(data, eval_add_prec)
+}
+
+/// Sum up the expressions on all lines of the input, evaluated with `evaluator`.
+fn evaluate_all(data: &[String], evaluator: fn(&str) -> Option<usize>) -> Result<usize> {
+    crate::parse_each(
+        data,
+        "expected a balanced expression of single digits, `+` and `*` without overflow",
+        evaluator,
+    )?
+    .into_iter()
+    .try_fold(0usize, |acc, v| acc.checked_add(v))
+    .ok_or_else(|| AocError::unsolvable("the sum of the expressions overflows"))
+}
+
+/// The operators that are allowed in the expression.
+enum Op {
+    Add,
+    Mul,
+}
+
+/// Apply the operator `op` to `lhs` and `rhs`, where `None` means
+/// that there is no left-hand side yet.
+fn apply(lhs: usize, op: &Option<Op>, rhs: usize) -> Option<usize> {
+    match op {
+        None => Some(rhs),
+        Some(Op::Add) => lhs.checked_add(rhs),
+        Some(Op::Mul) => lhs.checked_mul(rhs),
+    }
+}
+
+/// Evaluate an expression from its string representation.
+///
+/// Invalid expressions and overflowing results yield `None`.
+fn eval(data: &str) -> Option<usize> {
+    let mut cumulative = 0; // Accumulate the result of the operations
+    let mut op = None; // Keep track of the last operator (+,*)
+    let mut open_counter = 0; // Keep track of the currently opened brackets
+    let mut tmp = vec![]; // Store expressions in brackets
+    for c in data.chars() {
+        match c {
+            ' ' => continue,
+            '(' => {
+                if open_counter > 0 {
+                    tmp.push(c)
+                }
+                open_counter += 1;
+            }
+            ')' => {
+                if open_counter == 0 {
+                    return None;
+                }
+                open_counter -= 1;
+                if open_counter == 0 {
+                    let value = eval(&tmp.into_iter().collect::<String>())?;
+                    cumulative = apply(cumulative, &op, value)?;
+                    tmp = vec![];
+                } else {
+                    tmp.push(c)
+                }
+            }
+            '*' => {
+                if open_counter > 0 {
+                    tmp.push(c)
+                } else {
+                    op = Some(Op::Mul)
+                }
+            }
+            '+' => {
+                if open_counter > 0 {
+                    tmp.push(c)
+                } else {
+                    op = Some(Op::Add)
+                }
+            }
+            c => {
+                if open_counter > 0 {
+                    tmp.push(c)
+                } else {
+                    cumulative = apply(cumulative, &op, c.to_digit(10)? as usize)?;
+                }
+            }
+        }
+    }
+
+    if open_counter != 0 {
+        return None;
+    }
+    Some(cumulative)
+}
+
+/// Evaluate an expression from its string representation with addition having precedence over
+/// multiplication.
+///
+/// Invalid expressions and overflowing results yield `None`.
+fn eval_add_prec(data: &str) -> Option<usize> {
+    let mut cumulative: usize = 1; // Accumulate the result of the operations
+    let mut op = None; // Keep track of the last operator (+,*)
+    let mut open_counter = 0; // Keep track of the currently opened brackets
+    let mut tmp = vec![]; // Store expressions in brackets
+    let mut sum: usize = 0;
+    for c in data.chars() {
+        match c {
+            ' ' => continue,
+            '(' => {
+                if open_counter > 0 {
+                    tmp.push(c)
+                }
+                open_counter += 1;
+            }
+            ')' => {
+                if open_counter == 0 {
+                    return None;
+                }
+                open_counter -= 1;
+                if open_counter == 0 {
+                    let value = eval_add_prec(&tmp.into_iter().collect::<String>())?;
+                    match op {
+                        None => sum = value,
+                        Some(Op::Add) => sum = sum.checked_add(value)?,
+                        Some(Op::Mul) => {
+                            cumulative = cumulative.checked_mul(sum)?;
+                            sum = value
+                        }
+                    }
+                    tmp = vec![];
+                } else {
+                    tmp.push(c)
+                }
+            }
+            '*' => {
+                if open_counter > 0 {
+                    tmp.push(c)
+                } else {
+                    op = Some(Op::Mul)
+                }
+            }
+            '+' => {
+                if open_counter > 0 {
+                    tmp.push(c)
+                } else {
+                    op = Some(Op::Add)
+                }
+            }
+            c => {
+                if open_counter > 0 {
+                    tmp.push(c)
+                } else {
+                    let value = c.to_digit(10)? as usize;
+                    match op {
+                        None => sum = value,
+                        Some(Op::Add) => sum = sum.checked_add(value)?,
+                        Some(Op::Mul) => {
+                            cumulative = cumulative.checked_mul(sum)?;
+                            sum = value
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if open_counter != 0 {
+        return None;
+    }
+    cumulative.checked_mul(sum)
+}
+
+/// A random homework of `size` expressions, along with the sums of their
+/// values when evaluated from left to right and with addition first.
+pub fn homework(rng: &mut Rng, size: usize) -> Sample {
+    let (mut left_to_right, mut addition_first) = (0, 0);
+    let expressions = (0..size)
+        .map(|_| loop {
+            match expression(rng, 2) {
+                Some((text, value_1, value_2)) if value_1.max(value_2) < 1 << 40 => {
+                    left_to_right += value_1;
+                    addition_first += value_2;
+                    break text;
+                }
+                _ => continue,
+            }
+        })
+        .collect::<Vec<_>>();
+    Sample::new(lines(&expressions))
+        .with_answer(Part::One, left_to_right)
+        .with_answer(Part::Two, addition_first)
+}
+
+/// A random expression with up to `depth` levels of nested parentheses, along
+/// with its values when evaluated from left to right and with addition first,
+/// or `None` if they overflow.
+fn expression(rng: &mut Rng, depth: usize) -> Option<(String, usize, usize)> {
+    let mut text = String::new();
+    let mut left_to_right = 0;
+    // The products of the sums evaluated so far, and the current sum.
+    let (mut product, mut sum) = (1usize, 0usize);
+    for i in 0..rng.range(2..=5) {
+        let (operand, value_1, value_2) = match depth > 0 && rng.chance(0.3) {
+            true => {
+                let (inner, value_1, value_2) = expression(rng, depth - 1)?;
+                (format!("({})", inner), value_1, value_2)
+            }
+            false => {
+                let digit = rng.range(1..=9);
+                (digit.to_string(), digit, digit)
+            }
+        };
+        if i == 0 {
+            left_to_right = value_1;
+            sum = value_2;
+        } else if rng.chance(0.5) {
+            text.push_str(" + ");
+            left_to_right = left_to_right.checked_add(value_1)?;
+            sum = sum.checked_add(value_2)?;
+        } else {
+            text.push_str(" * ");
+            left_to_right = left_to_right.checked_mul(value_1)?;
+            product = product.checked_mul(sum)?;
+            sum = value_2;
+        }
+        text.push_str(&operand);
+    }
+    Some((text, left_to_right, product.checked_mul(sum)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval() {
+        assert_eq!(eval("3"), Some(3));
+        assert_eq!(eval("3 + 2"), Some(5));
+        assert_eq!(eval("(3 + 2)"), Some(5));
+        assert_eq!(eval("3 + 5 * 2"), Some(16));
+        assert_eq!(eval("3 + (5 * 2)"), Some(13));
+        assert_eq!(eval("2 * 3 + (4 * 5)"), Some(26));
+        assert_eq!(eval("5 + (8 * 3 + 9 + 3 * 4 * 3)"), Some(437));
+        assert_eq!(
+            eval("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            Some(12240)
+        );
+        assert_eq!(
+            eval("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            Some(13632)
+        );
+    }
+
+    #[test]
+    fn test_eval_malformed() {
+        assert_eq!(eval("(3 + 2))"), None);
+        assert_eq!(eval("3 - 2"), None);
+        assert_eq!(eval("((3 + 2)"), None);
+    }
+
+    #[test]
+    fn test_eval_prec_plus() {
+        assert_eq!(eval_add_prec("1 + (2 * 3) + (4 * (5 + 6))"), Some(51));
+        assert_eq!(eval_add_prec("2 * 3 + (4 * 5)"), Some(46));
+        assert_eq!(eval_add_prec("2 * (3 + 4"), None);
+    }
+}
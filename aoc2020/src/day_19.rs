@@ -0,0 +1,366 @@
+//! This module contains the code
+//! for the solution of the nineteenth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/19).
+use crate::generate::lines;
+use crate::parse::{self, Cursor, PResult};
+use crate::{AocError, Part, Result, Rng, Sample, Solution, Unsolved};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// The solution to the nineteenth day's challenges.
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(Unsolved)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(message_rules(rng, size))
+    }
+}
+
+/// Find all the images that match rule 0.
+pub fn task_1(data: &str) -> Result<usize> {
+    let blocks = crate::blocks(data);
+    let (rules, images) = match blocks.split_first() {
+        Some((rules, images)) if !images.is_empty() => (rules, images),
+        _ => {
+            return Err(AocError::unexpected_end(
+                data,
+                "expected a blank line followed by the messages",
+            ))
+        }
+    };
+
+    let rules: HashMap<usize, Rule> = rules
+        .lines()
+        .map(|r| parse::parse_in(data, r, extract_rule))
+        .collect::<Result<_>>()?;
+
+    let matches = get_all_matches(0, &rules)?;
+
+    Ok(images
+        .iter()
+        .flat_map(|i| i.split_whitespace())
+        .filter(|img| matches.iter().any(|m| img == m))
+        .count())
+}
+
+/// Extract a rule from its string representation.
+///
+/// A rule is of the format `<id>: "<letter>"`, `<id>: <ids>` or
+/// `<id>: <ids> | <ids>`, where `<ids>` are separated by spaces.
+fn extract_rule<'a>(cursor: &mut Cursor<'a>) -> PResult<(usize, Rule<'a>)> {
+    let id = cursor.int()?;
+    cursor.tag(": ")?;
+    if cursor.eat("\"") {
+        let start = *cursor;
+        let letter = cursor.char()?;
+        if !letter.is_alphanumeric() {
+            return Err(start.error("a letter"));
+        }
+        cursor.tag("\"")?;
+        return Ok((id, Rule::Letter(&start.rest()[..letter.len_utf8()])));
+    }
+
+    let ids = |cursor: &mut Cursor<'a>| cursor.list(" ", |c| c.int());
+    let first = ids(cursor)?;
+    if cursor.eat(" | ") {
+        Ok((id, Rule::Either(first, ids(cursor)?)))
+    } else {
+        Ok((id, Rule::One(first)))
+    }
+}
+
+/// Get all the strings that match a given rule.
+///
+/// Rules referring to undefined rules or to themselves (directly or
+/// indirectly) cannot be expanded and yield an error.
+fn get_all_matches<'a>(rule: usize, rules: &HashMap<usize, Rule<'a>>) -> Result<Vec<String>> {
+    collect_matches(rule, rules, &mut vec![])
+}
+
+/// Get all the strings that match a given rule, where `path` holds the
+/// rules that are currently being expanded.
+fn collect_matches<'a>(
+    rule: usize,
+    rules: &HashMap<usize, Rule<'a>>,
+    path: &mut Vec<usize>,
+) -> Result<Vec<String>> {
+    if path.contains(&rule) {
+        return Err(AocError::unsolvable(format!(
+            "rule {} refers to itself and matches infinitely many messages",
+            rule
+        )));
+    }
+    let definition = rules
+        .get(&rule)
+        .ok_or_else(|| AocError::unsolvable(format!("rule {} is not defined", rule)))?;
+
+    path.push(rule);
+    let mut sequence = |v: &[usize]| -> Result<Vec<String>> {
+        Ok(v.iter()
+            .map(|&r| collect_matches(r, rules, path))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .multi_cartesian_product()
+            .map(|v| v.into_iter().collect::<String>())
+            .collect())
+    };
+    let matches = match definition {
+        Rule::Letter(a) => vec![a.to_string()],
+        Rule::One(v) => sequence(v)?,
+        Rule::Either(v1, v2) => {
+            let mut matches = sequence(v1)?;
+            matches.extend(sequence(v2)?);
+            matches
+        }
+    };
+    path.pop();
+
+    Ok(matches)
+}
+
+/// The different combinations a rule can be.
+#[derive(Debug, PartialEq)]
+enum Rule<'a> {
+    Letter(&'a str),
+    One(Vec<usize>),
+    Either(Vec<usize>, Vec<usize>),
+}
+
+/// Random rules and `size` messages, where rule 0 matches the messages made
+/// of two chunks matching rule 42 followed by one chunk matching rule 31.
+///
+/// The chunks are five letters long and rules 42 and 31 split them into two
+/// halves, such that rules 8 and 11 can loop as in the second part. The other
+/// messages have chunks in the wrong order or number, or a letter too many.
+pub fn message_rules(rng: &mut Rng, size: usize) -> Sample {
+    let mut ids = (1..=40)
+        .filter(|id| ![8, 11, 31, 42].contains(id))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut ids);
+    let mut rules = HashMap::new();
+    let letters = [ids.pop().unwrap(), ids.pop().unwrap()];
+    rules.insert(letters[0], Rule::Letter("a"));
+    rules.insert(letters[1], Rule::Letter("b"));
+    let [first, second] = halves(rng, &mut rules, &mut ids, letters, 5);
+    rules.insert(42, Rule::One(vec![first]));
+    rules.insert(31, Rule::One(vec![second]));
+    rules.insert(0, Rule::One(vec![8, 11]));
+    rules.insert(8, Rule::One(vec![42]));
+    rules.insert(11, Rule::One(vec![42, 31]));
+
+    let mut matching = 0;
+    let messages = (0..size)
+        .map(|_| {
+            let (chunks_42, chunks_31) = match rng.below(4) {
+                0 => (2, 1),
+                1 => {
+                    let chunks_31 = rng.range(1..=3);
+                    (chunks_31 + rng.range(1..=3), chunks_31)
+                }
+                _ => {
+                    let chunks_42 = rng.range(1..=3);
+                    (chunks_42, chunks_42 + rng.range(0..=2))
+                }
+            };
+            let mut chunks = vec![42; chunks_42];
+            chunks.extend(vec![31; chunks_31]);
+            let mut message = chunks
+                .iter()
+                .map(|&rule| sample_message(rng, &rules, rule))
+                .collect::<String>();
+            if (chunks_42, chunks_31) == (2, 1) {
+                match rng.chance(0.8) {
+                    true => matching += 1,
+                    false => message.push(*rng.pick(&['a', 'b'])),
+                }
+            }
+            message
+        })
+        .collect::<Vec<_>>();
+
+    let mut rules = rules
+        .iter()
+        .map(|(id, rule)| {
+            let ids = |v: &[usize]| v.iter().map(|id| id.to_string()).join(" ");
+            match rule {
+                Rule::Letter(letter) => format!("{}: \"{}\"", id, letter),
+                Rule::One(v) => format!("{}: {}", id, ids(v)),
+                Rule::Either(v1, v2) => format!("{}: {} | {}", id, ids(v1), ids(v2)),
+            }
+        })
+        .collect::<Vec<_>>();
+    // Sorting first keeps the order independent of the one of the map.
+    rules.sort_unstable();
+    rng.shuffle(&mut rules);
+    Sample::new(format!("{}\n{}", lines(&rules), lines(&messages))).with_answer(Part::One, matching)
+}
+
+/// Add rules that split the messages of `len` letters into two halves, and
+/// return the ids of the rules matching either half.
+///
+/// A message belongs to the first half if its first letter is in the first
+/// half of the letters and the rest in the first half of the shorter
+/// messages, or neither of them is.
+fn halves(
+    rng: &mut Rng,
+    rules: &mut HashMap<usize, Rule<'static>>,
+    ids: &mut Vec<usize>,
+    letters: [usize; 2],
+    len: usize,
+) -> [usize; 2] {
+    if len == 1 {
+        return if rng.chance(0.5) {
+            letters
+        } else {
+            [letters[1], letters[0]]
+        };
+    }
+    let [head_1, head_2] = halves(rng, rules, ids, letters, 1);
+    let [tail_1, tail_2] = halves(rng, rules, ids, letters, len - 1);
+    let (first, second) = (ids.pop().unwrap(), ids.pop().unwrap());
+    rules.insert(
+        first,
+        Rule::Either(vec![head_1, tail_1], vec![head_2, tail_2]),
+    );
+    rules.insert(
+        second,
+        Rule::Either(vec![head_1, tail_2], vec![head_2, tail_1]),
+    );
+    [first, second]
+}
+
+/// A random message matching `rule`.
+fn sample_message(rng: &mut Rng, rules: &HashMap<usize, Rule>, rule: usize) -> String {
+    let sequence = |rng: &mut Rng, v: &[usize]| {
+        v.iter()
+            .map(|&r| sample_message(rng, rules, r))
+            .collect::<String>()
+    };
+    match &rules[&rule] {
+        Rule::Letter(letter) => letter.to_string(),
+        Rule::One(v) => sequence(rng, v),
+        Rule::Either(v1, v2) => {
+            let v = if rng.chance(0.5) { v1 } else { v2 };
+            sequence(rng, v)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_rule_errors() {
+        let error = |rule| parse::parse(rule, extract_rule).unwrap_err().to_string();
+        assert_eq!(error("115 12"), "column 4: expected `: `");
+        assert_eq!(
+            error("115: 12 |"),
+            "column 8: expected the end of the input"
+        );
+        assert_eq!(error("115: 12 | x"), "column 11: expected an integer");
+        assert_eq!(error(r#"3: "c"#), "column 6: expected `\"`");
+        assert_eq!(error(r#"3: "-""#), "column 5: expected a letter");
+    }
+
+    #[test]
+    fn test_extract_rule() {
+        let extract_rule = |rule| parse::parse(rule, extract_rule).ok();
+        assert_eq!(extract_rule(r#"3: "a""#), Some((3, Rule::Letter("a"))));
+        assert_eq!(extract_rule(r"4: 12"), Some((4, Rule::One(vec![12]))));
+        assert_eq!(
+            extract_rule(r"4: 12 13"),
+            Some((4, Rule::One(vec![12, 13])))
+        );
+        assert_eq!(
+            extract_rule(r"4: 12 13 | 14 15"),
+            Some((4, Rule::Either(vec![12, 13], vec![14, 15])))
+        );
+        assert_eq!(
+            extract_rule(r"4: 12 | 14 15"),
+            Some((4, Rule::Either(vec![12], vec![14, 15])))
+        );
+        assert_eq!(
+            extract_rule(r"4: 12 13 | 14"),
+            Some((4, Rule::Either(vec![12, 13], vec![14])))
+        );
+        assert_eq!(
+            extract_rule(r"4: 12 | 14"),
+            Some((4, Rule::Either(vec![12], vec![14])))
+        );
+    }
+
+    #[test]
+    fn test_multi_cartesian_product() {
+        let possibilities = vec![vec!["a", "b"], vec!["c"]];
+        let combis = possibilities
+            .into_iter()
+            .multi_cartesian_product()
+            .collect::<std::collections::HashSet<_>>();
+        assert!(combis.contains(&vec!["a", "c"]));
+        assert!(combis.contains(&vec!["b", "c"]));
+    }
+
+    #[test]
+    fn test_get_all_matches() {
+        let rules = vec![
+            (0, Rule::One(vec![4, 1, 5])),
+            (1, Rule::Either(vec![2, 3], vec![3, 2])),
+            (2, Rule::Either(vec![4, 4], vec![5, 5])),
+            (3, Rule::Either(vec![4, 5], vec![5, 4])),
+            (4, Rule::Letter("a")),
+            (5, Rule::Letter("b")),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        let matches = get_all_matches(0, &rules).unwrap();
+        assert_eq!(matches.len(), 8);
+        assert!(matches.as_slice().contains(&"aaaabb".to_string()));
+        assert!(matches.as_slice().contains(&"aaabab".to_string()));
+        assert!(matches.as_slice().contains(&"abbabb".to_string()));
+        assert!(matches.as_slice().contains(&"abbbab".to_string()));
+        assert!(matches.as_slice().contains(&"aabaab".to_string()));
+        assert!(matches.as_slice().contains(&"aabbbb".to_string()));
+        assert!(matches.as_slice().contains(&"abaaab".to_string()));
+        assert!(matches.as_slice().contains(&"ababbb".to_string()));
+    }
+
+    #[test]
+    fn test_day_19_task_1() {
+        let input = r#"0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb"#;
+        assert_eq!(task_1(input).unwrap(), 2);
+        assert!(task_1("0: 1\n1: \"a\"").is_err());
+        assert!(task_1("0: 1\n\na").is_err());
+        assert!(task_1("0: 0 1 | 1\n1: \"a\"\n\na").is_err());
+    }
+}
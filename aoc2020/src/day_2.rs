@@ -0,0 +1,264 @@
+//! This module contains the code
+//! for the solution of the second day challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/2).
+use crate::generate::lines;
+use crate::parse::{self, Cursor, PResult};
+use crate::{Part, Result, Rng, Sample, Solution, Streamer};
+
+/// The solution to the second day's challenges.
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(password_database(rng, size))
+    }
+
+    fn streamer(part: Part) -> Option<Streamer> {
+        Some(match part {
+            Part::One => |lines| Ok(task_1_stream(lines)?.to_string()),
+            Part::Two => |lines| Ok(task_2_stream(lines)?.to_string()),
+        })
+    }
+}
+
+/// Check whether a given password is valid.
+///
+/// For this to be case, the password `pwd` needs to
+/// contain `character` at least `min_occurrence` times
+/// and at most `max_occurrence` times.
+fn is_valid_password_1(
+    pwd: &str,
+    character: char,
+    min_occurrence: usize,
+    max_occurrence: usize,
+) -> bool {
+    let count = pwd.matches(character).count();
+    count >= min_occurrence && count <= max_occurrence
+}
+
+/// Check whether a given password is valid.
+///
+/// For this to be the case, the password `pwd` needs to contain
+/// `character` in exactly one of the positions `pos_1` or  `pos_2`.
+///
+/// __Note__: Both `pos_1` and `pos_2` are 1-based. Hence `pos_1`
+/// corresponds to index 0 in the vector.
+fn is_valid_password_2(pwd: &str, character: char, pos_1: usize, pos_2: usize) -> bool {
+    pwd.chars()
+        .enumerate()
+        .filter(|(i, _)| *i + 1 == pos_1 || *i + 1 == pos_2)
+        .filter(|(_, c)| *c == character)
+        .count()
+        == 1
+}
+
+/// Process the input for the second day's challenge.
+///
+/// Each `input` consists of a single line from the
+/// puzzle input file and it is given in the form
+///
+/// `1-3 a: passwd`
+///
+/// where `passwd` is the password, and `a` is the letter
+/// that is allowed to occur from `1` up to `3` times in the password
+/// for it to be considered valid.
+///
+/// The function parses the different components of a line of the format
+/// `<min>-<max> <letter>: <password>` and returns them in a tuple.
+fn process_input_day_2(cursor: &mut Cursor) -> PResult<(String, char, usize, usize)> {
+    let min = cursor.int()?;
+    cursor.tag("-")?;
+    let max = cursor.int()?;
+    cursor.tag(" ")?;
+    let start = *cursor;
+    let target = cursor.char()?;
+    if !target.is_ascii_lowercase() {
+        return Err(start.error("a lowercase letter"));
+    }
+    cursor.tag(": ")?;
+    let password = cursor.take_while(|c| c.is_ascii_lowercase());
+    Ok((password.to_string(), target, min, max))
+}
+
+/// Compute the solution of the second day's first challenge.
+///
+/// For each line in the vector `data`
+/// check whether the password matches the given
+/// criterion and return the number of valid passwords.
+///
+/// ### Example
+///
+/// ```
+/// # use aoc2020::day_2::task_1;
+/// assert_eq!(task_1(&[
+///     "1-3 a: abcde".to_string(),
+///     "1-3 b: cdefg".to_string(),
+/// ]).unwrap(), 1);
+/// ```
+pub fn task_1(data: &[String]) -> Result<usize> {
+    task_1_stream(data.iter().map(Ok))
+}
+
+/// Compute the solution of the second day's second challenge.
+///
+/// For each line in the vector `data`
+/// check whether the password matches the given
+/// criterion and return the number of valid passwords.
+///
+/// ### Example
+///
+/// ```
+/// # use aoc2020::day_2::task_2;
+/// assert_eq!(task_2(&[
+///     "1-3 a: abcde".to_string(),
+///     "1-3 b: bdbfg".to_string(),
+/// ]).unwrap(), 1);
+/// ```
+pub fn task_2(data: &[String]) -> Result<usize> {
+    task_2_stream(data.iter().map(Ok))
+}
+
+/// Like [`task_1`], but the `lines` are processed one at a time as they are
+/// read, and the first malformed line aborts the count.
+pub fn task_1_stream<S, I>(lines: I) -> Result<usize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    count_valid(lines, is_valid_password_1)
+}
+
+/// Like [`task_2`], but the `lines` are processed one at a time as they are
+/// read, and the first malformed line aborts the count.
+pub fn task_2_stream<S, I>(lines: I) -> Result<usize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    count_valid(lines, is_valid_password_2)
+}
+
+/// Count the passwords of the `lines` that are valid according to `policy`.
+fn count_valid<S, I>(lines: I, policy: fn(&str, char, usize, usize) -> bool) -> Result<usize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    parse::stream_each_line(lines, process_input_day_2).try_fold(0, |count, entry| {
+        let (pwd, c, first, second) = entry?;
+        Ok(count + policy(&pwd, c, first, second) as usize)
+    })
+}
+
+/// A random password database of `size` entries.
+pub fn password_database(rng: &mut Rng, size: usize) -> Sample {
+    let mut entries = vec![];
+    let (mut valid_1, mut valid_2) = (0, 0);
+    for _ in 0..size {
+        let letter = (b'a' + rng.below(26) as u8) as char;
+        let len = rng.range(4..=16);
+        // Make the letter of the policy frequent, such that it matters.
+        let password = (0..len)
+            .map(|_| {
+                if rng.chance(0.3) {
+                    letter
+                } else {
+                    (b'a' + rng.below(26) as u8) as char
+                }
+            })
+            .collect::<String>();
+        let low = rng.range(1..=len / 2);
+        let high = rng.range(low + 1..=len);
+
+        let count = password.matches(letter).count();
+        valid_1 += (low <= count && count <= high) as usize;
+        let at = |pos: usize| password.as_bytes()[pos - 1] == letter as u8;
+        valid_2 += (at(low) != at(high)) as usize;
+        entries.push(format!("{}-{} {}: {}", low, high, letter, password));
+    }
+    Sample::new(lines(&entries))
+        .with_answer(Part::One, valid_1)
+        .with_answer(Part::Two, valid_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_2_task_1() {
+        assert_eq!(
+            task_1(&[
+                "1-3 a: abcde".to_string(),
+                "1-3 b: cdefg".to_string(),
+                "2-9 c: ccccccccc".to_string()
+            ])
+            .unwrap(),
+            2
+        );
+        assert!(task_1(&["1-3 a: abcde".to_string(), "1-a b: cdefg".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_day_2_stream() {
+        let data = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n";
+        assert_eq!(
+            task_1_stream(crate::read_lines(data.as_bytes())).unwrap(),
+            2
+        );
+        assert_eq!(
+            task_2_stream(crate::read_lines(data.as_bytes())).unwrap(),
+            1
+        );
+        let err = task_1_stream(["1-3 a: abcde", "1-a b: cdefg"].iter().map(Ok)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: malformed input `1-a b: cdefg`: column 3: expected an integer"
+        );
+    }
+
+    #[test]
+    fn test_process_input_day_2() {
+        assert_eq!(
+            parse::parse("1-3 b: cdefg", process_input_day_2),
+            Ok(("cdefg".to_string(), 'b', 1, 3))
+        );
+        assert_eq!(
+            parse::parse("1-3 b cdefg", process_input_day_2)
+                .unwrap_err()
+                .to_string(),
+            "column 6: expected `: `"
+        );
+    }
+
+    #[test]
+    fn test_is_valid_password_1() {
+        assert!(is_valid_password_1("abcde", 'a', 1, 3));
+        assert!(!is_valid_password_1("abcde", 'a', 2, 4));
+    }
+
+    #[test]
+    fn test_is_valid_password_2() {
+        assert!(is_valid_password_2("abcde", 'a', 1, 3));
+        assert!(!is_valid_password_2("cdefg", 'b', 1, 3));
+        assert!(!is_valid_password_2("ccccccccc", 'b', 2, 3));
+    }
+}
@@ -0,0 +1,432 @@
+//! This module contains the code
+//! for the solution of the twentieth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/20).
+use crate::grid::{Edge, Grid};
+use crate::render::Scene;
+use crate::{AocError, Part, Result, Rng, Sample, Solution, Unsolved};
+use std::collections::HashSet;
+
+/// The solution to the twentieth day's challenges.
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input, TILE_SIZE)
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(Unsolved)
+    }
+
+    /// Both parts draw the assembled image, with the lit pixels in the first color.
+    fn render(input: &Self::Input, _part: Part) -> Result<Option<Scene>> {
+        let image = assemble_image(input, TILE_SIZE)?;
+        Ok(Some(Scene::grid(
+            &image,
+            |&lit| if lit { Some(0) } else { None },
+        )))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(random_tiles(rng, size))
+    }
+}
+
+/// The number of rows and columns of the tiles in the puzzle input.
+const TILE_SIZE: usize = 10;
+
+/// Multiply the IDs of the four corner tiles of the image made of the tiles
+/// in `data`, each of which has `tile_size` rows and columns.
+///
+/// The corner tiles are the only ones with just two sides that match a side
+/// of another tile.
+pub fn task_1(data: &str, tile_size: usize) -> Result<usize> {
+    let tiles = parse_tiles(data, tile_size)?;
+    image_dim(&tiles)?;
+
+    let corners = tiles
+        .iter()
+        .filter(|tile| matching_sides(tile, &tiles) == 2)
+        .map(|tile| tile.id)
+        .collect::<Vec<_>>();
+    if corners.len() != 4 {
+        return Err(AocError::unsolvable(format!(
+            "expected 4 tiles with two matching sides, found {}",
+            corners.len()
+        )));
+    }
+    corners
+        .iter()
+        .try_fold(1usize, |product, &id| product.checked_mul(id))
+        .ok_or_else(|| AocError::unsolvable("the product of the corner IDs overflows"))
+}
+
+/// Assemble the image from the tiles in `data`, each of which has `tile_size`
+/// rows and columns.
+///
+/// The tiles are rotated and flipped such that the edges of neighbouring tiles
+/// match, and their borders are removed. The orientation of the whole image is
+/// arbitrary.
+pub fn assemble_image(data: &str, tile_size: usize) -> Result<Grid<bool>> {
+    let tiles = parse_tiles(data, tile_size)?;
+    let dim = image_dim(&tiles)?;
+
+    // Tiles with few matching sides belong to the corners and borders of the
+    // image, so trying them first avoids arrangements that start in its middle.
+    let mut tiles = tiles
+        .iter()
+        .map(|tile| (matching_sides(tile, &tiles), tile))
+        .collect::<Vec<_>>();
+    tiles.sort_by_key(|&(matches, _)| matches);
+    let orientations = tiles
+        .iter()
+        .map(|(_, tile)| {
+            tile.pixels
+                .orientations()
+                .into_iter()
+                .map(Oriented::new)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut placed = Vec::with_capacity(tiles.len());
+    let mut used = vec![false; tiles.len()];
+    if !arrange(&orientations, dim, &mut placed, &mut used) {
+        return Err(AocError::unsolvable(
+            "the tiles cannot be arranged into an image",
+        ));
+    }
+
+    let inner = tile_size.saturating_sub(2);
+    Ok(Grid::from_fn(dim * inner, dim * inner, |(row, col)| {
+        let (tile, orientation) = placed[row / inner * dim + col / inner];
+        orientations[tile][orientation].pixels[(row % inner + 1, col % inner + 1)]
+    }))
+}
+
+/// Parse the tiles in `data`, each of which has `tile_size` rows and columns.
+fn parse_tiles(data: &str, tile_size: usize) -> Result<Vec<Tile>> {
+    crate::blocks(data)
+        .into_iter()
+        .map(|t| Tile::from_string(t, tile_size))
+        .collect()
+}
+
+/// The number of tiles along each side of the square image made of `tiles`.
+fn image_dim(tiles: &[Tile]) -> Result<usize> {
+    let img_dim = (tiles.len() as f32).sqrt() as usize;
+    if img_dim.pow(2) != tiles.len() {
+        return Err(AocError::unsolvable(format!(
+            "{} tiles cannot be arranged in a square",
+            tiles.len()
+        )));
+    }
+    Ok(img_dim)
+}
+
+/// The number of sides of `tile` that match a side of any of the `tiles`.
+fn matching_sides(tile: &Tile, tiles: &[Tile]) -> usize {
+    tiles
+        .iter()
+        .filter(|other| other.id != tile.id)
+        .flat_map(|other| tile.get_matching_sides(other))
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// A tile in one of its orientations, along with the edges that need to match
+/// those of its neighbours.
+struct Oriented {
+    pixels: Grid<bool>,
+    top: Vec<bool>,
+    right: Vec<bool>,
+    bottom: Vec<bool>,
+    left: Vec<bool>,
+}
+
+impl Oriented {
+    fn new(pixels: Grid<bool>) -> Self {
+        Self {
+            top: pixels.edge(Edge::Top),
+            right: pixels.edge(Edge::Right),
+            bottom: pixels.edge(Edge::Bottom),
+            left: pixels.edge(Edge::Left),
+            pixels,
+        }
+    }
+}
+
+/// Arrange the tiles, given by their `orientations`, row by row into a square
+/// of `dim` tiles per side by backtracking. The `placed` tiles are identified by
+/// their index and orientation, and whether a tile is `used` is tracked
+/// alongside. Returns whether all tiles could be placed.
+fn arrange(
+    orientations: &[Vec<Oriented>],
+    dim: usize,
+    placed: &mut Vec<(usize, usize)>,
+    used: &mut [bool],
+) -> bool {
+    let pos = placed.len();
+    if pos == orientations.len() {
+        return true;
+    }
+    let left = (!pos.is_multiple_of(dim)).then(|| placed[pos - 1]);
+    let top = (pos >= dim).then(|| placed[pos - dim]);
+
+    for tile in 0..orientations.len() {
+        if used[tile] {
+            continue;
+        }
+        for (orientation, candidate) in orientations[tile].iter().enumerate() {
+            let fits_left = left.is_none_or(|(t, o)| orientations[t][o].right == candidate.left);
+            let fits_top = top.is_none_or(|(t, o)| orientations[t][o].bottom == candidate.top);
+            if !fits_left || !fits_top {
+                continue;
+            }
+            used[tile] = true;
+            placed.push((tile, orientation));
+            if arrange(orientations, dim, placed, used) {
+                return true;
+            }
+            placed.pop();
+            used[tile] = false;
+        }
+    }
+    false
+}
+
+/// A tile of the image, with its pixels set to `true` where they are lit (`#`).
+#[derive(Debug, PartialEq)]
+struct Tile {
+    id: usize,
+    pixels: Grid<bool>,
+}
+
+impl Tile {
+    fn from_string(data: &str, tile_dim: usize) -> Result<Tile> {
+        let (header, content) = data.split_once('\n').unwrap_or((data, ""));
+        let header = header.trim_end_matches('\r');
+        if header.is_empty() {
+            return Err(AocError::unexpected_end(data, "expected `Tile <id>:`"));
+        }
+        let id = header
+            .strip_prefix("Tile ")
+            .and_then(|h| h.strip_suffix(':'))
+            .and_then(|id| id.parse::<usize>().ok())
+            .ok_or_else(|| AocError::malformed_in(data, header, "expected `Tile <id>:`"))?;
+        let pixels = Grid::parse(content, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .map_err(|err| match err.line_errors().first() {
+            Some(line) => AocError::malformed(line.line + 1, &line.text, line.reason.clone()),
+            None => err,
+        })?;
+        if pixels.rows() != tile_dim || pixels.cols() != tile_dim || tile_dim == 0 {
+            return Err(AocError::unexpected_end(
+                data,
+                format!("expected {} rows of {} pixels", tile_dim, tile_dim),
+            ));
+        }
+
+        Ok(Tile { id, pixels })
+    }
+
+    fn get_side(&self, index: usize) -> Vec<bool> {
+        self.pixels.edge(Edge::ALL[index])
+    }
+
+    fn get_matching_sides(&self, other: &Tile) -> Vec<usize> {
+        let mut tmp = vec![];
+        for i1 in 0..4 {
+            for i2 in 0..4 {
+                if self.get_side(i1).iter().eq(other.get_side(i2).iter()) {
+                    tmp.push(i1);
+                }
+
+                if self.get_side(i1).iter().eq(other.get_side(i2).iter().rev()) {
+                    tmp.push(i1);
+                }
+            }
+        }
+        tmp
+    }
+}
+
+/// The tiles of a random square image of about `size` tiles, at most 12 x 12
+/// like the puzzle input, such that there are enough distinct edges.
+pub fn random_tiles(rng: &mut Rng, size: usize) -> Sample {
+    let dim = ((size as f64).sqrt() as usize).clamp(2, 12);
+    let (data, _, corners) = cut_image(rng, dim, TILE_SIZE);
+    Sample::new(data + "\n").with_answer(Part::One, corners)
+}
+
+/// Cut a random image into `dim` x `dim` tiles of `tile_size` pixels, where
+/// neighbouring tiles share their edges, and shuffle and reorient them.
+/// Returns the tiles along with the image they assemble into and the product
+/// of the IDs of its corner tiles.
+///
+/// The edges are drawn such that only neighbouring tiles match, unless there
+/// are too many edges for the number of pixels along them.
+pub fn cut_image(rng: &mut Rng, dim: usize, tile_size: usize) -> (String, Grid<bool>, usize) {
+    let side = dim * (tile_size - 1) + 1;
+    let mut full = Grid::from_fn(side, side, |_| rng.chance(0.5));
+    let step = tile_size - 1;
+    let mut edges = HashSet::new();
+    for (a, b) in (0..=dim).flat_map(|a| (0..dim).map(move |b| (a, b))) {
+        for edge in [
+            (0..tile_size)
+                .map(|k| (a * step, b * step + k))
+                .collect::<Vec<_>>(),
+            (0..tile_size)
+                .map(|k| (b * step + k, a * step))
+                .collect::<Vec<_>>(),
+        ] {
+            // The ends of an edge are shared with the perpendicular edges.
+            for _ in 0..64 {
+                for &pos in &edge[1..step] {
+                    full[pos] = rng.chance(0.5);
+                }
+                let pixels = edge.iter().map(|&pos| full[pos]).collect::<Vec<_>>();
+                let reversed = pixels.iter().rev().copied().collect::<Vec<_>>();
+                if !edges.contains(&pixels) && !edges.contains(&reversed) {
+                    edges.insert(pixels);
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut ids = HashSet::new();
+    while ids.len() < dim * dim {
+        ids.insert(rng.range(1000..=9999));
+    }
+    let mut ids = ids.into_iter().collect::<Vec<_>>();
+    ids.sort_unstable();
+    rng.shuffle(&mut ids);
+    let tiles = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let (top, left) = (i / dim * (tile_size - 1), i % dim * (tile_size - 1));
+            let tile = Grid::from_fn(tile_size, tile_size, |(r, c)| full[(top + r, left + c)]);
+            let pixels = tile.orientations().swap_remove(rng.below(8));
+            format!(
+                "Tile {}:\n{}",
+                id,
+                pixels.render(|&lit| if lit { '#' } else { '.' })
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut order = (0..tiles.len()).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+    let data = order
+        .iter()
+        .map(|&i| tiles[i].as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let inner = tile_size - 2;
+    let image = Grid::from_fn(dim * inner, dim * inner, |(r, c)| {
+        full[(
+            r / inner * (tile_size - 1) + r % inner + 1,
+            c / inner * (tile_size - 1) + c % inner + 1,
+        )]
+    });
+    let corners = [0, dim - 1, dim * (dim - 1), dim * dim - 1]
+        .iter()
+        .map(|&i| ids[i])
+        .product();
+    (data, image, corners)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tile_from_string() {
+        let input = "Tile 1:\n#.\n##";
+        let tile = Tile::from_string(input, 2).unwrap();
+        assert_eq!(tile.id, 1);
+
+        assert_eq!(tile.get_side(0), vec![true, false]);
+        assert_eq!(tile.get_side(1), vec![false, true]);
+        assert_eq!(tile.get_side(2), vec![true, true]);
+        assert_eq!(tile.get_side(3), vec![true, true]);
+
+        let input = "Tile 1:\n###\n...\n###";
+        let tile = Tile::from_string(input, 3).unwrap();
+        assert_eq!(tile.id, 1);
+
+        assert_eq!(tile.get_side(0), vec![true, true, true]);
+        assert_eq!(tile.get_side(1), vec![true, false, true]);
+        assert_eq!(tile.get_side(2), vec![true, true, true]);
+        assert_eq!(tile.get_side(3), vec![true, false, true]);
+
+        assert!(Tile::from_string("Tile 1:\n#.\n#", 2).is_err());
+        assert!(Tile::from_string("Tile 1:\n#.", 2).is_err());
+        assert!(Tile::from_string("Tile x:\n#.\n##", 2).is_err());
+        assert!(Tile::from_string("", 2).is_err());
+        match Tile::from_string("Tile 1:\n#.\n#?", 2).unwrap_err() {
+            AocError::MalformedLine(err) => assert_eq!(err.line, 3),
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_tile_matches() {
+        let tile_1 = Tile::from_string("Tile 1:\n###\n#..\n...", 3).unwrap();
+        let tile_2 = Tile::from_string("Tile 2:\n..#\n.#.\n#.#", 3).unwrap();
+        let matches = tile_1.get_matching_sides(&tile_2);
+        assert_eq!(matches.len(), 2);
+    }
+
+    #[test]
+    fn test_day_20_task_1() {
+        for seed in 0..3 {
+            let (data, _, corners) = cut_image(&mut Rng::new(seed), 3, 10);
+            assert_eq!(task_1(&data, 10).unwrap(), corners);
+        }
+
+        let (data, _, _) = cut_image(&mut Rng::new(7), 3, 10);
+        let eight_tiles = data.rsplit_once("\n\n").unwrap().0;
+        assert!(task_1(eight_tiles, 10).is_err());
+    }
+
+    #[test]
+    fn test_assemble_image() {
+        for seed in 0..3 {
+            let (data, expected, _) = cut_image(&mut Rng::new(seed), 3, 10);
+            let image = assemble_image(&data, 10).unwrap();
+            assert!(expected.orientations().contains(&image));
+        }
+
+        let (data, _, _) = cut_image(&mut Rng::new(7), 2, 10);
+        let three_tiles = data.rsplit_once("\n\n").unwrap().0;
+        assert!(assemble_image(three_tiles, 10).is_err());
+        let (data, _, _) = cut_image(&mut Rng::new(7), 2, 10);
+        let garbled = data.replacen("#", ".", 40);
+        assert!(assemble_image(&garbled, 10).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let (data, expected, _) = cut_image(&mut Rng::new(3), 2, TILE_SIZE);
+        let scene = Day20::render(&data, Part::One).unwrap().unwrap();
+        assert_eq!((scene.width(), scene.height()), (16.0, 16.0));
+        let lit = expected.cells().filter(|&&lit| lit).count();
+        assert_eq!(scene.shapes().len(), lit);
+    }
+}
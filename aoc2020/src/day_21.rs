@@ -0,0 +1,295 @@
+//! This module contains the code
+//! for the solution of the twenty-first day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/21).
+use crate::generate::lines;
+use crate::parse::{self, Cursor, PResult};
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// The solution to the twenty-first day's challenges.
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(food_list(rng, size))
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+type INGREDIENTS<'a> = HashSet<&'a str>;
+#[allow(clippy::upper_case_acronyms)]
+type ALLERGENS<'a> = Vec<&'a str>;
+#[allow(clippy::upper_case_acronyms)]
+type RECIPES<'a> = Vec<(ALLERGENS<'a>, INGREDIENTS<'a>)>;
+
+/// Count the number of times ingredients not containing allergens
+/// appear in recipes.
+pub fn task_1(data: &[String]) -> Result<usize> {
+    let recipes = parse_recipes(data)?;
+    let identified = get_ingredients_with_allergens(&recipes)?;
+    let identified_ingredients = identified.values().collect::<HashSet<_>>();
+
+    Ok(recipes
+        .iter()
+        .map(|(_, v)| v)
+        .flat_map(|v| v.iter())
+        .filter(|&&x| !identified_ingredients.contains(&x))
+        .count())
+}
+
+/// Identify the ingredients containing allergens, sort them according to their allergens and
+/// print the names of these ingredients in the sorted order separated by a comma.
+pub fn task_2(data: &[String]) -> Result<String> {
+    let recipes = parse_recipes(data)?;
+    let identified = get_ingredients_with_allergens(&recipes)?;
+
+    Ok(identified
+        .iter()
+        .sorted_by(|(allergen_1, _), (allergen_2, _)| Ord::cmp(&allergen_1, &allergen_2))
+        .map(|(_, ingredient)| ingredient)
+        .join(","))
+}
+
+/// Get a map relating the allergens (keys) with the ingredients they are contained in (values).
+fn get_ingredients_with_allergens<'a>(
+    recipes: &[(ALLERGENS<'a>, INGREDIENTS<'a>)],
+) -> Result<HashMap<&'a str, &'a str>> {
+    let allergens: BTreeSet<&&str> = recipes
+        .iter()
+        .map(|(a, _)| a)
+        .flat_map(|v| v.iter())
+        .collect();
+
+    let mut identified = HashMap::new();
+    let mut identified_ingredients = HashSet::new();
+
+    while identified_ingredients.len() < allergens.len() {
+        // For each allergen (that has not yet been identified), collect all ingredients
+        // (which are not yet identified) in which it could be contained.
+        let candidates = allergens
+            .iter()
+            .filter(|&&a| !identified.contains_key(a))
+            .find_map(|&a| {
+                // TODO: Use fold_first / reduce here once it becomes stable
+                let mut candidates = recipes.iter().filter(|(key, _)| key.as_slice().contains(a));
+
+                let first_set = candidates.next().unwrap().1.to_owned();
+                let candidates = candidates
+                    .fold(first_set, |acc, (_, ings)| {
+                        acc.intersection(ings).cloned().collect::<HashSet<_>>()
+                    })
+                    .into_iter()
+                    .filter(|&v| !identified_ingredients.contains(&v))
+                    .collect::<Vec<_>>();
+
+                if candidates.len() == 1 {
+                    Some((a, candidates[0]))
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(|| {
+                AocError::unsolvable(
+                    "the allergens cannot be assigned to ingredients unambiguously",
+                )
+            })?;
+
+        crate::trace!(
+            "assign",
+            step = identified.len() + 1,
+            allergen = candidates.0,
+            ingredient = candidates.1,
+        );
+        identified.insert(*candidates.0, candidates.1);
+        identified_ingredients.insert(candidates.1);
+    }
+    Ok(identified)
+}
+
+/// Parse the recipes of all the lines of the input.
+fn parse_recipes(data: &[String]) -> Result<RECIPES<'_>> {
+    parse::each_line(data, parse_recipe)
+}
+
+/// Parse the recipe and return the allergens and ingredients in a tuple.
+///
+/// A recipe is of the format `<ingredients> (contains <allergens>)`.
+fn parse_recipe<'a>(cursor: &mut Cursor<'a>) -> PResult<(ALLERGENS<'a>, INGREDIENTS<'a>)> {
+    let ingredients = cursor.list(" ", |c| c.word())?;
+    cursor.tag(" (contains ")?;
+    let allergens = cursor.list(", ", |c| c.word())?;
+    cursor.tag(")")?;
+    Ok((allergens, ingredients.into_iter().collect()))
+}
+
+/// The allergens the foods can contain.
+const ALLERGENS: [&str; 8] = [
+    "dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat",
+];
+
+/// A random list of `size` foods, whose allergens can be assigned to their
+/// ingredients by elimination.
+///
+/// The allergens are identified in a random order: the foods listing the
+/// `k`-th allergen only have the ingredients of the first `k` allergens in
+/// common.
+pub fn food_list(rng: &mut Rng, size: usize) -> Sample {
+    let mut allergens = ALLERGENS.to_vec();
+    rng.shuffle(&mut allergens);
+    allergens.truncate((size / 4).clamp(1, ALLERGENS.len()));
+    let mut names = HashSet::new();
+    while names.len() < 5 * allergens.len() {
+        let len = rng.range(4..=8);
+        names.insert(rng.word(len));
+    }
+    let mut names = names.into_iter().sorted().collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    let (dangerous, safe) = names.split_at(allergens.len());
+
+    let mut foods = vec![];
+    for k in 0..allergens.len() {
+        // Two foods without any safe ingredient in common.
+        let mut shuffled = safe.to_vec();
+        rng.shuffle(&mut shuffled);
+        for half in shuffled.chunks(safe.len() / 2 + 1) {
+            let mut ingredients = dangerous[..=k].to_vec();
+            ingredients.extend(half.iter().filter(|_| rng.chance(0.7)).cloned());
+            foods.push((vec![allergens[k]], ingredients));
+        }
+    }
+    while foods.len() < size {
+        let mut listed = allergens.clone();
+        rng.shuffle(&mut listed);
+        listed.truncate(rng.range(1..=3.min(allergens.len())));
+        let ingredients = names
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| {
+                let listed = i < allergens.len() && listed.contains(&allergens[i]);
+                listed || rng.chance(0.3)
+            })
+            .map(|(_, name)| name.clone())
+            .collect::<Vec<_>>();
+        foods.push((listed, ingredients));
+    }
+
+    let safe_count = foods
+        .iter()
+        .flat_map(|(_, ingredients)| ingredients)
+        .filter(|&ingredient| safe.contains(ingredient))
+        .count();
+    let canonical = allergens
+        .iter()
+        .zip(dangerous)
+        .sorted()
+        .map(|(_, ingredient)| ingredient)
+        .join(",");
+
+    rng.shuffle(&mut foods);
+    let foods = foods.into_iter().map(|(mut listed, mut ingredients)| {
+        rng.shuffle(&mut listed);
+        rng.shuffle(&mut ingredients);
+        format!("{} (contains {})", ingredients.join(" "), listed.join(", "))
+    });
+    Sample::new(lines(foods))
+        .with_answer(Part::One, safe_count)
+        .with_answer(Part::Two, canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace;
+
+    #[test]
+    fn test_parse_recipe() {
+        let (allergens, ingredients) = parse::parse(
+            "tplp shptr krh tfn ztrgb crsp ghvj hzr rpf rhjbx kjfnqg (contains peanuts, dairy)",
+            parse_recipe,
+        )
+        .unwrap();
+
+        // Check whether content counts match
+        assert_eq!(allergens.len(), 2);
+        assert_eq!(ingredients.len(), 11);
+
+        // Precise inspection of the ingredients contents.
+        assert!(ingredients.contains("tplp"));
+        assert!(ingredients.contains("shptr"));
+        assert!(ingredients.contains("krh"));
+        assert!(ingredients.contains("tfn"));
+        assert!(ingredients.contains("ztrgb"));
+        assert!(ingredients.contains("crsp"));
+        assert!(ingredients.contains("ghvj"));
+        assert!(ingredients.contains("hzr"));
+        assert!(ingredients.contains("rpf"));
+        assert!(ingredients.contains("rhjbx"));
+        assert!(ingredients.contains("kjfnqg"));
+        assert!(!ingredients.contains("aoc2020"));
+
+        // Precise inspection of the allergens contents
+        assert!(allergens.as_slice().contains(&"peanuts"));
+        assert!(allergens.as_slice().contains(&"dairy"));
+
+        assert_eq!(
+            parse::parse("tplp shptr krh", parse_recipe)
+                .unwrap_err()
+                .to_string(),
+            "column 15: expected ` (contains `"
+        );
+    }
+
+    #[test]
+    fn test_day_21_task_1() {
+        let input = [
+            "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)".to_string(),
+            "trh fvjkl sbzzf mxmxvkd (contains dairy)".to_string(),
+            "sqjhc fvjkl (contains soy)".to_string(),
+            "sqjhc mxmxvkd sbzzf (contains fish)".to_string(),
+        ];
+        assert_eq!(task_1(&input).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_day_21_task_2() {
+        let input = [
+            "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)".to_string(),
+            "trh fvjkl sbzzf mxmxvkd (contains dairy)".to_string(),
+            "sqjhc fvjkl (contains soy)".to_string(),
+            "sqjhc mxmxvkd sbzzf (contains fish)".to_string(),
+        ];
+        assert_eq!(task_2(&input).unwrap(), "mxmxvkd,sqjhc,fvjkl");
+
+        // The allergens are assigned in alphabetical order, as soon as only
+        // one ingredient is left that may contain them.
+        let (_, events) = trace::with_sink(vec![], || task_2(&input));
+        let events = events.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                "assign step=1 allergen=dairy ingredient=mxmxvkd",
+                "assign step=2 allergen=fish ingredient=sqjhc",
+                "assign step=3 allergen=soy ingredient=fvjkl",
+            ]
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! This module contains the code
+//! for the solution of the twenty-second day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/22).
+use crate::generate::lines;
+use crate::{AocError, Result, Rng, Sample, Solution};
+use std::collections::{HashSet, VecDeque};
+
+/// The solution to the twenty-second day's challenges.
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(random_decks(rng, size))
+    }
+}
+
+type Deck = VecDeque<usize>;
+
+/// Normal version of `Crab Combat` with the given decks.
+pub fn task_1(data: &str) -> Result<usize> {
+    let (deck_1, deck_2) = get_decks(data)?;
+
+    Ok(crab_combat(deck_1, deck_2, false)
+        .deck()
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(factor, card)| (factor + 1) * card)
+        .sum())
+}
+
+/// Recursive version of `Crab Combat` with the given decks.
+pub fn task_2(data: &str) -> Result<usize> {
+    let (deck_1, deck_2) = get_decks(data)?;
+    Ok(crab_combat(deck_1, deck_2, true)
+        .deck()
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(factor, card)| (factor + 1) * card)
+        .sum())
+}
+
+/// Get the player's deck from the input string.
+fn get_decks(data: &str) -> Result<(Deck, Deck)> {
+    let mut split = crate::blocks(data).into_iter();
+    Ok((
+        get_deck(data, split.next(), "Player 1:")?,
+        get_deck(data, split.next(), "Player 2:")?,
+    ))
+}
+
+/// Parse the deck introduced by `header` from its `section` of the input string.
+fn get_deck(data: &str, section: Option<&str>, header: &str) -> Result<Deck> {
+    let mut lines = section
+        .ok_or_else(|| AocError::unexpected_end(data, format!("expected `{}`", header)))?
+        .lines()
+        .filter(|l| !l.trim().is_empty());
+    match lines.next() {
+        Some(l) if l.trim() == header => (),
+        Some(l) => {
+            return Err(AocError::malformed_in(
+                data,
+                l,
+                format!("expected `{}`", header),
+            ))
+        }
+        None => {
+            return Err(AocError::unexpected_end(
+                data,
+                format!("expected `{}`", header),
+            ))
+        }
+    }
+    lines
+        .map(|s| {
+            s.trim()
+                .parse::<usize>()
+                .map_err(|_| AocError::malformed_in(data, s, "expected a card number"))
+        })
+        .collect()
+}
+
+/// Track who the winner and its corresponding deck is.
+#[derive(Debug, PartialEq)]
+enum Winner {
+    P1(Deck),
+    P2(Deck),
+}
+
+impl Winner {
+    /// Access the winner' s deck.
+    fn deck(&self) -> &Deck {
+        match self {
+            Self::P1(val) => val,
+            Self::P2(val) => val,
+        }
+    }
+}
+
+/// Implementation of `Crab Combat` The flag `recursive` indicates whether or not to use the
+/// recursive version of the game.
+fn crab_combat(deck_1: Deck, deck_2: Deck, recursive: bool) -> Winner {
+    play(deck_1, deck_2, recursive, &mut 0)
+}
+
+/// Play a game of `Crab Combat`, where `games` counts the games played so far,
+/// including the sub-games of the recursive version.
+fn play(mut deck_1: Deck, mut deck_2: Deck, recursive: bool, games: &mut usize) -> Winner {
+    let (mut memory_1, mut memory_2) = (HashSet::new(), HashSet::new());
+    *games += 1;
+    let game = *games;
+    let mut round = 0;
+
+    let winner = loop {
+        if memory_1.contains(&deck_1) || memory_2.contains(&deck_2) {
+            crate::trace!("repeat", game = game, round = round + 1);
+            break Winner::P1(deck_1);
+        }
+
+        memory_1.insert(deck_1.clone());
+        memory_2.insert(deck_2.clone());
+
+        if deck_1.is_empty() {
+            break Winner::P2(deck_2);
+        }
+        if deck_2.is_empty() {
+            break Winner::P1(deck_1);
+        }
+        round += 1;
+        crate::trace!(
+            "round",
+            game = game,
+            round = round,
+            deck_1 = deck_1,
+            deck_2 = deck_2,
+        );
+
+        let card_1 = deck_1.pop_front().unwrap();
+        let card_2 = deck_2.pop_front().unwrap();
+
+        let player_1_wins = if deck_1.len() < card_1 || deck_2.len() < card_2 || !recursive {
+            card_1 > card_2
+        } else {
+            let new_deck_1: Deck = deck_1.iter().take(card_1).cloned().collect();
+            let new_deck_2: Deck = deck_2.iter().take(card_2).cloned().collect();
+            matches!(
+                play(new_deck_1, new_deck_2, recursive, games),
+                Winner::P1(_)
+            )
+        };
+        if player_1_wins {
+            deck_1.push_back(card_1);
+            deck_1.push_back(card_2);
+        } else {
+            deck_2.push_back(card_2);
+            deck_2.push_back(card_1);
+        }
+    };
+    crate::trace!(
+        "winner",
+        game = game,
+        player = if matches!(winner, Winner::P1(_)) {
+            1
+        } else {
+            2
+        },
+    );
+    winner
+}
+
+/// The random decks of the two players, with `size` cards each, at most 25.
+pub fn random_decks(rng: &mut Rng, size: usize) -> Sample {
+    let count = size.clamp(1, 25);
+    let mut cards = (1..=2 * count).collect::<Vec<_>>();
+    rng.shuffle(&mut cards);
+    let (deck_1, deck_2) = cards.split_at(count);
+    Sample::new(format!(
+        "Player 1:\n{}\nPlayer 2:\n{}",
+        lines(deck_1),
+        lines(deck_2)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace;
+
+    #[test]
+    fn test_get_decks() {
+        let input = "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10";
+        let (mut deck_1, mut deck_2) = get_decks(input).unwrap();
+
+        assert_eq!(deck_1.len(), 5);
+        assert_eq!(deck_2.len(), 5);
+
+        assert_eq!(deck_1.pop_front(), Some(9));
+        assert_eq!(deck_1.pop_front(), Some(2));
+        assert_eq!(deck_1.pop_front(), Some(6));
+        assert_eq!(deck_1.pop_front(), Some(3));
+        assert_eq!(deck_1.pop_front(), Some(1));
+        assert_eq!(deck_1.pop_front(), None);
+
+        assert_eq!(deck_2.pop_front(), Some(5));
+        assert_eq!(deck_2.pop_front(), Some(8));
+        assert_eq!(deck_2.pop_front(), Some(4));
+        assert_eq!(deck_2.pop_front(), Some(7));
+        assert_eq!(deck_2.pop_front(), Some(10));
+        assert_eq!(deck_2.pop_front(), None);
+
+        assert!(get_decks("Player 1:\n9\n2").is_err());
+        assert!(get_decks("Player 1:\n9\nx\n\nPlayer 2:\n5").is_err());
+    }
+
+    #[test]
+    fn test_day_22_task_1() {
+        let input = "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10";
+        assert_eq!(task_1(input).unwrap(), 306);
+    }
+
+    #[test]
+    fn test_day_22_task_2() {
+        let input = "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10";
+        assert_eq!(task_2(input).unwrap(), 291);
+    }
+
+    #[test]
+    fn test_trace() {
+        let input = "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10";
+        let (_, events) = trace::with_sink(vec![], || task_1(input));
+        assert_eq!(
+            events[0].to_string(),
+            "round game=1 round=1 deck_1=[9,2,6,3,1] deck_2=[5,8,4,7,10]"
+        );
+        assert_eq!(events.len(), 30);
+        assert_eq!(events[29].to_string(), "winner game=1 player=2");
+
+        let (_, events) = trace::with_sink(vec![], || task_2(input));
+        let rounds = |game: u64| {
+            events
+                .iter()
+                .filter(|e| e.name == "round" && e.get("game").unwrap().as_u64() == Some(game))
+                .count()
+        };
+        // The first game spawns four sub-games.
+        let rounds = (1..=6).map(rounds).collect::<Vec<_>>();
+        assert_eq!(rounds, vec![17, 6, 4, 1, 1, 0]);
+        assert_eq!(events.last().unwrap().to_string(), "winner game=1 player=2");
+        let loops = "Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14";
+        let (_, events) = trace::with_sink(vec![], || task_2(loops));
+        assert!(events.iter().any(|e| e.name == "repeat"));
+    }
+}
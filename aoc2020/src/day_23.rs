@@ -0,0 +1,357 @@
+//! This module contains the code
+//! for the solution of the twenty-third day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/23).
+use crate::animate::{self, Frame, Frames};
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+
+/// The solution to the twenty-third day's challenges.
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        let labels = data.trim();
+        labels
+            .chars()
+            .map(|c| {
+                c.to_digit(10).map(|d| d as usize).ok_or_else(|| {
+                    AocError::malformed_in(data, labels, format!("unexpected cup label `{}`", c))
+                })
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input, 100)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input, 10_000_000)
+    }
+
+    fn animate(input: &Self::Input, part: Part) -> Result<Option<Frames>> {
+        validate_cups(input)?;
+        let (cups, moves) = match part {
+            Part::One => (input.len(), 100),
+            Part::Two => (input.len().max(1_000_000), 10_000_000),
+        };
+        Ok(Some(animate::simulate(
+            (CupCircle::from_input(input, cups), 0),
+            move |(cup_circle, moved)| {
+                *moved < moves && {
+                    cup_circle.move_once();
+                    *moved += 1;
+                    true
+                }
+            },
+            |(cup_circle, moved)| cup_circle.frame(*moved),
+        )))
+    }
+
+    fn generate(rng: &mut Rng, _size: usize) -> Option<Sample> {
+        Some(cup_labels(rng))
+    }
+}
+
+/// The number of cups shown by the animation, starting with the current one.
+const SHOWN_CUPS: usize = 20;
+
+/// Crab cups: Get the labels on the cups after cup 1 after `moves` moves.
+pub fn task_1(input: &[usize], moves: usize) -> Result<usize> {
+    validate_cups(input)?;
+    if input.len() > 9 {
+        return Err(AocError::unsolvable(
+            "the labels of more than nine cups cannot be concatenated",
+        ));
+    }
+    let mut cup_circle = CupCircle::from_input(input, input.len());
+
+    for _ in 0..moves {
+        cup_circle.move_once();
+    }
+
+    Ok(cup_circle
+        .get_config()
+        .into_iter()
+        .cycle()
+        .skip_while(|&x| x != 1)
+        .skip(1)
+        .take(input.len() - 1)
+        .enumerate()
+        .map(|(i, x)| 10usize.pow((input.len() - i - 2) as u32) * x)
+        .sum::<usize>())
+}
+
+/// Play a huge `crab cups` game, find the cups next to cup 1 and return their product.
+pub fn task_2(input: &[usize], moves: usize) -> Result<usize> {
+    validate_cups(input)?;
+    let mut cup_circle = CupCircle::from_input(input, input.len().max(1_000_000));
+    for _ in 0..moves {
+        cup_circle.move_once();
+    }
+    let final_config = cup_circle.get_config();
+    let len = final_config.len();
+    let pos = final_config.iter().position(|&x| x == 1).unwrap();
+    Ok(final_config[(pos + 1) % len] * final_config[(pos + 2) % len])
+}
+
+/// Make sure the cups are labelled from 1 up to their number, such that a move,
+/// which picks up three cups next to the current one, is always possible.
+fn validate_cups(input: &[usize]) -> Result<()> {
+    let mut labels = input.to_vec();
+    labels.sort_unstable();
+    if input.len() < 5 || labels.iter().enumerate().any(|(i, &l)| l != i + 1) {
+        return Err(AocError::unsolvable(format!(
+            "the cups need to be labelled from 1 to their number (at least 5), got {:?}",
+            input
+        )));
+    }
+    Ok(())
+}
+
+/// A circle of cups which is the focus of the `crab cups` game.
+/// For each cup `c` (located at `c-1` in `neighbors`, i.e. the
+/// cup labels are 1-based), we store the label of its neighboring
+/// cup. The `current` cup under consideration is also tracked.
+struct CupCircle {
+    neighbors: Vec<usize>,
+    current: usize,
+}
+
+impl CupCircle {
+    /// Construct a `CupCircle` with `cups` cups from `input`.
+    fn from_input(input: &[usize], cups: usize) -> CupCircle {
+        let mut neighbors: Vec<usize> = (2..=cups + 1).collect();
+        neighbors[cups - 1] = input[0];
+        for i in 0..input.len() - 1 {
+            neighbors[input[i] - 1] = input[i + 1];
+        }
+        if cups > input.len() {
+            neighbors[cups - 1] = input[0];
+            neighbors[input[input.len() - 1] - 1] = input.len() + 1;
+        } else {
+            neighbors[input[input.len() - 1] - 1] = input[0];
+        }
+
+        Self {
+            neighbors,
+            current: input[0],
+        }
+    }
+
+    /// Get the current configuration.
+    fn get_config(&self) -> Vec<usize> {
+        let mut cup = self.current;
+        let mut v = Vec::with_capacity(self.neighbors.len());
+        for _ in 0..self.neighbors.len() {
+            v.push(cup);
+
+            cup = if cup == 0 {
+                self.neighbors.len()
+            } else {
+                self.neighbors[cup - 1]
+            };
+        }
+
+        v
+    }
+
+    /// The labels of `count` cups in clockwise order, starting with `cup`.
+    fn following(&self, cup: usize, count: usize) -> Vec<usize> {
+        std::iter::successors(Some(cup), |&cup| Some(self.neighbors[cup - 1]))
+            .take(count)
+            .collect()
+    }
+
+    /// Draw the cups starting with the current one, which is highlighted
+    /// along with the three cups it picks up in the next move.
+    fn frame(&self, moves: usize) -> Frame {
+        let labels = self
+            .following(self.current, self.neighbors.len().min(SHOWN_CUPS))
+            .iter()
+            .map(|cup| format!("{} ", cup))
+            .collect::<Vec<_>>();
+        let segments = labels.iter().enumerate().map(|(i, label)| {
+            let color = match i {
+                0 => Some(0),
+                1..=3 => Some(1),
+                _ => None,
+            };
+            (label.as_str(), color)
+        });
+        let after_one = self.following(1, 3);
+        let caption = format!(
+            "move {}: cup 1 is followed by cups {} and {}",
+            moves, after_one[1], after_one[2]
+        );
+        Frame::line(caption, segments)
+    }
+
+    /// Perform one movement.
+    fn move_once(&mut self) {
+        // Get next three neighbours
+        let n1 = self.neighbors[self.current - 1];
+        let n2 = self.neighbors[n1 - 1];
+        let n3 = self.neighbors[n2 - 1];
+
+        let mut destination = self.current - 1;
+        if destination == 0 {
+            destination = self.neighbors.len();
+        }
+        while destination == n1 || destination == n2 || destination == n3 {
+            destination -= 1;
+            if destination == 0 {
+                destination = self.neighbors.len()
+            }
+        }
+
+        self.neighbors[self.current - 1] = self.neighbors[n3 - 1];
+        let tmp = self.neighbors[destination - 1];
+        self.neighbors[destination - 1] = n1;
+        self.neighbors[n3 - 1] = tmp;
+        self.current = self.neighbors[self.current - 1];
+    }
+}
+
+/// The labels of nine cups in random order.
+pub fn cup_labels(rng: &mut Rng) -> Sample {
+    let mut labels = (1..=9).map(|label| label.to_string()).collect::<Vec<_>>();
+    rng.shuffle(&mut labels);
+    Sample::new(labels.concat() + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_cup_circle() {
+        let mut cup_circle = CupCircle::from_input(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 9);
+        assert_eq!(cup_circle.neighbors, vec![2, 5, 8, 6, 4, 7, 3, 9, 1]);
+        assert_eq!(cup_circle.current, 3);
+
+        cup_circle.move_once();
+
+        assert_eq!(cup_circle.neighbors, vec![5, 8, 2, 6, 4, 7, 3, 9, 1]);
+        assert_eq!(cup_circle.current, 2);
+        assert_eq!(cup_circle.get_config(), vec![2, 8, 9, 1, 5, 4, 6, 7, 3]);
+
+        let cup_circle = CupCircle::from_input(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 20);
+        assert_eq!(
+            cup_circle.neighbors,
+            vec![2, 5, 8, 6, 4, 7, 10, 9, 1, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 3]
+        );
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut frames = Day23::animate(&vec![3, 8, 9, 1, 2, 5, 4, 6, 7], Part::One)
+            .unwrap()
+            .unwrap();
+        let frame = frames.next().unwrap();
+        assert_eq!(frame.glyphs.render(|g| g.symbol), "3 8 9 1 2 5 4 6 7 ");
+        assert_eq!(frame.glyphs[(0, 0)].color, Some(0));
+        assert_eq!(frame.glyphs[(0, 6)].color, Some(1));
+        assert_eq!(frame.glyphs[(0, 8)].color, None);
+        let frame = frames.next().unwrap();
+        assert_eq!(frame.glyphs.render(|g| g.symbol), "2 8 9 1 5 4 6 7 3 ");
+        assert_eq!(frame.caption, "move 1: cup 1 is followed by cups 5 and 4");
+        assert_eq!(frames.count(), 99);
+
+        let mut frames = Day23::animate(&vec![3, 8, 9, 1, 2, 5, 4, 6, 7], Part::Two)
+            .unwrap()
+            .unwrap();
+        let frame = frames.next().unwrap();
+        assert_eq!(
+            frame.glyphs.render(|g| g.symbol),
+            "3 8 9 1 2 5 4 6 7 10 11 12 13 14 15 16 17 18 19 20 "
+        );
+        assert!(Day23::animate(&vec![3, 1, 2], Part::One).is_err());
+    }
+
+    #[test]
+    fn test_day_23_parse() {
+        assert_eq!(
+            Day23::parse("389125467\n").unwrap(),
+            vec![3, 8, 9, 1, 2, 5, 4, 6, 7]
+        );
+        assert!(Day23::parse("38912a467").is_err());
+    }
+
+    #[test]
+    fn test_day_23_task_1() {
+        assert_eq!(task_1(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 10).unwrap(), 92658374);
+        assert_eq!(task_1(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 100).unwrap(), 67384529);
+        assert!(task_1(&[3, 8, 9, 1, 2, 5, 4, 6, 6], 10).is_err());
+        assert!(task_1(&[3, 1, 2], 10).is_err());
+    }
+
+    #[test]
+    fn test_day_23_task_2() {
+        assert_eq!(
+            task_2(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 10_000_000).unwrap(),
+            149245887792
+        );
+    }
+
+    /// The cups after `moves` moves, starting with the current one, by moving
+    /// them around in a double-ended queue whose front is the current cup.
+    fn oracle(input: &[usize], cups: usize, moves: usize) -> Vec<usize> {
+        let mut circle = input
+            .iter()
+            .copied()
+            .chain(input.len() + 1..=cups)
+            .collect::<VecDeque<_>>();
+        for _ in 0..moves {
+            let current = circle.pop_front().unwrap();
+            let picked = circle.drain(..3).collect::<Vec<_>>();
+            let mut destination = current;
+            loop {
+                destination = if destination == 1 {
+                    cups
+                } else {
+                    destination - 1
+                };
+                if !picked.contains(&destination) {
+                    break;
+                }
+            }
+            let at = circle.iter().position(|&cup| cup == destination).unwrap();
+            for (i, &cup) in picked.iter().enumerate() {
+                circle.insert(at + 1 + i, cup);
+            }
+            circle.push_back(current);
+        }
+        circle.into_iter().collect()
+    }
+
+    #[test]
+    fn test_cup_circle_oracle() {
+        let mut rng = Rng::new(23);
+        for _ in 0..200 {
+            let mut input = (1..=rng.range(5..=12)).collect::<Vec<_>>();
+            rng.shuffle(&mut input);
+            let cups = input.len() + rng.range(0..=10);
+            let moves = rng.range(0..=100);
+
+            let mut cup_circle = CupCircle::from_input(&input, cups);
+            for _ in 0..moves {
+                cup_circle.move_once();
+            }
+            assert_eq!(
+                cup_circle.get_config(),
+                oracle(&input, cups, moves),
+                "{:?} with {} cups after {} moves",
+                input,
+                cups,
+                moves
+            );
+        }
+    }
+}
@@ -0,0 +1,419 @@
+//! This module contains the code
+//! for the solution of the twenty-fourth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/24).
+use crate::animate::{self, Frame, Frames, Glyph};
+use crate::automaton::{Automaton, HexGrid, Life, Sparse};
+use crate::generate::lines;
+use crate::grid::Grid;
+use crate::render::Scene;
+use crate::{Part, Result, Rng, Sample, Solution};
+use std::collections::HashSet;
+
+/// The solution to the twenty-fourth day's challenges.
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn render(input: &Self::Input, part: Part) -> Result<Option<Scene>> {
+        let mut floor = floor(black_tiles(&parse_all_directions(input)?));
+        if part == Part::Two {
+            floor.run(100);
+        }
+        Ok(Some(floor_scene(&floor.alive().into_iter().collect())))
+    }
+
+    /// Part 1 shows the tiles being flipped one after the other, part 2 the
+    /// floor changing day by day.
+    fn animate(input: &Self::Input, part: Part) -> Result<Option<Frames>> {
+        let paths = parse_all_directions(input)?;
+        Ok(Some(match part {
+            Part::One => {
+                let tiles = paths.iter().map(|p| identify_tile(p)).collect::<Vec<_>>();
+                animate::simulate(
+                    (HashSet::new(), 0),
+                    move |(black, flipped)| match tiles.get(*flipped) {
+                        Some(tile) => {
+                            if !black.remove(tile) {
+                                black.insert(*tile);
+                            }
+                            *flipped += 1;
+                            true
+                        }
+                        None => false,
+                    },
+                    |(black, flipped)| {
+                        let caption = format!("{} flips: {} black tiles", flipped, black.len());
+                        Frame::new(caption, floor_glyphs(black))
+                    },
+                )
+            }
+            Part::Two => animate::simulate(
+                floor(black_tiles(&paths)),
+                |floor| {
+                    floor.generation() < 100 && {
+                        floor.run(1);
+                        true
+                    }
+                },
+                |floor| {
+                    let black = floor.alive().into_iter().collect();
+                    let caption = format!(
+                        "day {}: {} black tiles",
+                        floor.generation(),
+                        floor.population()
+                    );
+                    Frame::new(caption, floor_glyphs(&black))
+                },
+            ),
+        }))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(tile_paths(rng, size))
+    }
+}
+
+/// Count the number of black tiles after all the flips from the
+/// input have been performed.
+pub fn task_1(data: &[String]) -> Result<usize> {
+    Ok(black_tiles(&parse_all_directions(data)?).len())
+}
+
+/// Compute the number of black tiles 100 days after the tiles have been
+/// arranged according to the pattern specified in the input.
+pub fn task_2(data: &[String]) -> Result<usize> {
+    let mut floor = floor(black_tiles(&parse_all_directions(data)?));
+    floor.run(100);
+    Ok(floor.population())
+}
+
+/// The tiles that are black after flipping the tile identified by each of
+/// the `paths`, i.e. those that were flipped an odd number of times.
+fn black_tiles(paths: &[Vec<Dir>]) -> HashSet<(isize, isize)> {
+    paths.iter().fold(HashSet::new(), |mut black, path| {
+        let tile = identify_tile(path);
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+        black
+    })
+}
+
+/// The floor as a cellular automaton, whose alive cells are the black tiles.
+type Floor = Automaton<HexGrid, Sparse<(isize, isize)>, Life>;
+
+/// The floor with the `black` tiles, which are flipped daily according to the
+/// rules stated in the problem description.
+fn floor(black: HashSet<(isize, isize)>) -> Floor {
+    Automaton::sparse(HexGrid, Life::new(&[2], &[1, 2]), black)
+}
+
+/// Draw the tiles within the bounding box of the `black` tiles, with the
+/// black tiles in the first color and the white ones in the second.
+fn floor_scene(black: &HashSet<(isize, isize)>) -> Scene {
+    let (min_x, max_x, min_y, max_y) = bounding_box(black);
+    let tiles = (min_y..=max_y)
+        .flat_map(|y| (min_x..=max_x).map(move |x| (x, y)))
+        .filter(|(x, y)| (x + y) % 2 == 0)
+        // North is up, whereas the scene grows downwards.
+        .map(|tile| ((tile.0, -tile.1), if black.contains(&tile) { 0 } else { 1 }));
+    Scene::hex_grid(tiles)
+}
+
+/// Draw the tiles within the bounding box of the `black` tiles in their
+/// doubled coordinates, such that each row is offset by half a tile from
+/// its neighbours, with the black tiles as `#` and the white ones as `o`.
+fn floor_glyphs(black: &HashSet<(isize, isize)>) -> Grid<Glyph> {
+    let (min_x, max_x, min_y, max_y) = bounding_box(black);
+    let rows = (max_y - min_y + 1) as usize;
+    let cols = (max_x - min_x + 1) as usize;
+    Grid::from_fn(rows, cols, |(row, col)| {
+        // North is up, whereas the rows grow downwards.
+        let tile = (min_x + col as isize, max_y - row as isize);
+        if (tile.0 + tile.1) % 2 != 0 {
+            Glyph::plain(' ')
+        } else if black.contains(&tile) {
+            Glyph::new('#', 0)
+        } else {
+            Glyph::new('o', 1)
+        }
+    })
+}
+
+/// The bounding box `(min_x, max_x, min_y, max_y)` of the `black` tiles.
+fn bounding_box(black: &HashSet<(isize, isize)>) -> (isize, isize, isize, isize) {
+    let xs = black.iter().map(|&(x, _)| x);
+    let ys = black.iter().map(|&(_, y)| y);
+    let (min_x, max_x) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+    let (min_y, max_y) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+    (min_x, max_x, min_y, max_y)
+}
+
+/// All the directions that allow to reach one tile from the other.
+#[derive(Debug, PartialEq)]
+enum Dir {
+    E,
+    W,
+    NE,
+    NW,
+    SE,
+    SW,
+}
+
+/// Compute the tile coordinates from a set of directions, in the doubled
+/// coordinates of the [`HexGrid`].
+fn identify_tile(directions: &[Dir]) -> (isize, isize) {
+    directions.iter().fold((0, 0), |pos, dir| match dir {
+        Dir::E => (pos.0 + 2, pos.1),
+        Dir::W => (pos.0 - 2, pos.1),
+        Dir::NW => (pos.0 - 1, pos.1 + 1),
+        Dir::NE => (pos.0 + 1, pos.1 + 1),
+        Dir::SE => (pos.0 + 1, pos.1 - 1),
+        Dir::SW => (pos.0 - 1, pos.1 - 1),
+    })
+}
+
+/// Parse the directions of all the lines of the input.
+fn parse_all_directions(data: &[String]) -> Result<Vec<Vec<Dir>>> {
+    crate::parse_each(
+        data,
+        "expected a sequence of `e`, `se`, `sw`, `w`, `nw` and `ne`",
+        parse_directions,
+    )
+}
+
+/// Parse the directions from the string.
+///
+/// If `directions` contains anything but the six directions, `None` is returned.
+fn parse_directions(directions: &str) -> Option<Vec<Dir>> {
+    if !directions
+        .chars()
+        .all(|c| matches!(c, 'n' | 's' | 'e' | 'w'))
+    {
+        return None;
+    }
+    directions
+        .replace("nw", "x")
+        .replace("ne", "y")
+        .replace("se", "z")
+        .replace("sw", "v")
+        .chars()
+        .map(|c| match c {
+            'e' => Some(Dir::E),
+            'w' => Some(Dir::W),
+            'x' => Some(Dir::NW),
+            'y' => Some(Dir::NE),
+            'z' => Some(Dir::SE),
+            'v' => Some(Dir::SW),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A random list of `size` paths to the tiles to flip, some of which lead to
+/// the same tile.
+pub fn tile_paths(rng: &mut Rng, size: usize) -> Sample {
+    let steps = [
+        ("e", (1, 0)),
+        ("se", (0, 1)),
+        ("sw", (-1, 1)),
+        ("w", (-1, 0)),
+        ("nw", (0, -1)),
+        ("ne", (1, -1)),
+    ];
+    let mut paths: Vec<Vec<usize>> = vec![];
+    let mut black = HashSet::new();
+    for _ in 0..size {
+        let mut path = match paths.is_empty() || rng.chance(0.8) {
+            true => (0..rng.range(5..=20)).map(|_| rng.below(6)).collect(),
+            // Flip a tile again, taking another route.
+            false => rng.pick(&paths).clone(),
+        };
+        rng.shuffle(&mut path);
+        let tile = path.iter().fold((0, 0), |(q, r), &step| {
+            let (dq, dr) = steps[step].1;
+            (q + dq, r + dr)
+        });
+        if !black.insert(tile) {
+            black.remove(&tile);
+        }
+        paths.push(path);
+    }
+    let paths = paths
+        .iter()
+        .map(|path| path.iter().map(|&step| steps[step].0).collect::<String>());
+    Sample::new(lines(paths)).with_answer(Part::One, black.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::Shape;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_parse_directions() {
+        use Dir::*;
+        let directions = parse_directions("wsenenewsewwnese").unwrap();
+        assert_eq!(directions, vec![W, SE, NE, NE, W, SE, W, W, NE, SE]);
+
+        let directions = parse_directions("neeenesenwnwwswnenewnwwsewnenwseswesw").unwrap();
+        assert_eq!(
+            directions,
+            vec![NE, E, E, NE, SE, NW, NW, W, SW, NE, NE, W, NW, W, SE, W, NE, NW, SE, SW, E, SW]
+        );
+
+        assert_eq!(parse_directions("wsen"), None);
+        assert_eq!(parse_directions("wxe"), None);
+    }
+
+    #[test]
+    fn test_identify_tile() {
+        let directions = parse_directions("wsenenewsewwnese").unwrap();
+        assert_eq!(identify_tile(&directions), (-2, 0));
+    }
+
+    #[test]
+    fn test_day_24_task_1() {
+        let input = [
+            "sesenwnenenewseeswwswswwnenewsewsw".to_string(),
+            "neeenesenwnwwswnenewnwwsewnenwseswesw".to_string(),
+            "seswneswswsenwwnwse".to_string(),
+            "nwnwneseeswswnenewneswwnewseswneseene".to_string(),
+            "swweswneswnenwsewnwneneseenw".to_string(),
+            "eesenwseswswnenwswnwnwsewwnwsene".to_string(),
+            "sewnenenenesenwsewnenwwwse".to_string(),
+            "wenwwweseeeweswwwnwwe".to_string(),
+            "wsweesenenewnwwnwsenewsenwwsesesenwne".to_string(),
+            "neeswseenwwswnwswswnw".to_string(),
+            "nenwswwsewswnenenewsenwsenwnesesenew".to_string(),
+            "enewnwewneswsewnwswenweswnenwsenwsw".to_string(),
+            "sweneswneswneneenwnewenewwneswswnese".to_string(),
+            "swwesenesewenwneswnwwneseswwne".to_string(),
+            "enesenwswwswneneswsenwnewswseenwsese".to_string(),
+            "wnwnesenesenenwwnenwsewesewsesesew".to_string(),
+            "nenewswnwewswnenesenwnesewesw".to_string(),
+            "eneswnwswnwsenenwnwnwwseeswneewsenese".to_string(),
+            "neswnwewnwnwseenwseesewsenwsweewe".to_string(),
+            "wseweeenwnesenwwwswnew".to_string(),
+        ];
+
+        let tile_ids = input
+            .iter()
+            .map(|d| identify_tile(&parse_directions(d).unwrap()))
+            .collect::<HashSet<_>>();
+        assert_eq!(tile_ids.len(), 15);
+        assert_eq!(task_1(&input).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_floor() {
+        let mut floor = floor(vec![(2, 0), (1, 1)].into_iter().collect());
+        assert_eq!(floor.population(), 2);
+        assert_eq!(floor.live_neighbours((0, 0)), 2);
+        assert_eq!(floor.live_neighbours((2, 0)), 1);
+
+        floor.run(1);
+
+        assert_eq!(floor.population(), 4);
+
+        assert!(floor.is_alive((2, 0)));
+        assert!(floor.is_alive((1, 1)));
+        assert!(floor.is_alive((3, 1)));
+        assert!(floor.is_alive((0, 0)));
+
+        let input = [
+            "sesenwnenenewseeswwswswwnenewsewsw".to_string(),
+            "neeenesenwnwwswnenewnwwsewnenwseswesw".to_string(),
+            "seswneswswsenwwnwse".to_string(),
+            "nwnwneseeswswnenewneswwnewseswneseene".to_string(),
+            "swweswneswnenwsewnwneneseenw".to_string(),
+            "eesenwseswswnenwswnwnwsewwnwsene".to_string(),
+            "sewnenenenesenwsewnenwwwse".to_string(),
+            "wenwwweseeeweswwwnwwe".to_string(),
+            "wsweesenenewnwwnwsenewsenwwsesesenwne".to_string(),
+            "neeswseenwwswnwswswnw".to_string(),
+            "nenwswwsewswnenenewsenwsenwnesesenew".to_string(),
+            "enewnwewneswsewnwswenweswnenwsenwsw".to_string(),
+            "sweneswneswneneenwnewenewwneswswnese".to_string(),
+            "swwesenesewenwneswnwwneseswwne".to_string(),
+            "enesenwswwswneneswsenwnewswseenwsese".to_string(),
+            "wnwnesenesenenwwnenwsewesewsesesew".to_string(),
+            "nenewswnwewswnenesenwnesewesw".to_string(),
+            "eneswnwswnwsenenwnwnwwseeswneewsenese".to_string(),
+            "neswnwewnwnwseenwseesewsenwsweewe".to_string(),
+            "wseweeenwnesenwwwswnew".to_string(),
+        ];
+
+        let mut floor = super::floor(black_tiles(&parse_all_directions(&input).unwrap()));
+        assert_eq!(floor.population(), 10);
+
+        let daily = floor.generations().take(7).collect::<Vec<_>>();
+        assert_eq!(daily, vec![15, 12, 25, 14, 23, 28, 41]);
+
+        floor.run(93);
+        assert_eq!(floor.population(), 2208);
+        assert_eq!(task_2(&input).unwrap(), 2208);
+    }
+
+    #[test]
+    fn test_floor_scene() {
+        let black = vec![(0, 0), (3, 1)].into_iter().collect::<HashSet<_>>();
+        let scene = floor_scene(&black);
+        let colors = scene
+            .shapes()
+            .iter()
+            .map(|shape| match shape {
+                Shape::Polygon { color, .. } => *color,
+                _ => panic!("tiles are drawn as hexagons"),
+            })
+            .collect::<Vec<_>>();
+        // The bounding box holds (0, 0) and (2, 0) in the first row and
+        // (1, 1) and (3, 1) in the second.
+        assert_eq!(colors, vec![0, 1, 1, 0]);
+
+        assert_eq!(floor_glyphs(&black).render(|g| g.symbol), " o #\n# o ");
+    }
+
+    #[test]
+    fn test_animate() {
+        let input = vec![
+            "esew".to_string(),
+            "nwwswee".to_string(),
+            "esew".to_string(),
+        ];
+        let frames = Day24::animate(&input, Part::One)
+            .unwrap()
+            .unwrap()
+            .map(|frame| frame.caption)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            frames,
+            vec![
+                "0 flips: 0 black tiles",
+                "1 flips: 1 black tiles",
+                "2 flips: 2 black tiles",
+                "3 flips: 1 black tiles"
+            ]
+        );
+        let frames = Day24::animate(&input, Part::Two).unwrap().unwrap();
+        assert_eq!(frames.count(), 101);
+    }
+}
@@ -0,0 +1,115 @@
+//! This module contains the code
+//! for the solution of the twenty-fifth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/25).
+use crate::math::{discrete_log_from, pow_mod};
+use crate::{AocError, Part, Result, Rng, Sample, Solution, Unsolved};
+
+/// The solution to the twenty-fifth day's challenges.
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<Self::Answer2> {
+        Ok(Unsolved)
+    }
+
+    fn generate(rng: &mut Rng, _size: usize) -> Option<Sample> {
+        Some(public_keys(rng))
+    }
+}
+
+/// Compute the encryption key the handshake is trying to establish.
+pub fn task_1(data: &[usize]) -> Result<usize> {
+    if data.len() != 2 {
+        return Err(AocError::unsolvable(format!(
+            "expected the public keys of the card and the door, got {} numbers",
+            data.len()
+        )));
+    }
+    if let Some(i) = data.iter().position(|&key| key >= MODULUS) {
+        return Err(AocError::malformed(
+            i + 1,
+            &data[i].to_string(),
+            format!("expected a public key below {}", MODULUS),
+        ));
+    }
+    let loop_size = determine_loop_size(7, data[1]).ok_or_else(|| {
+        AocError::unsolvable(format!(
+            "public key {} cannot be generated by any loop size",
+            data[1]
+        ))
+    })?;
+    Ok(transform(loop_size, data[0]))
+}
+
+/// The modulus of the transformation used by the handshake.
+const MODULUS: usize = 20201227;
+
+/// Given the `subject_number` of the transformation and the `public_key` it should
+/// generate, determine its loop size.
+///
+/// The loop size is the discrete logarithm of `public_key` to the base
+/// `subject_number`, where the loop runs at least once. If no loop size
+/// generates `public_key`, `None` is returned.
+fn determine_loop_size(subject_number: usize, public_key: usize) -> Option<usize> {
+    discrete_log_from(subject_number, public_key, MODULUS, 1)
+}
+
+/// The transformation used by the handshake.
+fn transform(loop_size: usize, subject: usize) -> usize {
+    pow_mod(subject, loop_size, MODULUS)
+}
+
+/// The public keys of the card and the door for random loop sizes.
+pub fn public_keys(rng: &mut Rng) -> Sample {
+    let card_loop_size = rng.range(1..=MODULUS - 2);
+    let door_loop_size = rng.range(1..=MODULUS - 2);
+    let (card_key, door_key) = (transform(card_loop_size, 7), transform(door_loop_size, 7));
+    Sample::new(format!("{}\n{}\n", card_key, door_key))
+        .with_answer(Part::One, transform(card_loop_size, door_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_determine_loop_size() {
+        assert_eq!(determine_loop_size(7, 5764801), Some(8));
+        assert_eq!(determine_loop_size(7, 17807724), Some(11));
+        assert_eq!(determine_loop_size(7, 20201227), None);
+        // 7 generates all the residues, so 1 takes a full cycle.
+        assert_eq!(determine_loop_size(7, 1), Some(MODULUS - 1));
+        assert_eq!(transform(MODULUS - 1, 7), 1);
+    }
+
+    #[test]
+    fn test_day_25_task_1() {
+        assert_eq!(task_1(&[5764801, 17807724]).unwrap(), 14897079);
+        assert!(task_1(&[5764801]).is_err());
+        for keys in [[20201227, 17807724], [5764801, 20201227]] {
+            let err = task_1(&keys).unwrap_err();
+            assert!(matches!(err, AocError::MalformedLine(_)), "{:?}", err);
+            assert!(err.to_string().contains("20201227"));
+        }
+        assert!(task_1(&[5764801, 20201228])
+            .unwrap_err()
+            .to_string()
+            .contains("line 2"));
+        // A full cycle of the transformation leads back to one.
+        assert_eq!(task_1(&[5764801, 1]).unwrap(), 1);
+    }
+}
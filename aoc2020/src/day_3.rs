@@ -0,0 +1,128 @@
+//! This module contains the code
+//! for the solution of the third day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/3).
+use crate::{AocError, Grid, Part, Result, Rng, Sample, Solution};
+
+/// The solution to the third day's challenges.
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<bool>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        parse_terrain(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input, 3, 1)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input, &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)])
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(random_terrain(rng, size))
+    }
+}
+
+/// Parse the terrain from its map, in which each line corresponds to one
+/// level of the terrain, and trees (`#`) are marked as `true` and open
+/// squares (`.`) as `false`.
+pub fn parse_terrain(data: &str) -> Result<Grid<bool>> {
+    Grid::parse(data, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+/// Compute the solution to task 1 of day 3.
+///
+/// It takes as input the `terrain`, which repeats itself to the right.
+/// The remaining to variables describe the descent strategy across
+/// the terrain, i.e. take `slope_x` steps to the right, followed
+/// by taking `slope_y` steps down.
+///
+/// The number of trees encountered during the descent is returned.
+pub fn task_1(terrain: &Grid<bool>, slope_x: usize, slope_y: usize) -> Result<u64> {
+    if slope_y == 0 {
+        return Err(AocError::unsolvable("the descent needs to move down"));
+    }
+
+    let count = (1..)
+        .map(|step| (step * slope_y, step * slope_x))
+        .take_while(|&(y, _)| y < terrain.rows())
+        .filter(|&(y, x)| terrain.get_wrapping(y as isize, x as isize) == Some(&true))
+        .count();
+    Ok(count as u64)
+}
+
+/// The solution to task two.
+///
+/// Takes a vector of `slopes` and multiplies the number of trees
+/// encountered in the descent corresponding to each slope.
+///
+/// The terrain with the empty spots and trees is stored in
+/// the grid `terrain`, where each row corresponds to one height
+/// level in the terrain.
+///
+/// The solution delegates the work to the function [`task_1`](`crate::day_3::task_1`).
+pub fn task_2(terrain: &Grid<bool>, slopes: &[(usize, usize)]) -> Result<u64> {
+    slopes
+        .iter()
+        .map(|(x, y)| task_1(terrain, *x, *y))
+        .product::<Result<u64>>()
+}
+
+/// A random terrain of `size` levels, each of which is 31 squares wide.
+pub fn random_terrain(rng: &mut Rng, size: usize) -> Sample {
+    let terrain = Grid::from_fn(size.max(1), 31, |_| rng.chance(0.25));
+    let trees = |right: usize, down: usize| {
+        (0..terrain.rows())
+            .step_by(down)
+            .skip(1)
+            .filter(|&row| terrain[(row, row / down * right % 31)])
+            .count() as u64
+    };
+    let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+    Sample::new(terrain.render(|&tree| if tree { '#' } else { '.' }) + "\n")
+        .with_answer(Part::One, trees(3, 1))
+        .with_answer(
+            Part::Two,
+            slopes.iter().map(|&(r, d)| trees(r, d)).product::<u64>(),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TERRAIN: &str = "..##.......\n#...#...#..\n.#....#..#.\n..#.#...#.#\n.#...##..#.\n\
+                           ..#.##.....\n.#.#.#....#\n.#........#\n#.##...#...\n#...##....#\n.#..#...#.#";
+
+    #[test]
+    fn test_day_3_task_1() {
+        let terrain = parse_terrain(TERRAIN).unwrap();
+        assert_eq!(task_1(&terrain, 3, 1).unwrap(), 7);
+        assert!(task_1(&terrain, 3, 0).is_err());
+    }
+
+    #[test]
+    fn test_day_3_task_2() {
+        let terrain = parse_terrain(TERRAIN).unwrap();
+        let slopes = &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+        assert_eq!(task_2(&terrain, slopes).unwrap(), 336);
+    }
+
+    #[test]
+    fn test_parse_terrain() {
+        assert_eq!(parse_terrain(".#\n##").unwrap().rows(), 2);
+        assert!(parse_terrain(".#\n#x").is_err());
+        assert!(parse_terrain(".#\n#").is_err());
+    }
+}
@@ -3,19 +3,46 @@
 //!
 //! The problem formulation for these challenges can
 //! be found [here](https://adventofcode.com/2020/day/4).
+use crate::parse::{self, Cursor, PResult};
+use crate::{Part, Result, Rng, Sample, Solution};
 use std::collections::HashMap;
 
+/// The solution to the fourth day's challenges.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(passport_batch(rng, size))
+    }
+}
+
 /// Check how many passports contain the required fields.
 ///
 /// `data` is the content of the input batch file, which contains
 /// the data of all passports, where the batches are separated by
 /// blank lines.
-pub fn task_1(data: &str) -> usize {
+pub fn task_1(data: &str) -> Result<usize> {
     let required_fields = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
-    data.split("\n\n")
-        .map(|p| passport_has_valid_fields(p, &required_fields))
-        .filter(|b| *b)
-        .count()
+    Ok(parse::each_block(data, get_passport_fields)?
+        .iter()
+        .filter(|fields| passport_has_valid_fields(fields, &required_fields))
+        .count())
 }
 
 /// Check how many passports are valid.
@@ -23,12 +50,11 @@ pub fn task_1(data: &str) -> usize {
 /// `data` is the content of the input batch file, which contains
 /// the data of all passports, where the batches are separated by
 /// blank lines.
-pub fn task_2(data: &str) -> usize {
-    data.split("\n\n")
-        .map(|p| get_passport_fields(p))
-        .map(|hm| passport_is_valid(&hm))
-        .filter(|b| b.is_some())
-        .count()
+pub fn task_2(data: &str) -> Result<usize> {
+    Ok(parse::each_block(data, get_passport_fields)?
+        .iter()
+        .filter(|fields| passport_is_valid(fields).is_some())
+        .count())
 }
 
 #[derive(Debug, PartialEq)]
@@ -63,8 +89,7 @@ pub struct Passport<'a> {
 
 /// Extract the passport fields and their corresponding data.
 ///
-/// The input `passport_batch` is the string format of the
-/// passport taken from the batch file.
+/// The input is the string format of the passport taken from the batch file.
 ///
 /// The content of this batch is of the format `key:value`, separated
 /// by spaces or new lines and these key-value pairs are returned in
@@ -72,15 +97,16 @@ pub struct Passport<'a> {
 ///
 /// Notice that no processing (e.g. parsing & validation) is performed
 /// on the values and only their raw string representation is returned.
-fn get_passport_fields(passport_batch: &str) -> HashMap<&str, &str> {
-    passport_batch
-        .split_ascii_whitespace()
-        .map(|s| {
-            let split: Vec<_> = s.split(':').collect();
-            assert_eq!(split.len(), 2);
-            (split[0], split[1])
-        })
-        .collect()
+fn get_passport_fields<'a>(cursor: &mut Cursor<'a>) -> PResult<HashMap<&'a str, &'a str>> {
+    let mut fields = HashMap::new();
+    loop {
+        cursor.skip_whitespace();
+        if cursor.is_empty() {
+            return Ok(fields);
+        }
+        let (key, value) = cursor.key_value(':')?;
+        fields.insert(key, value);
+    }
 }
 
 /// Check whether a password is valid.
@@ -92,40 +118,19 @@ fn get_passport_fields(passport_batch: &str) -> HashMap<&str, &str> {
 /// fields does not satisfy the format specified in the problem statement, `None` is
 /// returned.
 fn passport_is_valid<'a>(field_data: &HashMap<&'a str, &'a str>) -> Option<Passport<'a>> {
-    let byr = match get_year(field_data, "byr", 1920, 2002) {
-        Some(value) => value,
-        None => return None,
-    };
+    let byr = get_year(field_data, "byr", 1920, 2002)?;
 
-    let iyr = match get_year(field_data, "iyr", 2010, 2020) {
-        Some(value) => value,
-        None => return None,
-    };
+    let iyr = get_year(field_data, "iyr", 2010, 2020)?;
 
-    let eyr = match get_year(field_data, "eyr", 2020, 2030) {
-        Some(value) => value,
-        None => return None,
-    };
+    let eyr = get_year(field_data, "eyr", 2020, 2030)?;
 
-    let hgt = match get_hgt(field_data) {
-        Some(value) => value,
-        None => return None,
-    };
+    let hgt = get_hgt(field_data)?;
 
-    let hcl = match get_hcl(field_data) {
-        Some(value) => value,
-        None => return None,
-    };
+    let hcl = get_hcl(field_data)?;
 
-    let ecl = match get_ecl(field_data) {
-        Some(value) => value,
-        None => return None,
-    };
+    let ecl = get_ecl(field_data)?;
 
-    let pid = match get_pid(field_data) {
-        Some(value) => value,
-        None => return None,
-    };
+    let pid = get_pid(field_data)?;
 
     let cid = match field_data.get("cid") {
         Some(val) => val.parse::<i64>().ok(),
@@ -146,20 +151,15 @@ fn passport_is_valid<'a>(field_data: &HashMap<&'a str, &'a str>) -> Option<Passp
 
 /// Check if a password has all required fields.
 ///
-/// The first argument `raw_data` is the string representation of the passport
-/// as taken from the input batch file. It is processed by the function
-/// [`get_passport_fields`](crate::day_4::get_passport_fields).
+/// The first argument `field_data` contains the fields of the passport as
+/// obtained by [`get_passport_fields`](crate::day_4::get_passport_fields).
 ///
 /// The second argument `required_fields` contains a list of entries that are
 /// required on the passport for it to be considered valid.
-fn passport_has_valid_fields(raw_data: &str, required_fields: &[&str]) -> bool {
-    let data = get_passport_fields(raw_data);
-    for elem in required_fields {
-        if !data.contains_key(elem) {
-            return false;
-        }
-    }
-    true
+fn passport_has_valid_fields(field_data: &HashMap<&str, &str>, required_fields: &[&str]) -> bool {
+    required_fields
+        .iter()
+        .all(|elem| field_data.contains_key(elem))
 }
 
 /// Extract the year `key` from the `passport_data`.
@@ -178,54 +178,111 @@ fn get_year(passport_data: &HashMap<&str, &str>, key: &str, min: u32, max: u32)
 
 /// Get the height of the passport holder.
 fn get_hgt(passport_data: &HashMap<&str, &str>) -> Option<Height> {
-    lazy_static::lazy_static! {
-        static ref RE_HEIGHT: regex::Regex = regex::Regex::new(r"^(\d*)(in|cm)$").unwrap();
-    }
-    match passport_data.get("hgt") {
-        Some(value) => match RE_HEIGHT.captures(value) {
-            Some(v) if v.len() == 3 => match (v[1].parse::<u32>(), &v[2]) {
-                (Ok(val @ 59..=76), "in") => Some(Height::Inch(val)),
-                (Ok(val @ 150..=193), "cm") => Some(Height::Cm(val)),
-                _ => None,
-            },
-            _ => None,
-        },
-        None => None,
+    let height = parse::parse(passport_data.get("hgt")?, |c| {
+        let value = c.int()?;
+        let unit = c.keyword::<fn(u32) -> Height>(&[("in", Height::Inch), ("cm", Height::Cm)])?;
+        Ok(unit(value))
+    })
+    .ok()?;
+    match height {
+        Height::Inch(59..=76) | Height::Cm(150..=193) => Some(height),
+        _ => None,
     }
 }
 
 /// Get the hair color of the passport holder.
 fn get_hcl<'a>(passport_data: &HashMap<&'a str, &'a str>) -> Option<&'a str> {
-    lazy_static::lazy_static! {
-        static ref RE_HCL: regex::Regex = regex::Regex::new(r"#[0-9,a-f]{6}$").unwrap();
-    }
-    match passport_data.get("hcl") {
-        Some(&val) if RE_HCL.is_match(val) => Some(val),
-        _ => None,
-    }
+    let val = *passport_data.get("hcl")?;
+    parse::parse(val, |c| {
+        c.tag("#")?;
+        match c.take_while(|d| matches!(d, '0'..='9' | 'a'..='f')) {
+            digits if digits.len() == 6 => Ok(val),
+            _ => Err(c.error("six hexadecimal digits")),
+        }
+    })
+    .ok()
 }
 
 /// Get the eye color of the passport holder.
 fn get_ecl<'a>(passport_data: &HashMap<&'a str, &'a str>) -> Option<&'a str> {
-    lazy_static::lazy_static! {
-        static ref RE_ECL: regex::Regex = regex::Regex::new(r"^(amb|blu|brn|gry|grn|hzl|oth)$").unwrap();
-    }
+    let val = *passport_data.get("ecl")?;
+    let colors = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].map(|color| (color, ()));
+    parse::parse(val, |c| c.keyword(&colors)).ok().map(|_| val)
+}
 
-    match passport_data.get("ecl") {
-        Some(&val) if RE_ECL.is_match(val) => Some(val),
+/// Get the passport id..
+fn get_pid<'a>(passport_data: &HashMap<&'a str, &'a str>) -> Option<&'a str> {
+    match passport_data.get("pid") {
+        Some(&val) if val.len() == 9 && val.chars().all(|c| c.is_ascii_digit()) => Some(val),
         _ => None,
     }
 }
 
-/// Get the passport id..
-fn get_pid<'a>(passport_data: &HashMap<&'a str, &'a str>) -> Option<&'a str> {
-    lazy_static::lazy_static! {
-        static ref RE_PID: regex::Regex = regex::Regex::new(r"^(\d{9})$").unwrap();
+/// A random batch of `size` passports, some of which lack a required field
+/// or have an invalid value.
+pub fn passport_batch(rng: &mut Rng, size: usize) -> Sample {
+    let required = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    let mut passports = vec![];
+    let (mut complete, mut valid) = (0, 0);
+    for _ in 0..size {
+        let missing = rng.chance(0.2).then(|| rng.below(required.len()));
+        let invalid = rng.chance(0.4).then(|| rng.below(required.len()));
+        let mut fields = required
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| Some(i) != missing)
+            .map(|(i, key)| format!("{}:{}", key, field_value(rng, key, Some(i) != invalid)))
+            .collect::<Vec<_>>();
+        if rng.chance(0.5) {
+            fields.push(format!("cid:{}", rng.range(100..=350)));
+        }
+        rng.shuffle(&mut fields);
+
+        complete += missing.is_none() as usize;
+        valid += (missing.is_none() && invalid.is_none()) as usize;
+        let passport = fields
+            .iter()
+            .map(|field| field.to_string() + if rng.chance(0.3) { "\n" } else { " " })
+            .collect::<String>();
+        passports.push(passport.trim_end().to_string());
     }
+    Sample::new(passports.join("\n\n") + "\n")
+        .with_answer(Part::One, complete)
+        .with_answer(Part::Two, valid)
+}
 
-    match passport_data.get("pid") {
-        Some(&val) if RE_PID.is_match(val) => Some(val),
-        _ => None,
+/// A random value of the passport field `key`, which is `valid` or not.
+fn field_value(rng: &mut Rng, key: &str, valid: bool) -> String {
+    let year = |rng: &mut Rng, min: usize, max: usize| match valid {
+        true => rng.range(min..=max),
+        false if rng.chance(0.5) => rng.range(min - 20..=min - 1),
+        false => rng.range(max + 1..=max + 20),
+    };
+    let hex = |rng: &mut Rng, len| {
+        (0..len)
+            .map(|_| *rng.pick(&b"0123456789abcdef"[..]) as char)
+            .collect::<String>()
+    };
+    match (key, valid) {
+        ("byr", _) => year(rng, 1920, 2002).to_string(),
+        ("iyr", _) => year(rng, 2010, 2020).to_string(),
+        ("eyr", _) => year(rng, 2020, 2030).to_string(),
+        ("hgt", true) if rng.chance(0.5) => format!("{}cm", rng.range(150..=193)),
+        ("hgt", true) => format!("{}in", rng.range(59..=76)),
+        ("hgt", false) => match rng.below(3) {
+            0 => format!("{}cm", rng.range(194..=250)),
+            1 => format!("{}in", rng.range(40..=58)),
+            _ => rng.range(150..=193).to_string(),
+        },
+        ("hcl", true) => format!("#{}", hex(rng, 6)),
+        ("hcl", false) if rng.chance(0.5) => hex(rng, 6),
+        ("hcl", false) => format!("#{}", hex(rng, 7)),
+        ("ecl", true) => rng
+            .pick(&["amb", "blu", "brn", "gry", "grn", "hzl", "oth"])
+            .to_string(),
+        ("ecl", false) => rng.pick(&["red", "zzz", "am", "blue"]).to_string(),
+        (_, true) => format!("{:09}", rng.below(1_000_000_000)),
+        (_, false) => format!("{:010}", rng.below(1_000_000_000)),
     }
 }
 
@@ -252,7 +309,13 @@ mod tests {
         .into_iter()
         .collect();
 
-        assert_eq!(target, get_passport_fields(&input));
+        assert_eq!(target, parse::parse(input, get_passport_fields).unwrap());
+        assert_eq!(
+            parse::parse("eyr:2039 hgt", get_passport_fields)
+                .unwrap_err()
+                .to_string(),
+            "column 13: expected `:`"
+        );
     }
 
     #[test]
@@ -265,15 +328,16 @@ mod tests {
         hcl:#cfa07d byr:1929";
 
         let required_fields = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
-
-        assert_eq!(
-            passport_has_valid_fields(valid_input, &required_fields),
-            true
-        );
-        assert_eq!(
-            passport_has_valid_fields(invalid_input, &required_fields),
-            false
-        );
+        let fields = |input| parse::parse(input, get_passport_fields).unwrap();
+
+        assert!(passport_has_valid_fields(
+            &fields(valid_input),
+            &required_fields
+        ));
+        assert!(!passport_has_valid_fields(
+            &fields(invalid_input),
+            &required_fields
+        ));
     }
 
     #[test]
@@ -475,7 +539,7 @@ mod tests {
     fn test_valid_passport() {
         let valid_input = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
         hcl:#623a2f";
-        let fields = get_passport_fields(valid_input);
+        let fields = parse::parse(valid_input, get_passport_fields).unwrap();
         assert!(fields.contains_key("byr"));
         assert!(fields.contains_key("iyr"));
         assert!(fields.contains_key("eyr"));
@@ -511,7 +575,7 @@ mod tests {
     #[test]
     fn test_invalid_passport() {
         let valid_input = "hgt:97 byr:1990 iyr:2019 ecl:grn pid:587580330 hcl:#341e13 eyr:2022 ";
-        let fields = get_passport_fields(valid_input);
+        let fields = parse::parse(valid_input, get_passport_fields).unwrap();
         assert!(fields.contains_key("byr"));
         assert!(fields.contains_key("iyr"));
         assert!(fields.contains_key("eyr"));
@@ -562,7 +626,8 @@ mod tests {
         eyr:2038 hcl:74454a iyr:2023
         pid:3556412378 byr:2007";
 
-        assert_eq!(task_2(valid), 4);
-        assert_eq!(task_2(invalid), 0);
+        assert_eq!(task_2(valid).unwrap(), 4);
+        assert_eq!(task_2(invalid).unwrap(), 0);
+        assert!(task_2("byr:1980\nhcl#623a2f").is_err());
     }
 }
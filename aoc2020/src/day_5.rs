@@ -0,0 +1,225 @@
+//! This module contains the code
+//! for the solution of the fifth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/5).
+use crate::generate::lines;
+use crate::{AocError, Part, Result, Rng, Sample, Solution, Streamer};
+
+/// The solution to the fifth day's challenges.
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(boarding_passes(rng, size))
+    }
+
+    fn streamer(part: Part) -> Option<Streamer> {
+        Some(match part {
+            Part::One => |lines| Ok(task_1_stream(lines)?.to_string()),
+            Part::Two => |lines| Ok(task_2_stream(lines)?.to_string()),
+        })
+    }
+}
+
+/// Determine the maximum seat number.
+///
+/// The input `data` consists of the lines of the input
+/// file where each line contains the string representation
+/// of a seat number.
+///
+/// After parsing these representations to integers, the maximal
+/// seat number is returned.
+pub fn task_1(data: &[String]) -> Result<usize> {
+    task_1_stream(data.iter().map(Ok))
+}
+
+/// Determine our seat.
+///
+/// This is the missing seat. We need to ignore that at the beginning and at the
+/// end, some seats are missing, albeit knowing that our direct seat neighboring
+/// seat numbers are present.
+///
+/// The `input` data consists of the lines of the input file
+/// where each line contains the string representation of a
+/// seat number.
+///
+/// After parsing these representations to integers, the missing seat is the
+/// first free one between the lowest and the highest taken seat.
+pub fn task_2(data: &[String]) -> Result<usize> {
+    task_2_stream(data.iter().map(Ok))
+}
+
+/// Like [`task_1`], but the `lines` are processed one at a time as they are
+/// read, and the first malformed line aborts the search.
+pub fn task_1_stream<S, I>(lines: I) -> Result<usize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    let mut max = None;
+    for seat in crate::stream_each(lines, SEAT_FORMAT, extract_seat_number) {
+        max = max.max(Some(seat?));
+    }
+    max.ok_or_else(|| AocError::unsolvable("there are no seats"))
+}
+
+/// Like [`task_2`], but the `lines` are processed one at a time as they are
+/// read, and the first malformed line aborts the search.
+///
+/// The taken seats are marked on the map of the 1024 seats of the plane.
+pub fn task_2_stream<S, I>(lines: I) -> Result<usize>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    let mut taken = [false; 1024];
+    for seat in crate::stream_each(lines, SEAT_FORMAT, extract_seat_number) {
+        taken[seat?] = true;
+    }
+    let first = taken.iter().position(|&taken| taken);
+    let last = taken.iter().rposition(|&taken| taken);
+    first
+        .zip(last)
+        .and_then(|(first, last)| (first..last).find(|&seat| !taken[seat]))
+        .ok_or_else(|| AocError::unsolvable("there is no missing seat"))
+}
+
+/// The format of the lines of the input.
+const SEAT_FORMAT: &str = "expected 7 characters `F` or `B` followed by 3 characters `L` or `R`";
+
+/// Given the string representation of the `seat` determine the seat number.
+fn extract_row_number(seat: &str) -> usize {
+    usize::from_str_radix(
+        &seat
+            .chars()
+            .take_while(|c| c == &'F' || c == &'B')
+            .map(|c| if c == 'F' { '0' } else { '1' })
+            .collect::<String>(),
+        2,
+    )
+    .unwrap()
+}
+
+/// Given the string representation of the `seat` determine its row.
+fn extract_col_number(seat: &str) -> usize {
+    usize::from_str_radix(
+        &seat
+            .chars()
+            .skip_while(|c| c == &'F' || c == &'B')
+            .map(|c| if c == 'L' { '0' } else { '1' })
+            .collect::<String>(),
+        2,
+    )
+    .unwrap()
+}
+
+/// Given the string representation of the `seat` determine its column.
+///
+/// If the `seat` is not made of 7 characters `F` or `B` followed by
+/// 3 characters `L` or `R`, `None` is returned.
+fn extract_seat_number(seat: &str) -> Option<usize> {
+    let valid = seat.is_ascii()
+        && seat.len() == 10
+        && seat[..7].chars().all(|c| c == 'F' || c == 'B')
+        && seat[7..].chars().all(|c| c == 'L' || c == 'R');
+    if !valid {
+        return None;
+    }
+    Some(extract_row_number(seat) * 8 + extract_col_number(seat))
+}
+
+/// The random boarding passes of `size` consecutive seats, except for one
+/// seat in between which is missing.
+pub fn boarding_passes(rng: &mut Rng, size: usize) -> Sample {
+    let count = size.clamp(2, 1000);
+    let first = rng.range(0..=1023 - count);
+    let missing = rng.range(first + 1..=first + count - 1);
+    let mut seats = (first..=first + count)
+        .filter(|&seat| seat != missing)
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut seats);
+
+    let passes = seats.iter().map(|seat| {
+        (0..10)
+            .rev()
+            .map(|bit| match (bit >= 3, seat >> bit & 1 == 1) {
+                (true, false) => 'F',
+                (true, true) => 'B',
+                (false, false) => 'L',
+                (false, true) => 'R',
+            })
+            .collect::<String>()
+    });
+    Sample::new(lines(passes))
+        .with_answer(Part::One, first + count)
+        .with_answer(Part::Two, missing)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_extract_row_number() {
+        assert_eq!(extract_row_number("BFFFBBFRRR"), 70);
+        assert_eq!(extract_row_number("FFFBBBFRRR"), 14);
+        assert_eq!(extract_row_number("BBFFBBFRLL"), 102);
+    }
+
+    #[test]
+    fn test_extract_col_number() {
+        assert_eq!(extract_col_number("BFFFBBFRRR"), 7);
+        assert_eq!(extract_col_number("FFFBBBFRRR"), 7);
+        assert_eq!(extract_col_number("BBFFBBFRLL"), 4);
+    }
+    #[test]
+    fn test_extract_seat_number() {
+        assert_eq!(extract_seat_number("BFFFBBFRRR"), Some(567));
+        assert_eq!(extract_seat_number("FFFBBBFRRR"), Some(119));
+        assert_eq!(extract_seat_number("BBFFBBFRLL"), Some(820));
+        assert_eq!(extract_seat_number("BBFFBBFRL"), None);
+        assert_eq!(extract_seat_number("BBFFBBLRLF"), None);
+    }
+
+    #[test]
+    fn test_day_5_task_1() {
+        let input = [
+            "BFFFBBFRRR".to_string(),
+            "FFFBBBFRRR".to_string(),
+            "BBFFBBFRLL".to_string(),
+        ];
+        assert_eq!(task_1(&input).unwrap(), 820);
+    }
+
+    #[test]
+    fn test_day_5_stream() {
+        for seed in 0..5 {
+            let sample = boarding_passes(&mut Rng::new(seed), 100);
+            let lines = || crate::read_lines(sample.data.as_bytes());
+            let task_1 = task_1_stream(lines()).unwrap().to_string();
+            assert_eq!(sample.answer(Part::One), Some(task_1.as_str()));
+            let task_2 = task_2_stream(lines()).unwrap().to_string();
+            assert_eq!(sample.answer(Part::Two), Some(task_2.as_str()));
+        }
+        assert!(task_1_stream(["BFFFBBFRRR", "BFFFBBFRR"].iter().map(Ok)).is_err());
+        assert!(task_2_stream(["BFFFBBFRRR", "BFFFBBFRRL"].iter().map(Ok)).is_err());
+        assert!(task_1_stream(Vec::<Result<String>>::new()).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//! This module contains the code
+//! for the solution of the sixth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/6).
+use crate::{Part, Result, Rng, Sample, Solution};
+use std::collections::HashSet;
+
+/// The solution to the sixth day's challenges.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        Ok(data.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(customs_answers(rng, size))
+    }
+}
+
+/// Compute the sum of the questions answered with yes per group.
+pub fn task_1(data: &str) -> Result<usize> {
+    Ok(crate::blocks(data)
+        .into_iter()
+        .map(count_questions_yes_answers)
+        .sum())
+}
+
+/// Compute the sum of the number of questions that have been answered with yes by __all__ group members.
+pub fn task_2(data: &str) -> Result<usize> {
+    Ok(crate::blocks(data)
+        .into_iter()
+        .map(count_questions_all_yes_answers)
+        .sum())
+}
+
+/// Compute the number of questions answered with yes per group.
+fn count_questions_yes_answers(s: &str) -> usize {
+    s.chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Compute the number of questions answered with yes by __all__ people in a group.
+fn count_questions_all_yes_answers(input: &str) -> usize {
+    let members = input
+        .split_whitespace()
+        .map(|s| s.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    members.first().map_or(0, |first| {
+        first
+            .iter()
+            .filter(|c| members.iter().all(|m| m.contains(c)))
+            .count()
+    })
+}
+
+/// The random answers of `size` groups of up to five people.
+pub fn customs_answers(rng: &mut Rng, size: usize) -> Sample {
+    let mut groups = vec![];
+    let (mut anyone, mut everyone) = (0, 0);
+    for _ in 0..size {
+        let common = (0..26).filter(|_| rng.chance(0.1)).collect::<Vec<_>>();
+        let people = (0..rng.range(1..=5))
+            .map(|_| {
+                let mut answers = (0..26)
+                    .filter(|q| common.contains(q) || rng.chance(0.2))
+                    .collect::<Vec<u8>>();
+                if answers.is_empty() {
+                    answers.push(rng.below(26) as u8);
+                }
+                rng.shuffle(&mut answers);
+                answers
+            })
+            .collect::<Vec<_>>();
+
+        let all = people.iter().flatten().collect::<HashSet<_>>();
+        anyone += all.len();
+        everyone += all
+            .iter()
+            .filter(|q| people.iter().all(|answers| answers.contains(q)))
+            .count();
+        let text = people
+            .iter()
+            .map(|answers| {
+                answers
+                    .iter()
+                    .map(|q| (b'a' + q) as char)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        groups.push(text.join("\n"));
+    }
+    Sample::new(groups.join("\n\n") + "\n")
+        .with_answer(Part::One, anyone)
+        .with_answer(Part::Two, everyone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_questions_yes_answers() {
+        assert_eq!(count_questions_yes_answers("abc"), 3);
+        assert_eq!(count_questions_yes_answers("a\nb\nc"), 3);
+        assert_eq!(count_questions_yes_answers("a\na\na"), 1);
+    }
+
+    #[test]
+    fn test_count_questions_all_yes_answers() {
+        assert_eq!(count_questions_all_yes_answers("qepdrhamt\nifnd\nnxfdy"), 1);
+        assert_eq!(count_questions_all_yes_answers("a\nb\nc"), 0);
+        assert_eq!(count_questions_all_yes_answers("a\na\na"), 1);
+        assert_eq!(count_questions_all_yes_answers(""), 0);
+    }
+
+    #[test]
+    fn test_day_6_task_1() {
+        let input = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb";
+        assert_eq!(task_1(input).unwrap(), 11);
+    }
+
+    #[test]
+    fn test_day_6_task_2() {
+        let input_1 = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb";
+        let input_2 = "
+        qepdrhamt
+        ifnd
+        nxfdy";
+        assert_eq!(task_2(input_1).unwrap(), 6);
+        assert_eq!(task_2(input_2).unwrap(), 1);
+    }
+}
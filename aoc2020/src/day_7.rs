@@ -0,0 +1,371 @@
+//! This module contains the code
+//! for the solution of the seventh day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/7).
+use crate::generate::lines;
+use crate::parse::{self, Cursor, PResult};
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// The solution to the seventh day's challenges.
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(bag_rules(rng, size))
+    }
+}
+
+type BagSet<'a> = HashSet<&'a str>;
+type BagCounts<'a> = HashMap<&'a str, u32>;
+type BagContentMap<'a> = HashMap<&'a str, BagCounts<'a>>;
+
+/// Determine the number of colors top-level bags can have if they contain a shiny gold bag.
+pub fn task_1(data: &[String]) -> Result<usize> {
+    // Map listing each bag color, and the number of bags of a given color it can contain
+    let content_rules = parse_rules(data)?;
+
+    Ok(content_rules
+        .iter()
+        .filter(|(_, content)| content.contains_key(&"shiny gold"))
+        .fold(BagSet::new(), |mut acc, (&bag, _)| {
+            for parent in get_parents(bag, &content_rules) {
+                acc.insert(parent);
+            }
+            acc
+        })
+        .len())
+}
+
+/// Compute the number of bags contained in a bag of color `shiny gold`.
+///
+/// -1 because `count_contained_bags` also counts the containing bag, which is what we want
+/// to have, except for the top-level gold bag which we do not want to count, since we are only
+/// interested in its contents.
+pub fn task_2(data: &[String]) -> Result<u32> {
+    let content_rules = parse_rules(data)?;
+    count_contained_bags("shiny gold", &content_rules)
+        .map(|count| count - 1)
+        .ok_or_else(|| AocError::unsolvable("the rules for some of the bags are missing"))
+}
+
+/// Parse the rules of all the lines of the input.
+fn parse_rules(data: &[String]) -> Result<BagContentMap<'_>> {
+    Ok(parse::each_line(data, extract_color_contents)?
+        .into_iter()
+        .collect())
+}
+
+/// Compute the parents of each bag containing a shiny gold bag at some level.
+/// Notice that the bag under consideration is itself considered a possible
+/// top-level bag.
+///
+/// We loop over all keys in the bag counter map, filter those that contain
+/// `bag` and for each of these add them to the possible parent list, together
+/// with their parents.
+fn get_parents<'a>(bag: &'a str, bcm: &BagContentMap<'a>) -> BagSet<'a> {
+    bcm.keys().filter(|&key| bcm[key].contains_key(&bag)).fold(
+        vec![bag].into_iter().collect::<HashSet<_>>(),
+        |parents, key| {
+            get_parents(key, bcm)
+                .into_iter()
+                .fold(parents, |mut acc, p| {
+                    acc.insert(p);
+                    acc
+                })
+        },
+    )
+}
+
+/// Extract the color of a parent bag and the number and color of its content bags.
+///
+/// The input is a line from the input file, which formulates the rule, e.g.
+/// `light red bags contain 1 bright white bag, 2 muted yellow bags.`
+fn extract_color_contents<'a>(cursor: &mut Cursor<'a>) -> PResult<(&'a str, BagCounts<'a>)> {
+    let color = parse_color(cursor)?;
+    cursor.tag(" bags contain ")?;
+    let content = if cursor.eat("no other bags") {
+        BagCounts::new()
+    } else {
+        cursor
+            .list(", ", |c| {
+                let count = c.int()?;
+                c.tag(" ")?;
+                let color = parse_color(c)?;
+                c.tag(" bag")?;
+                c.eat("s");
+                Ok((color, count))
+            })?
+            .into_iter()
+            .collect()
+    };
+    cursor.tag(".")?;
+
+    Ok((color, content))
+}
+
+/// Parse a bag color, which consists of two words, e.g. `shiny gold`.
+fn parse_color<'a>(cursor: &mut Cursor<'a>) -> PResult<&'a str> {
+    let rest = cursor.rest();
+    let adjective = cursor.word()?;
+    cursor.tag(" ")?;
+    let color = cursor.word()?;
+    Ok(&rest[..adjective.len() + 1 + color.len()])
+}
+
+/// Count the number of bags contained in `bag`.
+///
+/// If the rule of any of the (nested) bags is missing, `None` is returned.
+fn count_contained_bags<'a>(bag: &'a str, content_map: &BagContentMap<'a>) -> Option<u32> {
+    content_map
+        .get(bag)?
+        .iter()
+        .map(|(key, &count)| Some(count * count_contained_bags(key, content_map)?))
+        .sum::<Option<u32>>()
+        .map(|sum| sum + 1) // + 1 because we also count the containing bag
+}
+
+/// The adjectives and colors making up the colors of the bags.
+const ADJECTIVES: [&str; 18] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+const COLORS: [&str; 33] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+];
+
+/// Random rules for `size` bags of different colors, one of which is `shiny gold`.
+///
+/// The bags are arranged in six levels, where a bag only contains bags of the
+/// levels below its own, such that the number of nested bags stays small.
+pub fn bag_rules(rng: &mut Rng, size: usize) -> Sample {
+    let mut colors = ADJECTIVES
+        .iter()
+        .cartesian_product(COLORS.iter())
+        .map(|(adjective, color)| format!("{} {}", adjective, color))
+        .filter(|color| color != "shiny gold")
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut colors);
+    colors.truncate(size.clamp(1, colors.len() + 1) - 1);
+    colors.push("shiny gold".to_string());
+    let gold = colors.len() - 1;
+
+    let levels = (0..colors.len())
+        .map(|bag| {
+            if bag == gold {
+                rng.range(1..=3)
+            } else {
+                rng.below(6)
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut contents = (0..colors.len())
+        .map(|bag| {
+            let mut below = (0..colors.len())
+                .filter(|&other| levels[other] < levels[bag])
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut below);
+            // The shiny gold bag holds other bags, unless it is the only one.
+            below.truncate(rng.range(if bag == gold { 1..=3 } else { 0..=3 }));
+            below
+                .into_iter()
+                .map(|other| (other, rng.range(1..=4)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // Some bag holds the shiny gold one directly, if any is on a higher level.
+    let outer = (0..colors.len())
+        .filter(|&bag| levels[bag] > levels[gold])
+        .collect::<Vec<_>>();
+    if !outer.is_empty() && !contents.iter().flatten().any(|&(bag, _)| bag == gold) {
+        let bag = *rng.pick(&outer);
+        let count = rng.range(1..=4);
+        contents[bag].push((gold, count));
+    }
+
+    // Resolve the bags level by level, starting with the innermost ones.
+    let mut order = (0..colors.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&bag| levels[bag]);
+    let mut holds_gold = vec![false; colors.len()];
+    let mut nested = vec![0; colors.len()];
+    for &bag in &order {
+        holds_gold[bag] = contents[bag]
+            .iter()
+            .any(|&(other, _)| other == gold || holds_gold[other]);
+        nested[bag] = contents[bag]
+            .iter()
+            .map(|&(other, count)| count * (1 + nested[other]))
+            .sum();
+    }
+
+    let mut rules = (0..colors.len())
+        .map(|bag| {
+            let content = match contents[bag].is_empty() {
+                true => "no other bags".to_string(),
+                false => contents[bag]
+                    .iter()
+                    .map(|&(other, count)| {
+                        let plural = if count == 1 { "" } else { "s" };
+                        format!("{} {} bag{}", count, colors[other], plural)
+                    })
+                    .join(", "),
+            };
+            format!("{} bags contain {}.", colors[bag], content)
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut rules);
+    Sample::new(lines(&rules))
+        .with_answer(Part::One, holds_gold.iter().filter(|&&holds| holds).count())
+        .with_answer(Part::Two, nested[gold])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_color_contents() {
+        let extract_color_contents = |rule| parse::parse(rule, extract_color_contents).ok();
+        assert_eq!(
+            extract_color_contents("vibrant magenta bags contain 2 dark lime bags."),
+            Some((
+                "vibrant magenta",
+                vec![("dark lime", 2)].into_iter().collect()
+            ))
+        );
+
+        assert_eq!(
+            extract_color_contents("dull white bags contain no other bags."),
+            Some(("dull white", vec![].into_iter().collect()))
+        );
+
+        assert_eq!(
+            extract_color_contents("muted bronze bags contain 5 bright tomato bags, 5 light red bags, 2 shiny yellow bags, 2 dim teal bags."),
+            Some((
+                "muted bronze",
+                vec![("bright tomato", 5), ("light red", 5), ("shiny yellow", 2), ("dim teal", 2)].into_iter().collect()
+            ))
+        );
+
+        assert_eq!(
+            extract_color_contents("dull white bags contain x bright red bag."),
+            None
+        );
+        assert_eq!(
+            parse::parse(
+                "dull white bags contain 1 bright red bag",
+                super::extract_color_contents
+            )
+            .unwrap_err()
+            .to_string(),
+            "column 41: expected `.`"
+        );
+    }
+
+    #[test]
+    fn test_day_7_task_1() {
+        let input = vec![
+            "light red bags contain 1 bright white bag, 2 muted yellow bags.".to_string(),
+            "dark orange bags contain 3 bright white bags, 4 muted yellow bags.".to_string(),
+            "bright white bags contain 1 shiny gold bag.".to_string(),
+            "muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.".to_string(),
+            "shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.".to_string(),
+            "dark olive bags contain 3 faded blue bags, 4 dotted black bags.".to_string(),
+            "vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.".to_string(),
+            "faded blue bags contain no other bags.".to_string(),
+            "dotted black bags contain no other bags.".to_string(),
+        ];
+        assert_eq!(task_1(&input).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_count_contained_bags() {
+        let input_1 = [
+            "shiny gold bags contain 2 dark red bags.".to_string(),
+            "dark red bags contain 2 dark orange bags.".to_string(),
+            "dark orange bags contain 2 dark yellow bags.".to_string(),
+            "dark yellow bags contain 2 dark green bags.".to_string(),
+            "dark green bags contain 2 dark blue bags.".to_string(),
+            "dark blue bags contain 2 dark violet bags.".to_string(),
+            "dark violet bags contain no other bags.".to_string(),
+        ];
+
+        let input_2 = vec![
+            "light red bags contain 1 bright white bag, 2 muted yellow bags.".to_string(),
+            "dark orange bags contain 3 bright white bags, 4 muted yellow bags.".to_string(),
+            "bright white bags contain 1 shiny gold bag.".to_string(),
+            "muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.".to_string(),
+            "shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.".to_string(),
+            "dark olive bags contain 3 faded blue bags, 4 dotted black bags.".to_string(),
+            "vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.".to_string(),
+            "faded blue bags contain no other bags.".to_string(),
+            "dotted black bags contain no other bags.".to_string(),
+        ];
+
+        let content_rules_1 = parse_rules(&input_1).unwrap();
+        let content_rules_2 = parse_rules(&input_2).unwrap();
+
+        // We add one, with respect to the test data presented in the exercise
+        // since we also count the containing bag.
+        assert_eq!(count_contained_bags("dark blue", &content_rules_1), Some(3));
+        assert_eq!(
+            count_contained_bags("shiny gold", &content_rules_1),
+            Some(127)
+        );
+        assert_eq!(
+            count_contained_bags("shiny gold", &content_rules_2),
+            Some(33)
+        );
+        assert_eq!(count_contained_bags("dark red", &content_rules_2), None);
+    }
+}
@@ -0,0 +1,376 @@
+//! This module contains the code
+//! for the solution of the eight day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/8).
+use crate::generate::lines;
+use crate::parse::{self, Cursor, PResult};
+use crate::{AocError, Part, Result, Rng, Sample, Solution};
+use std::collections::HashSet;
+
+/// The solution to the eighth day's challenges.
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<String>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(corrupted_program(rng, size))
+    }
+}
+
+/// Find the state of the accumulator before entering the infinite loop.
+pub fn task_1(data: &[String]) -> Result<isize> {
+    let instructions = parse_program(data)?;
+
+    match helper(&instructions) {
+        ExitOn::Loop(val) => Ok(val),
+        _ => Err(AocError::unsolvable("the program does not enter a loop")),
+    }
+}
+
+/// Fix the bug and compute the state of the accumulator after the program finishes.
+pub fn task_2(data: &[String]) -> Result<isize> {
+    let instructions = parse_program(data)?;
+    // Get the positions of all jmp and nop instructions
+    instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| matches!(op, Instruction::Jmp(_) | Instruction::Nop(_)))
+        .map(|(i, _)| i)
+        // Try out all possible replacements
+        .map(|index| {
+            crate::trace!("swap", line = index + 1);
+            let tmp = instructions
+                .iter()
+                .enumerate()
+                .map(|(i, op)| if i == index { op.swap() } else { op.clone() })
+                .collect::<Vec<_>>();
+            helper(&tmp)
+        })
+        .find_map(|i| match i {
+            ExitOn::Finish(val) => Some(val),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            AocError::unsolvable("swapping a single `jmp` or `nop` does not fix the program")
+        })
+}
+
+/// Check whether the given instructions lead to a loop or not.
+/// Return the value of the accumulator at the end of the program
+/// or when the loop starts, wrapped in an `ExitOn` enum.
+fn helper(instructions: &[Instruction]) -> ExitOn {
+    let mut visited: HashSet<usize> = HashSet::new();
+    let mut accumulator: isize = 0;
+    let mut index: isize = 0;
+
+    let exit = loop {
+        // A negative index wraps around and is hence out of bounds as well.
+        let instruction = match instructions.get(index as usize) {
+            Some(instruction) => instruction,
+            None => break ExitOn::OutOfBounds,
+        };
+        crate::trace!(
+            "execute",
+            line = index + 1,
+            op = instruction.op(),
+            arg = instruction.arg(),
+            accumulator = accumulator,
+        );
+        match *instruction {
+            Instruction::Nop(_) => index += 1,
+            Instruction::Jmp(val) => index += val,
+            Instruction::Acc(val) => {
+                accumulator += val;
+                index += 1;
+            }
+        }
+        if !visited.insert(index as usize) {
+            break ExitOn::Loop(accumulator);
+        }
+        if index as usize == instructions.len() {
+            break ExitOn::Finish(accumulator);
+        }
+    };
+    crate::trace!("exit", on = format!("{:?}", exit), line = index + 1);
+    exit
+}
+
+/// Indicate whether a program finishes or enters a loop.
+/// Store the value of the accumulator at the end of the
+/// program or the beginning of the loop. A program that jumps
+/// to an instruction outside of the program is invalid.
+#[derive(Debug, PartialEq)]
+enum ExitOn {
+    Finish(isize),
+    Loop(isize),
+    OutOfBounds,
+}
+
+/// Enumerate the instructions
+#[derive(Debug, PartialEq, Clone)]
+enum Instruction {
+    Nop(isize),
+    Acc(isize),
+    Jmp(isize),
+}
+
+impl Instruction {
+    /// The name of the operation as in the input.
+    fn op(&self) -> &'static str {
+        match self {
+            Self::Nop(_) => "nop",
+            Self::Acc(_) => "acc",
+            Self::Jmp(_) => "jmp",
+        }
+    }
+
+    /// The argument of the operation.
+    fn arg(&self) -> isize {
+        match self {
+            Self::Nop(val) | Self::Acc(val) | Self::Jmp(val) => *val,
+        }
+    }
+
+    /// Swap the `Nop` and `Jmp` operations, leave `Acc` untouched.
+    fn swap(&self) -> Self {
+        match self {
+            Self::Nop(val) => Self::Jmp(*val),
+            Self::Acc(val) => Self::Acc(*val),
+            Self::Jmp(val) => Self::Nop(*val),
+        }
+    }
+}
+
+/// Parse the instructions of all the lines of the input.
+fn parse_program(data: &[String]) -> Result<Vec<Instruction>> {
+    parse::each_line(data, parse_instruction)
+}
+
+/// Parse an instruction from the input file, e.g. `jmp -4`.
+///
+/// The argument of the instruction always carries an explicit sign.
+fn parse_instruction(cursor: &mut Cursor) -> PResult<Instruction> {
+    let instruction = cursor.keyword::<fn(isize) -> Instruction>(&[
+        ("nop", Instruction::Nop),
+        ("acc", Instruction::Acc),
+        ("jmp", Instruction::Jmp),
+    ])?;
+    cursor.tag(" ")?;
+    if !matches!(cursor.peek(), Some('+') | Some('-')) {
+        return Err(cursor.error("`+` or `-`"));
+    }
+    Ok(instruction(cursor.int()?))
+}
+
+/// A random boot program of `size` instructions that enters a loop, and
+/// terminates once exactly one of its `jmp` or `nop` instructions is swapped.
+///
+/// The program runs straight through its instructions, except for forward
+/// jumps over instructions that are never executed, up to the corrupted
+/// instruction, which jumps back. Any other swap of an executed instruction
+/// either jumps back as well or falls through into skipped instructions that
+/// lead back, such that the corrupted instruction is the only fix.
+pub fn corrupted_program(rng: &mut Rng, size: usize) -> Sample {
+    let len = size.max(2);
+    let value = |rng: &mut Rng| rng.range(0..=100) as isize - 50;
+    // The loop does not return to the first instruction, which `helper`
+    // only notices as visited on its second execution.
+    let corrupted = rng.range((len / 4).max(1)..=(len * 3 / 4).max(1));
+    let mut program = Vec::with_capacity(len);
+    // The executed instructions before the corrupted one.
+    let mut executed = vec![];
+    let (mut looping, mut accumulator) = (0, 0);
+    while program.len() < len {
+        let at = program.len();
+        let before = at < corrupted;
+        let jump_back = |rng: &mut Rng, executed: &[usize]| {
+            let targets = &executed[executed.len().min(1)..];
+            let target = match targets {
+                [] => at,
+                _ => *rng.pick(targets),
+            };
+            target as isize - at as isize
+        };
+        if at == corrupted {
+            program.push(Instruction::Jmp(jump_back(rng, &executed)));
+            continue;
+        }
+        if before {
+            executed.push(at);
+        }
+
+        let end = if before { corrupted } else { len };
+        let skip = rng.range(1..=3).min(end - at - 1);
+        if skip > 0 && rng.chance(0.2) {
+            program.push(Instruction::Jmp(skip as isize + 1));
+            for _ in 0..skip {
+                let skipped = program.len();
+                program.push(match rng.below(3) {
+                    0 => Instruction::Acc(value(rng)),
+                    1 => Instruction::Nop(rng.below(len) as isize - skipped as isize),
+                    // Swapping the jump over it must not lead past the corrupted instruction.
+                    _ if before => {
+                        Instruction::Jmp(*rng.pick(&executed) as isize - skipped as isize)
+                    }
+                    _ => Instruction::Jmp(rng.below(len) as isize - skipped as isize),
+                });
+            }
+        } else if rng.chance(0.5) {
+            let value = value(rng);
+            if before {
+                looping += value;
+            }
+            accumulator += value;
+            program.push(Instruction::Acc(value));
+        } else if before {
+            program.push(Instruction::Nop(jump_back(rng, &executed)));
+        } else {
+            program.push(Instruction::Nop(rng.below(len) as isize - at as isize));
+        }
+    }
+
+    let program = program
+        .iter()
+        .map(|instruction| format!("{} {:+}", instruction.op(), instruction.arg()));
+    Sample::new(lines(program))
+        .with_answer(Part::One, looping)
+        .with_answer(Part::Two, accumulator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace;
+
+    #[test]
+    fn test_parse_instruction() {
+        use Instruction::*;
+        let instruction = |code| parse::parse(code, parse_instruction);
+        assert_eq!(instruction("nop +0"), Ok(Nop(0)));
+        assert_eq!(instruction("acc +1"), Ok(Acc(1)));
+        assert_eq!(instruction("jmp +4"), Ok(Jmp(4)));
+        assert_eq!(instruction("jmp -4"), Ok(Jmp(-4)));
+        assert_eq!(instruction("jmp -346"), Ok(Jmp(-346)));
+        assert_eq!(instruction("jmp 346").unwrap_err().column, 5);
+        assert_eq!(
+            instruction("mul +3").unwrap_err().expected,
+            "one of `nop`, `acc` or `jmp`"
+        );
+    }
+
+    #[test]
+    fn test_day_8_task_1() {
+        let input = vec![
+            "nop +0".to_string(),
+            "acc +1".to_string(),
+            "jmp +4".to_string(),
+            "acc +3".to_string(),
+            "jmp -3".to_string(),
+            "acc -99".to_string(),
+            "acc +1".to_string(),
+            "jmp -4".to_string(),
+            "acc +6".to_string(),
+        ];
+
+        assert_eq!(task_1(&input).unwrap(), 5);
+        assert!(task_1(&["jmp -2".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_day_8_task_2() {
+        let input = vec![
+            "nop +0".to_string(),
+            "acc +1".to_string(),
+            "jmp +4".to_string(),
+            "acc +3".to_string(),
+            "jmp -3".to_string(),
+            "acc -99".to_string(),
+            "acc +1".to_string(),
+            "jmp -4".to_string(),
+            "acc +6".to_string(),
+        ];
+
+        assert_eq!(task_2(&input).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_corrupted_program() {
+        for seed in 0..20 {
+            let sample = corrupted_program(&mut Rng::new(seed), 50);
+            let input = sample.data.lines().map(String::from).collect::<Vec<_>>();
+            assert_eq!(input.len(), 50);
+            let program = parse_program(&input).unwrap();
+            let fixes = (0..program.len())
+                .filter(|&i| {
+                    let mut fixed = program.clone();
+                    fixed[i] = fixed[i].swap();
+                    matches!(helper(&fixed), ExitOn::Finish(_))
+                })
+                .count();
+            assert_eq!(fixes, 1);
+            assert_eq!(
+                sample.answer(Part::One),
+                Some(task_1(&input).unwrap().to_string().as_str())
+            );
+            assert_eq!(
+                sample.answer(Part::Two),
+                Some(task_2(&input).unwrap().to_string().as_str())
+            );
+        }
+
+        let sample = corrupted_program(&mut Rng::new(0), 100_000);
+        let input = sample.data.lines().map(String::from).collect::<Vec<_>>();
+        assert_eq!(input.len(), 100_000);
+        assert_eq!(
+            sample.answer(Part::One),
+            Some(task_1(&input).unwrap().to_string().as_str())
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let input = vec![
+            "nop +0".to_string(),
+            "acc +1".to_string(),
+            "jmp +4".to_string(),
+            "acc +3".to_string(),
+            "jmp -3".to_string(),
+            "acc -99".to_string(),
+            "acc +1".to_string(),
+            "jmp -4".to_string(),
+            "acc +6".to_string(),
+        ];
+        let (_, events) = trace::with_sink(vec![], || task_1(&input));
+        let lines = events
+            .iter()
+            .filter(|e| e.name == "execute")
+            .map(|e| e.get("line").unwrap().as_i64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec![1, 2, 3, 7, 8, 4, 5]);
+        assert_eq!(events.last().unwrap().to_string(), "exit on=Loop(5) line=2");
+
+        let (_, events) = trace::with_sink(vec![], || task_2(&input));
+        let swaps = events.iter().filter(|e| e.name == "swap").count();
+        assert_eq!(swaps, 4);
+        assert_eq!(
+            events.last().unwrap().to_string(),
+            "exit on=Finish(8) line=10"
+        );
+    }
+}
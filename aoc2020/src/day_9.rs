@@ -0,0 +1,238 @@
+//! This module contains the code
+//! for the solution of the ninth day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/2020/day/9).
+use crate::generate::lines;
+use crate::{AocError, Part, Result, Rng, Sample, Solution, Streamer};
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+/// The solution to the ninth day's challenges.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        crate::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        task_1(input, 25)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        task_2(input, 25)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<Sample> {
+        Some(xmas_data(rng, size))
+    }
+
+    fn streamer(part: Part) -> Option<Streamer> {
+        match part {
+            Part::One => {
+                Some(|lines| Ok(task_1_stream(crate::stream_data(lines), 25)?.to_string()))
+            }
+            // The contiguous range may start anywhere before the invalid number.
+            Part::Two => None,
+        }
+    }
+}
+
+/// Find the number which does not correspond to the sum of any pair
+/// of numbers belonging to the previous `memory` numbers.
+pub fn task_1(data: &[u64], memory: usize) -> Result<u64> {
+    task_1_stream(data.iter().copied().map(Ok), memory)
+}
+
+/// Like [`task_1`], but the `numbers` are processed one at a time as they are
+/// read, keeping only the last `memory` of them, and reading stops at the
+/// invalid number.
+pub fn task_1_stream<I>(numbers: I, memory: usize) -> Result<u64>
+where
+    I: IntoIterator<Item = Result<u64>>,
+{
+    let mut window = VecDeque::with_capacity(memory + 1);
+    for number in numbers {
+        let number = number?;
+        if window.len() == memory && !window.iter().combinations(2).any(|t| t[0] + t[1] == number) {
+            return Ok(number);
+        }
+        window.push_back(number);
+        if window.len() > memory {
+            window.pop_front();
+        }
+    }
+    Err(AocError::unsolvable(
+        "every number is the sum of two previous ones",
+    ))
+}
+
+/// Compute the sum of the minimum and maximum range of continuous numbers
+/// whose sum equals the invalid number found in task 1.
+pub fn task_2(data: &[u64], memory: usize) -> Result<u64> {
+    let invalid = task_1(data, memory)?;
+
+    let w = (2..=data.len())
+        .find_map(|i| {
+            data.windows(i).find_map(|w| {
+                if w.iter().sum::<u64>() == invalid {
+                    Some(w.iter().sorted().collect::<Vec<_>>())
+                } else {
+                    None
+                }
+            })
+        })
+        .ok_or_else(|| {
+            AocError::unsolvable(format!(
+                "no contiguous range of numbers sums to {}",
+                invalid
+            ))
+        })?;
+
+    Ok(w[0] + w[w.len() - 1])
+}
+
+/// A random stream of `size` numbers, in which every number after the
+/// preamble of 25 is the sum of two of the 25 numbers before it, except for
+/// one which is the sum of a contiguous range of earlier numbers instead.
+pub fn xmas_data(rng: &mut Rng, size: usize) -> Sample {
+    const PREAMBLE: usize = 25;
+    let len = size.clamp(PREAMBLE + 2, 1000);
+    let mut numbers = (1..=50).collect::<Vec<u64>>();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(PREAMBLE);
+
+    let invalid_at = rng.range(PREAMBLE..=len - 1);
+    while numbers.len() < len {
+        let window = &numbers[numbers.len() - PREAMBLE..];
+        let number = if numbers.len() == invalid_at {
+            let start = rng.below(numbers.len() - 1);
+            let end = rng.range(start + 2..=numbers.len().min(start + 17));
+            numbers[start..end].iter().sum()
+        } else {
+            window[rng.below(PREAMBLE)] + window[rng.below(PREAMBLE)]
+        };
+        let is_sum = window
+            .iter()
+            .tuple_combinations()
+            .any(|(a, b)| a + b == number);
+        if is_sum == (numbers.len() != invalid_at) {
+            numbers.push(number);
+        }
+    }
+
+    // The shortest, and then first, contiguous range adding up to the invalid number.
+    let invalid = numbers[invalid_at];
+    let sums = numbers
+        .iter()
+        .scan(0, |sum, n| {
+            *sum += n;
+            Some(*sum)
+        })
+        .collect::<Vec<_>>();
+    let sum =
+        |start: usize, end: usize| sums[end - 1] - start.checked_sub(1).map_or(0, |s| sums[s]);
+    let (start, end) = (2..=len)
+        .flat_map(|count| (0..=len - count).map(move |start| (start, start + count)))
+        .find(|&(start, end)| sum(start, end) == invalid)
+        .unwrap();
+    let range = &numbers[start..end];
+    let weakness = range.iter().min().unwrap() + range.iter().max().unwrap();
+
+    Sample::new(lines(&numbers))
+        .with_answer(Part::One, invalid)
+        .with_answer(Part::Two, weakness)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_9_task_1() {
+        let input = [
+            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
+            576,
+        ];
+        assert_eq!(task_1(&input, 5).unwrap(), 127);
+        assert!(task_1(&input[..5], 5).is_err());
+    }
+
+    #[test]
+    fn test_day_9_task_2() {
+        let input = [
+            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
+            576,
+        ];
+        assert_eq!(task_2(&input, 5).unwrap(), 62);
+    }
+
+    #[test]
+    fn test_day_9_stream() {
+        let input = [
+            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
+            576,
+        ];
+        assert_eq!(
+            task_1_stream(input.iter().copied().map(Ok), 5).unwrap(),
+            127
+        );
+        assert!(task_1_stream(input[..14].iter().copied().map(Ok), 5).is_err());
+
+        let sample = xmas_data(&mut Rng::new(9), 200);
+        let numbers = crate::stream_data(crate::read_lines(sample.data.as_bytes()));
+        let task_1 = task_1_stream(numbers, 25).unwrap().to_string();
+        assert_eq!(sample.answer(Part::One), Some(task_1.as_str()));
+    }
+
+    /// The invalid number by comparing it to the sums of all pairs of earlier
+    /// numbers, and the answers of all contiguous ranges summing to it, ordered
+    /// by their length and start.
+    fn oracle(data: &[u64], memory: usize) -> Option<(u64, Vec<u64>)> {
+        let i = (memory..data.len()).find(|&i| {
+            let window = &data[i - memory..i];
+            !(0..memory).any(|j| (j + 1..memory).any(|k| window[j] + window[k] == data[i]))
+        })?;
+        let invalid = data[i];
+        let mut ranges = vec![];
+        for len in 2..=data.len() {
+            for start in 0..=data.len() - len {
+                let range = &data[start..start + len];
+                if range.iter().sum::<u64>() == invalid {
+                    let min = range.iter().min().unwrap();
+                    let max = range.iter().max().unwrap();
+                    ranges.push(min + max);
+                }
+            }
+        }
+        Some((invalid, ranges))
+    }
+
+    #[test]
+    fn test_day_9_oracle() {
+        let mut rng = Rng::new(9);
+        for _ in 0..500 {
+            let memory = rng.range(2..=5);
+            let data = (0..rng.range(1..=30))
+                .map(|_| rng.range(1..=20) as u64)
+                .collect::<Vec<_>>();
+            match oracle(&data, memory) {
+                None => assert!(task_1(&data, memory).is_err(), "{:?}", data),
+                Some((invalid, ranges)) => {
+                    assert_eq!(task_1(&data, memory).unwrap(), invalid, "{:?}", data);
+                    match ranges.first() {
+                        None => assert!(task_2(&data, memory).is_err(), "{:?}", data),
+                        Some(&answer) => {
+                            assert_eq!(task_2(&data, memory).unwrap(), answer, "{:?}", data)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
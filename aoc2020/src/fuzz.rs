@@ -0,0 +1,92 @@
+//! This module contains the entry points exercised by the fuzz targets.
+//!
+//! Every fuzz target in `fuzz/` passes its input to [`run`] for one day,
+//! which feeds it to all public functions of the day that take the puzzle
+//! input as text or as a stream of lines. Whatever the input, they are
+//! expected to return an error rather than to panic.
+//!
+//! The parts whose running time does not depend on the size of the input,
+//! such as the 30 million turns of day 15, are run with smaller parameters
+//! or skipped, such that the fuzzer spends its time on the parsing.
+use crate::{
+    day_1, day_11, day_12, day_14, day_15, day_17, day_2, day_20, day_23, day_3, day_5, day_9,
+    find_day, Part, Solution,
+};
+
+/// The largest number of tiles of day 20 that are assembled into an image.
+const MAX_TILES: usize = 4;
+
+/// Pass `data` to the entry points of `day`, ignoring their results.
+///
+/// # Panics
+///
+/// Panics if `day` is not registered, or if an entry point panics.
+pub fn run(day: u32, data: &str) {
+    let lines = data.lines().map(String::from).collect::<Vec<_>>();
+    let registered = find_day(day).unwrap_or_else(|| panic!("day {} is not registered", day));
+    for part in [Part::One, Part::Two] {
+        if !matches!(
+            (day, part),
+            (15, Part::Two) | (17, _) | (23, Part::Two) | (24, Part::Two)
+        ) {
+            let _ = registered.solve(data, part);
+        }
+    }
+
+    let stream = || lines.iter().map(Ok);
+    match day {
+        1 => {
+            let _ = day_1::day_1_stream(crate::stream_data(stream()), 3, 2020);
+        }
+        2 => {
+            let _ = day_2::task_1_stream(stream());
+            let _ = day_2::task_2_stream(stream());
+        }
+        3 => {
+            let _ = day_3::parse_terrain(data);
+        }
+        5 => {
+            let _ = day_5::task_1_stream(stream());
+            let _ = day_5::task_2_stream(stream());
+        }
+        9 => {
+            let _ = day_9::task_1_stream(crate::stream_data(stream()), 25);
+        }
+        11 => {
+            let _ = day_11::task_1_2(data, &day_11::Strategy::Adjacent, 1);
+            let _ = day_11::task_1_2(data, &day_11::Strategy::Visible, 8);
+        }
+        12 => {
+            let _ = day_12::trajectory(&lines, Part::One);
+            let _ = day_12::trajectory(&lines, Part::Two);
+            let _ = day_12::task_1_stream(stream());
+            let _ = day_12::task_2_stream(stream());
+        }
+        14 => {
+            let _ = day_14::task_1_stream(stream());
+            let _ = day_14::task_2_stream(stream());
+        }
+        15 => {
+            if let Ok(numbers) = day_15::Day15::parse(data) {
+                let _ = day_15::task_1_2(&numbers, 100);
+            }
+        }
+        17 => {
+            let _ = day_17::task_1(data, 2);
+            let _ = day_17::task_2(data, 1);
+        }
+        // The arrangement of the tiles takes exponential time in their number.
+        20 if crate::blocks(data).len() <= MAX_TILES => {
+            for tile_size in [0, 1, 3] {
+                let _ = day_20::assemble_image(data, tile_size);
+            }
+        }
+        23 => {
+            if let Ok(cups) = day_23::Day23::parse(data) {
+                let _ = day_23::task_1(&cups, 0);
+                let _ = day_23::task_2(&cups, 10);
+            }
+        }
+        _ => (),
+    }
+}
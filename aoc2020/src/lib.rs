@@ -0,0 +1,77 @@
+//! # Advent Of Code 2020
+//!
+//! Solutions in Rust.
+//!
+//! This crate contains the solutions to the challenges of 2020, one module
+//! per day, and registers them as a [`Year`]. The shared utilities of the
+//! [`aoc_core`] crate are re-exported, such that the solutions refer to them
+//! as if they were defined here.
+
+pub use aoc_core::*;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+
+/// The registry of all days, in order.
+///
+/// New days are registered here by the scaffolding generator, see [`scaffold`].
+pub static DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(1),
+    Day::new::<day_2::Day2>(2),
+    Day::new::<day_3::Day3>(3),
+    Day::new::<day_4::Day4>(4),
+    Day::new::<day_5::Day5>(5),
+    Day::new::<day_6::Day6>(6),
+    Day::new::<day_7::Day7>(7),
+    Day::new::<day_8::Day8>(8),
+    Day::new::<day_9::Day9>(9),
+    Day::new::<day_10::Day10>(10),
+    Day::new::<day_11::Day11>(11),
+    Day::new::<day_12::Day12>(12),
+    Day::new::<day_13::Day13>(13),
+    Day::new::<day_14::Day14>(14),
+    Day::new::<day_15::Day15>(15),
+    Day::new::<day_16::Day16>(16),
+    Day::new::<day_17::Day17>(17),
+    Day::new::<day_18::Day18>(18),
+    Day::new::<day_19::Day19>(19),
+    Day::new::<day_20::Day20>(20),
+    Day::new::<day_21::Day21>(21),
+    Day::new::<day_22::Day22>(22),
+    Day::new::<day_23::Day23>(23),
+    Day::new::<day_24::Day24>(24),
+    Day::new::<day_25::Day25>(25),
+];
+
+/// The solutions to the challenges of 2020.
+pub static YEAR: Year = Year::new(2020, DAYS, env!("CARGO_MANIFEST_DIR"));
+
+/// The registered day `day`, if any.
+pub fn find_day(day: u32) -> Option<&'static Day> {
+    YEAR.find_day(day)
+}
@@ -0,0 +1,29 @@
+use aoc2020::{self, open_input, read_data, read_lines, Part, Profile, Status};
+
+#[test]
+fn test_day_1() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 1).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = read_data::<u32, _>(profile.input_path(1)).unwrap();
+        let task_1 = aoc2020::day_1::day_1(&data, 2, 2020).unwrap();
+        assert_eq!(
+            answers.check(1, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+        let task_2 = aoc2020::day_1::day_1(&data, 3, 2020).unwrap();
+        assert_eq!(
+            answers.check(1, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let lines = read_lines(open_input(profile.input_path(1)).unwrap());
+        let streamed = aoc2020::day_1::day_1_stream(aoc2020::stream_data(lines), 3, 2020).unwrap();
+        assert_eq!(
+            answers.check(1, Part::Two, streamed),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use aoc2020::{Part, Profile, Status};
+
+#[test]
+fn test_day_11() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 11).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = aoc2020::read_string(profile.input_path(11)).unwrap();
+
+        let task_1 =
+            aoc2020::day_11::task_1_2(&data, &aoc2020::day_11::Strategy::Adjacent, 4).unwrap();
+        assert_eq!(
+            answers.check(11, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let task_2 =
+            aoc2020::day_11::task_1_2(&data, &aoc2020::day_11::Strategy::Visible, 5).unwrap();
+        assert_eq!(
+            answers.check(11, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
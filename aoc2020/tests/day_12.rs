@@ -0,0 +1,31 @@
+use aoc2020::{self, open_input, read_data, read_lines, Part, Profile, Status};
+
+#[test]
+fn test_day_12() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 12).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = read_data::<String, _>(profile.input_path(12)).unwrap();
+
+        let task_1 = aoc2020::day_12::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(12, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let task_2 = aoc2020::day_12::task_2(&data).unwrap();
+        assert_eq!(
+            answers.check(12, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let lines = read_lines(open_input(profile.input_path(12)).unwrap());
+        let streamed = aoc2020::day_12::task_2_stream(lines).unwrap();
+        assert_eq!(
+            answers.check(12, Part::Two, streamed),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
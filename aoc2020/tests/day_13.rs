@@ -0,0 +1,23 @@
+use aoc2020::{Part, Profile, Status};
+
+#[test]
+fn test_day_13() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 13).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = aoc2020::read_string(profile.input_path(13)).unwrap();
+
+        let task_1 = aoc2020::day_13::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(13, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let task_2 = aoc2020::day_13::task_2(&data).unwrap();
+        assert_eq!(
+            answers.check(13, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use aoc2020::{day_15::Day15, Part, Profile, Solution, Status};
+
+#[test]
+fn test_day_15() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 15).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = Day15::parse(&aoc2020::read_string(profile.input_path(15)).unwrap()).unwrap();
+
+        let task_1 = aoc2020::day_15::task_1_2(&data, 2020).unwrap();
+        assert_eq!(
+            answers.check(15, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+        let task_2 = aoc2020::day_15::task_1_2(&data, 30000000).unwrap();
+        assert_eq!(
+            answers.check(15, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
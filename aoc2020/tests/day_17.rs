@@ -0,0 +1,23 @@
+use aoc2020::{Part, Profile, Status};
+
+#[test]
+fn test_day_17() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 17).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = aoc2020::read_string(profile.input_path(17)).unwrap();
+
+        let task_1 = aoc2020::day_17::task_1(&data, 6).unwrap();
+        assert_eq!(
+            answers.check(17, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let task_2 = aoc2020::day_17::task_2(&data, 6).unwrap();
+        assert_eq!(
+            answers.check(17, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use aoc2020::{self, read_data, Part, Profile, Status};
+
+#[test]
+fn test_day_18() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 18).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = read_data::<String, _>(profile.input_path(18)).unwrap();
+
+        let task_1 = aoc2020::day_18::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(18, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+        let task_2 = aoc2020::day_18::task_2(&data).unwrap();
+        assert_eq!(
+            answers.check(18, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use aoc2020::{Part, Profile, Status};
+
+#[test]
+fn test_day_19() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 19).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = aoc2020::read_string(profile.input_path(19)).unwrap();
+
+        let task_1 = aoc2020::day_19::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(19, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        // let task_2 = aoc2020::day_19::task_2(&data);
+        // assert_eq!(task_2, );
+    }
+}
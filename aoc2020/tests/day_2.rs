@@ -0,0 +1,31 @@
+use aoc2020::{self, open_input, read_data, read_lines, Part, Profile, Status};
+
+#[test]
+fn test_day_2() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 2).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = read_data::<String, _>(profile.input_path(2)).unwrap();
+
+        let task_1 = aoc2020::day_2::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(2, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let task_2 = aoc2020::day_2::task_2(&data).unwrap();
+        assert_eq!(
+            answers.check(2, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let lines = read_lines(open_input(profile.input_path(2)).unwrap());
+        let streamed = aoc2020::day_2::task_1_stream(lines).unwrap();
+        assert_eq!(
+            answers.check(2, Part::One, streamed),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
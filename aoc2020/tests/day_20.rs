@@ -0,0 +1,16 @@
+use aoc2020::{Part, Profile, Status};
+
+#[test]
+fn test_day_20() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 20).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = aoc2020::read_string(profile.input_path(20)).unwrap();
+
+        let task_1 = aoc2020::day_20::task_1(&data, 10).unwrap();
+        assert_eq!(
+            answers.check(20, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use aoc2020::{Part, Profile, Status};
+
+#[test]
+fn test_day_22() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 22).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = aoc2020::read_string(profile.input_path(22)).unwrap();
+
+        let task_1 = aoc2020::day_22::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(22, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let task_2 = aoc2020::day_22::task_2(&data).unwrap();
+        assert_eq!(
+            answers.check(22, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
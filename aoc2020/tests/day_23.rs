@@ -0,0 +1,22 @@
+use aoc2020::{day_23::Day23, Part, Profile, Solution, Status};
+
+#[test]
+fn test_day_23() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 23).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = Day23::parse(&aoc2020::read_string(profile.input_path(23)).unwrap()).unwrap();
+
+        let task_1 = aoc2020::day_23::task_1(&data, 100).unwrap();
+        assert_eq!(
+            answers.check(23, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+        let task_2 = aoc2020::day_23::task_2(&data, 10_000_000).unwrap();
+        assert_eq!(
+            answers.check(23, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use aoc2020::{self, read_data, Part, Profile, Status};
+
+#[test]
+fn test_day_25() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 25).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = read_data::<usize, _>(profile.input_path(25)).unwrap();
+
+        let task_1 = aoc2020::day_25::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(25, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use aoc2020::{self, Part, Profile, Status};
+
+#[test]
+fn test_day_3() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 3).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = aoc2020::read_string(profile.input_path(3)).unwrap();
+        let data = aoc2020::day_3::parse_terrain(&data).unwrap();
+
+        let task_1 = aoc2020::day_3::task_1(&data, 3, 1).unwrap();
+        assert_eq!(
+            answers.check(3, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+        let task_2 =
+            aoc2020::day_3::task_2(&data, &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]).unwrap();
+        assert_eq!(
+            answers.check(3, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use aoc2020::{Part, Profile, Status};
+
+#[test]
+fn test_day_4() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 4).unwrap() {
+        let answers = profile.answers().unwrap();
+        let content = aoc2020::read_string(profile.input_path(4)).unwrap();
+
+        let day_4_task_1 = aoc2020::day_4::task_1(&content).unwrap();
+        assert_eq!(
+            answers.check(4, Part::One, day_4_task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let day_4_task_2 = aoc2020::day_4::task_2(&content).unwrap();
+        assert_eq!(
+            answers.check(4, Part::Two, day_4_task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
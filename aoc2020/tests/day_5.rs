@@ -0,0 +1,30 @@
+use aoc2020::{self, open_input, read_data, read_lines, Part, Profile, Status};
+
+#[test]
+fn test_day_5() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 5).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = read_data::<String, _>(profile.input_path(5)).unwrap();
+
+        let task_1 = aoc2020::day_5::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(5, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+        let task_2 = aoc2020::day_5::task_2(&data).unwrap();
+        assert_eq!(
+            answers.check(5, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let lines = read_lines(open_input(profile.input_path(5)).unwrap());
+        let streamed = aoc2020::day_5::task_2_stream(lines).unwrap();
+        assert_eq!(
+            answers.check(5, Part::Two, streamed),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
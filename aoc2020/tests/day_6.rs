@@ -0,0 +1,22 @@
+use aoc2020::{Part, Profile, Status};
+
+#[test]
+fn test_day_6() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 6).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = aoc2020::read_string(profile.input_path(6)).unwrap();
+
+        let task_1 = aoc2020::day_6::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check(6, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+        let task_2 = aoc2020::day_6::task_2(&data).unwrap();
+        assert_eq!(
+            answers.check(6, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
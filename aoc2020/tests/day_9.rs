@@ -0,0 +1,31 @@
+use aoc2020::{self, open_input, read_data, read_lines, Part, Profile, Status};
+
+#[test]
+fn test_day_9() {
+    for profile in Profile::with_input(&aoc2020::YEAR, 9).unwrap() {
+        let answers = profile.answers().unwrap();
+        let data = read_data::<u64, _>(profile.input_path(9)).unwrap();
+
+        let task_1 = aoc2020::day_9::task_1(&data, 25).unwrap();
+        assert_eq!(
+            answers.check(9, Part::One, task_1),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let task_2 = aoc2020::day_9::task_2(&data, 25).unwrap();
+        assert_eq!(
+            answers.check(9, Part::Two, task_2),
+            Status::Pass,
+            "{profile}"
+        );
+
+        let lines = read_lines(open_input(profile.input_path(9)).unwrap());
+        let streamed = aoc2020::day_9::task_1_stream(aoc2020::stream_data(lines), 25).unwrap();
+        assert_eq!(
+            answers.check(9, Part::One, streamed),
+            Status::Pass,
+            "{profile}"
+        );
+    }
+}
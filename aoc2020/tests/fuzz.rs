@@ -0,0 +1,104 @@
+#![cfg(feature = "fuzzing")]
+
+use aoc2020::{fuzz, Rng, DAYS};
+use std::fs;
+use std::panic;
+use std::path::Path;
+
+/// The characters the mutations insert, which are the ones the parsers look for,
+/// some that none of them accepts, and large numbers.
+const INSERTS: &[&str] = &[
+    "0",
+    "1",
+    "7",
+    "-",
+    "+",
+    "#",
+    ".",
+    "x",
+    ",",
+    ":",
+    " ",
+    "\n",
+    "\n\n",
+    "(",
+    ")",
+    "*",
+    "|",
+    "\"",
+    "a",
+    "b",
+    "e",
+    "w",
+    "L",
+    "F",
+    "R",
+    "N",
+    "é",
+    "contain",
+    "Tile",
+    "99999999999999999999",
+    "18446744073709551616",
+    "-9223372036854775808",
+];
+
+/// The seed inputs of `day` in the corpus of its fuzz target.
+fn corpus(day: u32) -> Vec<String> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../fuzz/corpus")
+        .join(format!("day_{}", day));
+    let mut seeds = fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("cannot read {}: {}", dir.display(), err))
+        .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+        .collect::<Vec<_>>();
+    seeds.sort();
+    seeds
+}
+
+/// A random mutation of `seed`, which may insert, delete or duplicate parts of it.
+fn mutate(rng: &mut Rng, seed: &str) -> String {
+    let mut chars = seed.chars().collect::<Vec<_>>();
+    for _ in 0..rng.range(1..=4) {
+        let at = rng.range(0..=chars.len());
+        let len = rng.range(0..=(chars.len() - at).min(8));
+        match rng.below(4) {
+            0 => {
+                chars.drain(at..at + len);
+            }
+            1 => {
+                let copy = chars[at..at + len].to_vec();
+                chars.splice(at..at, copy);
+            }
+            2 => {
+                chars.splice(at..at + len, rng.pick(INSERTS).chars());
+            }
+            _ => {
+                chars.splice(at..at, rng.pick(INSERTS).chars());
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[test]
+fn test_fuzz_corpus() {
+    for day in DAYS {
+        for seed in corpus(day.day) {
+            fuzz::run(day.day, &seed);
+        }
+    }
+}
+
+#[test]
+fn test_fuzz_mutations() {
+    let mut rng = Rng::new(2020);
+    for day in DAYS {
+        let seeds = corpus(day.day);
+        for _ in 0..200 {
+            let seed = rng.pick(&seeds).clone();
+            let input = mutate(&mut rng, &seed);
+            let result = panic::catch_unwind(|| fuzz::run(day.day, &input));
+            assert!(result.is_ok(), "day {} panics on {:?}", day.day, input);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use aoc2020::{day_1::Day1, Part, Solution, DAYS};
+
+#[test]
+fn test_registry() {
+    assert_eq!(DAYS.len(), 25);
+    for (i, day) in DAYS.iter().enumerate() {
+        assert_eq!(day.day as usize, i + 1);
+    }
+    assert_eq!(aoc2020::find_day(7).map(|d| d.day), Some(7));
+    assert!(aoc2020::find_day(26).is_none());
+
+    let data = aoc2020::read_string(DAYS[0].input_path()).unwrap();
+    let input = Day1::parse(&data).unwrap();
+    assert_eq!(
+        DAYS[0].solve(&data, Part::One).unwrap(),
+        Day1::part_1(&input).unwrap().to_string()
+    );
+    assert_eq!(
+        DAYS[0].solve(&data, Part::Two).unwrap(),
+        Day1::part_2(&input).unwrap().to_string()
+    );
+}
+
+#[test]
+fn test_registry_render() {
+    let data = aoc2020::read_string(DAYS[0].input_path()).unwrap();
+    assert!(DAYS[0].render(&data, Part::One).unwrap().is_none());
+
+    let day = aoc2020::find_day(11).unwrap();
+    let data = aoc2020::read_string(day.input_path()).unwrap();
+    assert!(day.render(&data, Part::One).unwrap().is_some());
+    assert!(day.render("L.?", Part::One).is_err());
+}
+
+#[test]
+fn test_registry_generate() {
+    for day in DAYS {
+        for seed in 0..3 {
+            let sample = day.generate(30, seed).unwrap();
+            assert_eq!(day.generate(30, seed), Some(sample.clone()));
+            for part in [Part::One, Part::Two] {
+                // Only the parts with known answers are solved, since some
+                // of the others take long in debug builds.
+                if part == Part::Two && sample.answer(part).is_none() {
+                    continue;
+                }
+                let answer = day.solve(&sample.data, part);
+                assert!(
+                    answer.is_ok(),
+                    "day {} seed {}: {:?}",
+                    day.day,
+                    seed,
+                    answer
+                );
+                if let Some(expected) = sample.answer(part) {
+                    assert_eq!(answer.unwrap(), expected, "day {} seed {}", day.day, seed);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_registry_stream() {
+    let streamed = DAYS
+        .iter()
+        .filter(|day| day.streams(Part::One))
+        .map(|day| day.day)
+        .collect::<Vec<_>>();
+    assert_eq!(streamed, vec![1, 2, 5, 9, 12, 14]);
+    assert!(!DAYS[8].streams(Part::Two));
+
+    for day in DAYS {
+        let data = aoc2020::read_string(day.input_path()).unwrap();
+        for part in [Part::One, Part::Two] {
+            if let Some(answer) = day.stream(data.as_bytes(), part) {
+                assert_eq!(answer.unwrap(), day.solve(&data, part).unwrap());
+            }
+        }
+    }
+}
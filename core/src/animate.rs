@@ -0,0 +1,384 @@
+//! This module contains the animation of iterative simulations in the terminal.
+//!
+//! A solution exposes the intermediate states of a simulation as an iterator
+//! of [`Frame`]s, usually built with [`simulate`] from the state and a per-step
+//! callback. The [`Player`] draws the frames with ANSI colors at a given frame
+//! rate, and reacts to the [`Control`]s it receives in between, such as pausing
+//! or stepping through the frames one by one.
+use crate::grid::Grid;
+use crate::render::{Palette, Rgb};
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// A character of a [`Frame`], drawn in the color with the given index into
+/// the [`Palette`], or in the default color of the terminal if it has none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub symbol: char,
+    pub color: Option<usize>,
+}
+
+impl Glyph {
+    /// A `symbol` in the color with the given index.
+    pub fn new(symbol: char, color: usize) -> Self {
+        Self {
+            symbol,
+            color: Some(color),
+        }
+    }
+
+    /// A `symbol` in the default color of the terminal.
+    pub fn plain(symbol: char) -> Self {
+        Self {
+            symbol,
+            color: None,
+        }
+    }
+}
+
+/// The drawing of one state of a simulation, along with a caption describing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub glyphs: Grid<Glyph>,
+}
+
+impl Frame {
+    /// A frame made of the `glyphs`.
+    pub fn new(caption: impl Into<String>, glyphs: Grid<Glyph>) -> Self {
+        Self {
+            caption: caption.into(),
+            glyphs,
+        }
+    }
+
+    /// A frame of a single line of text, split into segments of different colors.
+    pub fn line<'a>(
+        caption: impl Into<String>,
+        segments: impl IntoIterator<Item = (&'a str, Option<usize>)>,
+    ) -> Self {
+        let glyphs = segments
+            .into_iter()
+            .flat_map(|(text, color)| text.chars().map(move |symbol| Glyph { symbol, color }))
+            .collect::<Vec<_>>();
+        Self::new(
+            caption,
+            Grid::from_fn(1, glyphs.len(), |(_, col)| glyphs[col]),
+        )
+    }
+
+    /// Draw the frame with the colors of `palette` as ANSI escape sequences.
+    ///
+    /// Every line ends with a carriage return, so the frame is drawn correctly
+    /// when the terminal is in raw mode, and the caption comes last.
+    pub fn to_ansi(&self, palette: &Palette) -> String {
+        let mut ansi = String::new();
+        for row in 0..self.glyphs.rows() {
+            ansi.push_str(&background(palette.background));
+            let mut current = None;
+            for glyph in self.glyphs.row(row) {
+                if current != Some(glyph.color) {
+                    match glyph.color {
+                        Some(color) => ansi.push_str(&foreground(palette.color(color))),
+                        None => ansi.push_str("\x1b[39m"),
+                    }
+                    current = Some(glyph.color);
+                }
+                ansi.push(glyph.symbol);
+            }
+            ansi.push_str("\x1b[0m\x1b[K\r\n");
+        }
+        ansi.push_str(&self.caption);
+        ansi.push_str("\x1b[K\r\n");
+        ansi
+    }
+}
+
+/// The ANSI escape sequence setting the foreground to `rgb`.
+fn foreground(Rgb(r, g, b): Rgb) -> String {
+    format!("\x1b[38;2;{};{};{}m", r, g, b)
+}
+
+/// The ANSI escape sequence setting the background to `rgb`.
+fn background(Rgb(r, g, b): Rgb) -> String {
+    format!("\x1b[48;2;{};{};{}m", r, g, b)
+}
+
+/// The frames of an animation, which own the state of their simulation.
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// The frames of a simulation that starts in `state` and is advanced by `step`
+/// until it returns `false`, i.e. the simulation is over. Every state, starting
+/// with the initial one, is drawn by `draw`.
+pub fn simulate<S: 'static>(
+    state: S,
+    mut step: impl FnMut(&mut S) -> bool + 'static,
+    draw: impl Fn(&S) -> Frame + 'static,
+) -> Frames {
+    let mut state = Some(state);
+    let mut started = false;
+    Box::new(std::iter::from_fn(move || {
+        let current = state.as_mut()?;
+        if started && !step(current) {
+            state = None;
+            return None;
+        }
+        started = true;
+        Some(draw(current))
+    }))
+}
+
+/// The controls of the [`Player`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Pause or resume the animation.
+    Pause,
+    /// Show the next frame, pausing the animation if it is running.
+    Step,
+    /// Double the frame rate.
+    Faster,
+    /// Halve the frame rate.
+    Slower,
+    /// Stop the animation.
+    Quit,
+}
+
+/// The lowest and highest frame rates the player can be set to.
+const FPS_RANGE: (f64, f64) = (0.125, 1000.0);
+
+/// Plays the frames of an animation in the terminal.
+#[derive(Debug, Clone)]
+pub struct Player {
+    /// The number of frames shown per second.
+    pub fps: f64,
+    /// Whether the animation starts paused.
+    pub paused: bool,
+    /// The colors the frames are drawn with.
+    pub palette: Palette,
+}
+
+impl Player {
+    /// A player showing `fps` frames per second.
+    pub fn new(fps: f64) -> Self {
+        Self {
+            fps: fps.clamp(FPS_RANGE.0, FPS_RANGE.1),
+            paused: false,
+            palette: Palette::default(),
+        }
+    }
+
+    /// Play the `frames` on `out` until they are exhausted or the animation is
+    /// quit, and return the number of frames shown.
+    ///
+    /// Between two frames, `controls` is asked to wait for a [`Control`], at
+    /// most until the given time has passed (or indefinitely if there is
+    /// none), and to return it, or `None` once the time has passed.
+    pub fn play<W: Write>(
+        &self,
+        mut frames: impl Iterator<Item = Frame>,
+        mut controls: impl FnMut(Option<Duration>) -> io::Result<Option<Control>>,
+        mut out: W,
+    ) -> io::Result<usize> {
+        let mut fps = self.fps;
+        let mut paused = self.paused;
+        let mut frame = match frames.next() {
+            Some(frame) => frame,
+            None => return Ok(0),
+        };
+        let mut shown = 1;
+        let mut shown_at = Instant::now();
+        write!(out, "\x1b[?25l\x1b[2J")?;
+        self.draw(&mut out, &frame, shown, fps, paused)?;
+
+        let result = loop {
+            let timeout = if paused {
+                None
+            } else {
+                let delay = Duration::from_secs_f64(1.0 / fps);
+                Some(delay.saturating_sub(shown_at.elapsed()))
+            };
+            let advance = match controls(timeout) {
+                Err(err) => break Err(err),
+                Ok(None) => !paused,
+                Ok(Some(Control::Quit)) => break Ok(shown),
+                Ok(Some(Control::Step)) => {
+                    paused = true;
+                    true
+                }
+                Ok(Some(control)) => {
+                    match control {
+                        Control::Pause => paused = !paused,
+                        Control::Faster => fps = (fps * 2.0).min(FPS_RANGE.1),
+                        _ => fps = (fps / 2.0).max(FPS_RANGE.0),
+                    }
+                    false
+                }
+            };
+            if advance {
+                frame = match frames.next() {
+                    Some(frame) => frame,
+                    None => break Ok(shown),
+                };
+                shown += 1;
+                shown_at = Instant::now();
+            }
+            if let Err(err) = self.draw(&mut out, &frame, shown, fps, paused) {
+                break Err(err);
+            }
+        };
+        write!(out, "\x1b[?25h")?;
+        out.flush()?;
+        result
+    }
+
+    /// Draw `frame` along with the status of the player over the previous frame.
+    fn draw<W: Write>(
+        &self,
+        out: &mut W,
+        frame: &Frame,
+        shown: usize,
+        fps: f64,
+        paused: bool,
+    ) -> io::Result<()> {
+        let mut status = format!("frame {} at {} fps", shown, fps);
+        if paused {
+            status.push_str(", paused");
+        }
+        // Writing to a `String` cannot fail.
+        let _ = write!(
+            status,
+            "  [space] pause  [n] step  [+/-] speed  [q] quit\x1b[K\r\n"
+        );
+        write!(
+            out,
+            "\x1b[H{}{}\x1b[J",
+            frame.to_ansi(&self.palette),
+            status
+        )?;
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Frames with the captions `0` up to `n - 1`.
+    fn counter(n: usize) -> Frames {
+        simulate(
+            0,
+            move |i| {
+                *i += 1;
+                *i < n
+            },
+            |i| Frame::line(i.to_string(), vec![]),
+        )
+    }
+
+    /// Play `frames`, answering the requests for controls with `controls`,
+    /// and return the number of frames shown, the captions and the timeouts.
+    fn play(
+        player: &Player,
+        frames: Frames,
+        controls: &[Option<Control>],
+    ) -> (usize, Vec<String>, Vec<Option<Duration>>) {
+        let mut controls = controls.iter().copied();
+        let mut timeouts = vec![];
+        let mut out = vec![];
+        let shown = player
+            .play(
+                frames,
+                |timeout| {
+                    timeouts.push(timeout);
+                    Ok(controls.next().unwrap_or(Some(Control::Quit)))
+                },
+                &mut out,
+            )
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let captions = out
+            .split("\x1b[H")
+            .skip(1)
+            .map(|frame| {
+                frame
+                    .lines()
+                    .nth(1)
+                    .unwrap()
+                    .trim_end_matches("\x1b[K")
+                    .to_string()
+            })
+            .collect();
+        (shown, captions, timeouts)
+    }
+
+    #[test]
+    fn test_frame() {
+        let frame = Frame::line("moves: 1", vec![("3 ", None), ("8", Some(1))]);
+        assert_eq!(frame.glyphs.cols(), 3);
+        assert_eq!(frame.glyphs[(0, 2)], Glyph::new('8', 1));
+
+        let palette = Palette::new(Rgb(0, 0, 0), vec![Rgb(1, 2, 3), Rgb(4, 5, 6)]);
+        assert_eq!(
+            frame.to_ansi(&palette),
+            "\x1b[48;2;0;0;0m\x1b[39m3 \x1b[38;2;4;5;6m8\x1b[0m\x1b[K\r\nmoves: 1\x1b[K\r\n"
+        );
+
+        let glyphs = Grid::parse("#.\n.#", |c| match c {
+            '#' => Some(Glyph::new('#', 0)),
+            _ => Some(Glyph::plain('.')),
+        })
+        .unwrap();
+        let ansi = Frame::new("", glyphs).to_ansi(&palette);
+        assert_eq!(ansi.matches("\x1b[38;2;1;2;3m#").count(), 2);
+        assert_eq!(ansi.matches("\r\n").count(), 3);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let captions = counter(4).map(|f| f.caption).collect::<Vec<_>>();
+        assert_eq!(captions, vec!["0", "1", "2", "3"]);
+        assert_eq!(counter(1).count(), 1);
+    }
+
+    #[test]
+    fn test_player() {
+        let player = Player::new(4.0);
+
+        // The frames advance whenever waiting for a control times out.
+        let (shown, captions, timeouts) = play(&player, counter(3), &[None, None]);
+        assert_eq!(shown, 3);
+        assert_eq!(captions, vec!["0", "1", "2"]);
+        assert_eq!(timeouts.len(), 3);
+        assert!(timeouts
+            .iter()
+            .all(|t| matches!(t, Some(t) if *t <= Duration::from_millis(250))));
+
+        // Quitting stops the animation early.
+        let (shown, _, _) = play(&player, counter(10), &[None, Some(Control::Quit)]);
+        assert_eq!(shown, 2);
+
+        // A paused animation waits indefinitely and only advances by stepping.
+        let controls = [
+            Some(Control::Pause),
+            Some(Control::Step),
+            Some(Control::Pause),
+            Some(Control::Faster),
+            None,
+        ];
+        let (shown, captions, timeouts) = play(&player, counter(10), &controls);
+        assert_eq!(shown, 3);
+        assert_eq!(captions, vec!["0", "0", "1", "1", "1", "2"]);
+        assert_eq!(timeouts[1], None);
+        assert_eq!(timeouts[2], None);
+        assert!(timeouts[3].is_some());
+
+        // Stepping also pauses a running animation.
+        let (shown, _, timeouts) = play(&player, counter(10), &[Some(Control::Step)]);
+        assert_eq!(shown, 2);
+        assert_eq!(timeouts[1], None);
+
+        // Nothing is drawn without frames.
+        assert_eq!(play(&player, Box::new(std::iter::empty()), &[]).0, 0);
+    }
+}
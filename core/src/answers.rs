@@ -0,0 +1,181 @@
+//! This module contains the manifest of the known answers.
+//!
+//! The answers to the puzzle inputs are stored next to them, in the
+//! `answers.toml` of their [`Profile`](crate::Profile), which contains one
+//! table per day:
+//!
+//! ```toml
+//! [day1]
+//! part1 = 270144
+//! part2 = 261342720
+//!
+//! [day21]
+//! part2 = "spcqmzfg,rpf,dzqlq,pflk,bltrbvz,xbdh,spql,bltzkxx"
+//! ```
+//!
+//! Parts whose answer is not known are left out, such that checking
+//! them yields [`Status::Unknown`].
+use crate::{AocError, Part, Result};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::path::Path;
+
+/// The outcome of comparing an answer against the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The answer matches the known answer.
+    Pass,
+    /// The answer differs from the known answer.
+    Fail,
+    /// There is no known answer to compare against.
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail => write!(f, "fail"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// The known answers to the parts of the days.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: HashMap<(u32, Part), String>,
+}
+
+impl Answers {
+    /// Parse the manifest from its TOML representation in `data`.
+    ///
+    /// Answers can either be given as integers or as strings.
+    pub fn parse(data: &str) -> Result<Self> {
+        let table = data.parse::<toml::Table>().map_err(|err| {
+            let start = err.span().map_or(data.len(), |span| span.start);
+            AocError::malformed_in(data, &data[start..start], err.message())
+        })?;
+
+        let mut answers = HashMap::new();
+        for (key, parts) in &table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u32>().ok())
+                .filter(|d| (1..=25).contains(d))
+                .ok_or_else(|| malformed_key(data, None, key, "expected a table `[day<1-25>]`"))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| malformed_key(data, None, key, "expected a table `[day<1-25>]`"))?;
+
+            for (name, answer) in parts {
+                let part = match name.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => {
+                        return Err(malformed_key(
+                            data,
+                            Some(key),
+                            name,
+                            "expected `part1` or `part2`",
+                        ))
+                    }
+                };
+                let answer = match answer {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(i) => i.to_string(),
+                    _ => {
+                        return Err(malformed_key(
+                            data,
+                            Some(key),
+                            name,
+                            "expected an integer or a string as answer",
+                        ))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Read the manifest from the file at `path`.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::parse(&crate::read_string(path)?)
+    }
+
+    /// The known answer to `part` of `day`, if any.
+    pub fn expected(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare `answer` to the known answer to `part` of `day`.
+    pub fn check(&self, day: u32, part: Part, answer: impl Display) -> Status {
+        match self.expected(day, part) {
+            None => Status::Unknown,
+            Some(expected) if expected == answer.to_string() => Status::Pass,
+            Some(_) => Status::Fail,
+        }
+    }
+}
+
+/// Report the line of the TOML file `data` that defines `key` as malformed,
+/// where `key` is searched for within `table`, if given.
+pub(crate) fn malformed_key(data: &str, table: Option<&str>, key: &str, reason: &str) -> AocError {
+    let defines = |line: &str, key: &str| {
+        let line = line.trim().trim_start_matches('[');
+        line.starts_with(key) && line[key.len()..].trim_start().starts_with(&['=', ']'][..])
+    };
+    let start = table
+        .and_then(|table| data.lines().position(|l| defines(l, table)))
+        .unwrap_or(0);
+    match data
+        .lines()
+        .enumerate()
+        .skip(start)
+        .find(|(_, l)| defines(l, key))
+    {
+        Some((i, text)) => AocError::malformed(i + 1, text, format!("`{}`: {}", key, reason)),
+        None => AocError::unexpected_end(data, format!("`{}`: {}", key, reason)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# Comment\n[day1]\npart1 = 270144\npart2 = \"261342720\"\n\n[day21]\npart2 = \"a,b\"",
+        )
+        .unwrap();
+        assert_eq!(answers.expected(1, Part::One), Some("270144"));
+        assert_eq!(answers.expected(1, Part::Two), Some("261342720"));
+        assert_eq!(answers.expected(21, Part::One), None);
+        assert_eq!(answers.expected(21, Part::Two), Some("a,b"));
+        assert_eq!(answers.expected(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        let line_of = |data| match Answers::parse(data) {
+            Err(AocError::MalformedLine(err)) => err.line,
+            res => panic!("unexpected result {:?}", res),
+        };
+        assert_eq!(line_of("[day1]\npart1 = 1\n[day26]\npart1 = 2"), 3);
+        assert_eq!(line_of("[day1]\npart1 = 1\npart3 = 2"), 3);
+        assert_eq!(line_of("[day1]\npart1 = 1.5"), 2);
+        assert_eq!(line_of("[day1]\npart1 = 1\n[day2]\npart1 = true"), 4);
+        assert_eq!(line_of("[day1]\npart1 = = 1"), 2);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[day1]\npart1 = 270144").unwrap();
+        assert_eq!(answers.check(1, Part::One, 270144), Status::Pass);
+        assert_eq!(answers.check(1, Part::One, "270145"), Status::Fail);
+        assert_eq!(answers.check(1, Part::Two, 1), Status::Unknown);
+    }
+}
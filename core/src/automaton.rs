@@ -0,0 +1,667 @@
+//! This module contains a generic engine for cellular automata with two
+//! states per cell, alive and dead.
+//!
+//! An [`Automaton`] combines
+//! - a [`Topology`], which defines the cells and their neighbours, such as a
+//!   [`SquareGrid`], an N-dimensional [`Lattice`], a [`HexGrid`] or an
+//!   arbitrary [`Graph`],
+//! - a [`Rule`], which decides the next state of a cell from its current state
+//!   and the number of its alive neighbours, such as the birth / survival
+//!   rules of [`Life`],
+//! - a storage of the alive cells, either [`Sparse`] for unbounded topologies
+//!   or [`Dense`] for bounded ones.
+use crate::grid::{Pos, DIRECTIONS_4, DIRECTIONS_8};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// The cells of an automaton and the way they are connected.
+pub trait Topology {
+    /// The identification of a cell.
+    type Cell: Copy + Eq + Hash;
+
+    /// Call `f` with each neighbour of `cell`.
+    fn neighbours<F: FnMut(Self::Cell)>(&self, cell: Self::Cell, f: F);
+}
+
+/// A topology with a finite number of cells, which are numbered consecutively.
+pub trait Bounded: Topology {
+    /// The number of cells.
+    fn len(&self) -> usize;
+
+    /// Whether there are no cells.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of `cell`, if it is part of the topology.
+    fn index(&self, cell: Self::Cell) -> Option<usize>;
+
+    /// The cell with the number `index`.
+    fn cell(&self, index: usize) -> Self::Cell;
+}
+
+/// A bounded square grid of `rows` by `cols` cells, where each cell is
+/// connected to its four orthogonal neighbours or, with `diagonals`, also
+/// to the four diagonal ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SquareGrid {
+    pub rows: usize,
+    pub cols: usize,
+    pub diagonals: bool,
+}
+
+impl Topology for SquareGrid {
+    type Cell = Pos;
+
+    fn neighbours<F: FnMut(Pos)>(&self, cell: Pos, mut f: F) {
+        let directions: &[(isize, isize)] = if self.diagonals {
+            &DIRECTIONS_8
+        } else {
+            &DIRECTIONS_4
+        };
+        for dir in directions {
+            let row = cell.0.checked_add_signed(dir.0);
+            let col = cell.1.checked_add_signed(dir.1);
+            if let (Some(row), Some(col)) = (row, col) {
+                if row < self.rows && col < self.cols {
+                    f((row, col));
+                }
+            }
+        }
+    }
+}
+
+impl Bounded for SquareGrid {
+    fn len(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    fn index(&self, cell: Pos) -> Option<usize> {
+        if cell.0 < self.rows && cell.1 < self.cols {
+            Some(cell.0 * self.cols + cell.1)
+        } else {
+            None
+        }
+    }
+
+    fn cell(&self, index: usize) -> Pos {
+        (index / self.cols, index % self.cols)
+    }
+}
+
+/// The unbounded `N`-dimensional lattice of integer coordinates, where each
+/// cell is connected to the `3^N - 1` cells that differ by at most one in
+/// each coordinate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Lattice<const N: usize>;
+
+impl<const N: usize> Topology for Lattice<N> {
+    type Cell = [isize; N];
+
+    fn neighbours<F: FnMut([isize; N])>(&self, cell: [isize; N], mut f: F) {
+        let count = 3usize.pow(N as u32);
+        for offset in 0..count {
+            // The cell itself is the one in the middle of the enumeration.
+            if offset == count / 2 {
+                continue;
+            }
+            let mut neighbour = cell;
+            let mut digits = offset;
+            for coordinate in neighbour.iter_mut() {
+                *coordinate += (digits % 3) as isize - 1;
+                digits /= 3;
+            }
+            f(neighbour);
+        }
+    }
+}
+
+/// The unbounded grid of hexagons with flat sides to the east and west,
+/// addressed by doubled coordinates `(x, y)`: the eastern neighbour is
+/// at `(x + 2, y)` and the north-eastern one at `(x + 1, y + 1)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HexGrid;
+
+impl HexGrid {
+    /// The steps towards the six neighbours, clockwise starting in the east.
+    pub const DIRECTIONS: [(isize, isize); 6] =
+        [(2, 0), (1, -1), (-1, -1), (-2, 0), (-1, 1), (1, 1)];
+}
+
+impl Topology for HexGrid {
+    type Cell = (isize, isize);
+
+    fn neighbours<F: FnMut((isize, isize))>(&self, cell: (isize, isize), mut f: F) {
+        for dir in Self::DIRECTIONS.iter() {
+            f((cell.0 + dir.0, cell.1 + dir.1));
+        }
+    }
+}
+
+/// A finite topology given by the list of neighbours of each cell.
+#[derive(Debug, Clone)]
+pub struct Graph<C> {
+    cells: Vec<C>,
+    indices: HashMap<C, usize>,
+    neighbours: Vec<Vec<C>>,
+}
+
+impl<C: Copy + Eq + Hash> Graph<C> {
+    /// The graph of the cells with their `neighbours`, which must be cells of
+    /// the graph themselves.
+    pub fn new<I: IntoIterator<Item = (C, Vec<C>)>>(neighbours: I) -> Self {
+        let (cells, neighbours): (Vec<_>, Vec<_>) = neighbours.into_iter().unzip();
+        let indices = cells.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+        Self {
+            cells,
+            indices,
+            neighbours,
+        }
+    }
+}
+
+impl<C: Copy + Eq + Hash> Topology for Graph<C> {
+    type Cell = C;
+
+    fn neighbours<F: FnMut(C)>(&self, cell: C, mut f: F) {
+        if let Some(&index) = self.indices.get(&cell) {
+            self.neighbours[index].iter().for_each(|&n| f(n));
+        }
+    }
+}
+
+impl<C: Copy + Eq + Hash> Bounded for Graph<C> {
+    fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn index(&self, cell: C) -> Option<usize> {
+        self.indices.get(&cell).copied()
+    }
+
+    fn cell(&self, index: usize) -> C {
+        self.cells[index]
+    }
+}
+
+/// The rule deciding the next state of a cell.
+pub trait Rule {
+    /// Whether a cell that is currently `alive` and has `neighbours` alive
+    /// neighbours is alive in the next generation.
+    fn next(&self, alive: bool, neighbours: usize) -> bool;
+}
+
+impl<F: Fn(bool, usize) -> bool> Rule for F {
+    fn next(&self, alive: bool, neighbours: usize) -> bool {
+        self(alive, neighbours)
+    }
+}
+
+/// The rules of Conway's Game of Life and its relatives: a dead cell comes
+/// alive if its number of alive neighbours is one of `birth`, and an alive
+/// cell survives if its number is one of `survival`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Life {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Life {
+    /// The rule with the given numbers of alive neighbours for `birth` and `survival`.
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        Self {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+        }
+    }
+}
+
+impl Rule for Life {
+    fn next(&self, alive: bool, neighbours: usize) -> bool {
+        if alive {
+            self.survival.contains(&neighbours)
+        } else {
+            self.birth.contains(&neighbours)
+        }
+    }
+}
+
+/// The storage of the alive cells of an automaton on the topology `T`.
+pub trait Storage<T: Topology>: Sized {
+    /// The storage of the `alive` cells.
+    fn new<I: IntoIterator<Item = T::Cell>>(topology: &T, alive: I) -> Self;
+
+    /// Whether `cell` is alive.
+    fn is_alive(&self, topology: &T, cell: T::Cell) -> bool;
+
+    /// The number of alive cells.
+    fn population(&self) -> usize;
+
+    /// The alive cells, in no particular order.
+    fn alive(&self, topology: &T) -> Vec<T::Cell>;
+
+    /// The next generation according to `rule`.
+    fn step<R: Rule>(&self, topology: &T, rule: &R) -> Self;
+}
+
+/// The storage of the alive cells in a set, which only visits the alive
+/// cells and their neighbours in each step.
+///
+/// A dead cell without alive neighbours therefore never comes alive, even
+/// if the rule says so.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sparse<C: Eq + Hash> {
+    alive: HashSet<C>,
+}
+
+impl<C: Eq + Hash> Hash for Sparse<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Combine the hashes of the cells independently of their order in the set.
+        let combined = self.alive.iter().fold(0u64, |sum, cell| {
+            let mut hasher = DefaultHasher::new();
+            cell.hash(&mut hasher);
+            sum.wrapping_add(hasher.finish())
+        });
+        self.alive.len().hash(state);
+        combined.hash(state);
+    }
+}
+
+impl<T: Topology> Storage<T> for Sparse<T::Cell> {
+    fn new<I: IntoIterator<Item = T::Cell>>(_topology: &T, alive: I) -> Self {
+        Self {
+            alive: alive.into_iter().collect(),
+        }
+    }
+
+    fn is_alive(&self, _topology: &T, cell: T::Cell) -> bool {
+        self.alive.contains(&cell)
+    }
+
+    fn population(&self) -> usize {
+        self.alive.len()
+    }
+
+    fn alive(&self, _topology: &T) -> Vec<T::Cell> {
+        self.alive.iter().copied().collect()
+    }
+
+    fn step<R: Rule>(&self, topology: &T, rule: &R) -> Self {
+        let mut counts: HashMap<T::Cell, usize> = HashMap::with_capacity(self.alive.len() * 4);
+        for &cell in &self.alive {
+            counts.entry(cell).or_insert(0);
+            topology.neighbours(cell, |n| *counts.entry(n).or_insert(0) += 1);
+        }
+        Self {
+            alive: counts
+                .into_iter()
+                .filter(|(cell, count)| rule.next(self.alive.contains(cell), *count))
+                .map(|(cell, _)| cell)
+                .collect(),
+        }
+    }
+}
+
+/// The storage of the states of all cells of a [`Bounded`] topology in a
+/// vector, which visits every cell in each step.
+#[derive(Debug, Clone)]
+pub struct Dense {
+    alive: Vec<bool>,
+    /// The numbers of the neighbours of each cell, shared by all generations.
+    neighbours: Arc<Vec<Vec<usize>>>,
+}
+
+impl PartialEq for Dense {
+    fn eq(&self, other: &Self) -> bool {
+        self.alive == other.alive
+    }
+}
+
+impl Eq for Dense {}
+
+impl Hash for Dense {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.alive.hash(state);
+    }
+}
+
+impl<T: Bounded> Storage<T> for Dense {
+    /// The storage of the `alive` cells, ignoring those that are not part of the topology.
+    fn new<I: IntoIterator<Item = T::Cell>>(topology: &T, alive: I) -> Self {
+        let mut states = vec![false; topology.len()];
+        for index in alive.into_iter().filter_map(|c| topology.index(c)) {
+            states[index] = true;
+        }
+        let neighbours = (0..topology.len())
+            .map(|index| {
+                let mut neighbours = vec![];
+                topology.neighbours(topology.cell(index), |n| {
+                    neighbours.extend(topology.index(n));
+                });
+                neighbours
+            })
+            .collect();
+        Self {
+            alive: states,
+            neighbours: Arc::new(neighbours),
+        }
+    }
+
+    fn is_alive(&self, topology: &T, cell: T::Cell) -> bool {
+        topology.index(cell).is_some_and(|index| self.alive[index])
+    }
+
+    fn population(&self) -> usize {
+        self.alive.iter().filter(|&&alive| alive).count()
+    }
+
+    fn alive(&self, topology: &T) -> Vec<T::Cell> {
+        (0..self.alive.len())
+            .filter(|&index| self.alive[index])
+            .map(|index| topology.cell(index))
+            .collect()
+    }
+
+    fn step<R: Rule>(&self, _topology: &T, rule: &R) -> Self {
+        let alive = self
+            .neighbours
+            .iter()
+            .zip(&self.alive)
+            .map(|(neighbours, &alive)| {
+                let count = neighbours.iter().filter(|&&n| self.alive[n]).count();
+                rule.next(alive, count)
+            })
+            .collect();
+        Self {
+            alive,
+            neighbours: self.neighbours.clone(),
+        }
+    }
+}
+
+/// A cycle of states, which the automaton enters at generation `start`
+/// and repeats every `period` generations. A fixed point is a cycle of period one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+/// A cellular automaton on the topology `T` with the storage `S` and the rule `R`.
+#[derive(Debug, Clone)]
+pub struct Automaton<T, S, R> {
+    topology: T,
+    rule: R,
+    state: S,
+    generation: usize,
+}
+
+impl<T: Topology, R: Rule> Automaton<T, Sparse<T::Cell>, R> {
+    /// An automaton storing the `alive` cells sparsely.
+    pub fn sparse<I: IntoIterator<Item = T::Cell>>(topology: T, rule: R, alive: I) -> Self {
+        Self::new(topology, rule, alive)
+    }
+}
+
+impl<T: Bounded, R: Rule> Automaton<T, Dense, R> {
+    /// An automaton storing the states of all cells, of which the `alive` ones are alive.
+    pub fn dense<I: IntoIterator<Item = T::Cell>>(topology: T, rule: R, alive: I) -> Self {
+        Self::new(topology, rule, alive)
+    }
+}
+
+impl<T: Topology, S: Storage<T>, R: Rule> Automaton<T, S, R> {
+    /// An automaton of the given `topology` and `rule`, where the `alive` cells are alive.
+    pub fn new<I: IntoIterator<Item = T::Cell>>(topology: T, rule: R, alive: I) -> Self {
+        let state = S::new(&topology, alive);
+        Self {
+            topology,
+            rule,
+            state,
+            generation: 0,
+        }
+    }
+
+    /// The topology of the automaton.
+    pub fn topology(&self) -> &T {
+        &self.topology
+    }
+
+    /// The number of steps taken so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// The number of alive cells.
+    pub fn population(&self) -> usize {
+        self.state.population()
+    }
+
+    /// Whether `cell` is alive.
+    pub fn is_alive(&self, cell: T::Cell) -> bool {
+        self.state.is_alive(&self.topology, cell)
+    }
+
+    /// The alive cells, in no particular order.
+    pub fn alive(&self) -> Vec<T::Cell> {
+        self.state.alive(&self.topology)
+    }
+
+    /// The number of alive neighbours of `cell`.
+    pub fn live_neighbours(&self, cell: T::Cell) -> usize {
+        let mut count = 0;
+        self.topology.neighbours(cell, |n| {
+            if self.state.is_alive(&self.topology, n) {
+                count += 1;
+            }
+        });
+        count
+    }
+
+    /// Advance by one generation and return whether any cell changed its state.
+    pub fn step(&mut self) -> bool
+    where
+        S: PartialEq,
+    {
+        let next = self.state.step(&self.topology, &self.rule);
+        let changed = next != self.state;
+        self.state = next;
+        self.generation += 1;
+        changed
+    }
+
+    /// Advance by `generations` generations.
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.state = self.state.step(&self.topology, &self.rule);
+            self.generation += 1;
+        }
+    }
+
+    /// Advance generation by generation, yielding the population after each of them.
+    pub fn generations(&mut self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::from_fn(move || {
+            self.run(1);
+            Some(self.population())
+        })
+    }
+
+    /// Advance until a fixed point is reached, i.e. a generation in which no
+    /// cell changes its state, taking at most `limit` steps.
+    ///
+    /// Returns the generation of the fixed point, or `None` if it was not
+    /// reached within the limit.
+    pub fn run_until_stable(&mut self, limit: usize) -> Option<usize>
+    where
+        S: PartialEq,
+    {
+        for _ in 0..limit {
+            if !self.step() {
+                self.generation -= 1;
+                return Some(self.generation);
+            }
+        }
+        None
+    }
+
+    /// Advance until a state repeats, taking at most `limit` steps, and
+    /// return the cycle the automaton entered.
+    ///
+    /// The automaton is left at the first repetition, i.e. at generation
+    /// `start + period` of the returned cycle.
+    pub fn find_cycle(&mut self, limit: usize) -> Option<Cycle>
+    where
+        S: Clone + Eq + Hash,
+    {
+        let mut seen = HashMap::new();
+        seen.insert(self.state.clone(), self.generation);
+        for _ in 0..limit {
+            self.run(1);
+            if let Some(&start) = seen.get(&self.state) {
+                return Some(Cycle {
+                    start,
+                    period: self.generation - start,
+                });
+            }
+            seen.insert(self.state.clone(), self.generation);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conway() -> Life {
+        Life::new(&[3], &[2, 3])
+    }
+
+    /// The neighbours of `cell`, in order.
+    fn neighbours<T: Topology>(topology: &T, cell: T::Cell) -> Vec<T::Cell>
+    where
+        T::Cell: Ord,
+    {
+        let mut cells = vec![];
+        topology.neighbours(cell, |c| cells.push(c));
+        cells.sort_unstable();
+        cells
+    }
+
+    #[test]
+    fn test_topologies() {
+        let square = SquareGrid {
+            rows: 2,
+            cols: 3,
+            diagonals: false,
+        };
+        assert_eq!(neighbours(&square, (0, 1)), vec![(0, 0), (0, 2), (1, 1)]);
+        let moore = SquareGrid {
+            diagonals: true,
+            ..square
+        };
+        assert_eq!(neighbours(&moore, (1, 0)).len(), 3);
+        assert_eq!(moore.index((1, 2)), Some(5));
+        assert_eq!(moore.index((2, 0)), None);
+        assert_eq!(moore.cell(4), (1, 1));
+
+        let mut count = 0;
+        Lattice::<4>.neighbours([0; 4], |c| {
+            assert_ne!(c, [0; 4]);
+            assert!(c.iter().all(|x| x.abs() <= 1));
+            count += 1;
+        });
+        assert_eq!(count, 80);
+
+        assert_eq!(
+            neighbours(&HexGrid, (0, 0)),
+            vec![(-2, 0), (-1, -1), (-1, 1), (1, -1), (1, 1), (2, 0)]
+        );
+
+        let graph = Graph::new(vec![('a', vec!['b']), ('b', vec!['a', 'c']), ('c', vec![])]);
+        assert_eq!(neighbours(&graph, 'b'), vec!['a', 'c']);
+        assert_eq!(
+            (graph.len(), graph.index('c'), graph.cell(0)),
+            (3, Some(2), 'a')
+        );
+    }
+
+    #[test]
+    fn test_blinker() {
+        let grid = SquareGrid {
+            rows: 5,
+            cols: 5,
+            diagonals: true,
+        };
+        let blinker = vec![(2, 1), (2, 2), (2, 3)];
+        let mut dense = Automaton::dense(grid, conway(), blinker.clone());
+        let mut sparse = Automaton::sparse(grid, conway(), blinker);
+
+        assert_eq!(dense.live_neighbours((1, 2)), 3);
+        assert!(dense.step());
+        sparse.run(1);
+        for automaton in [dense.alive(), sparse.alive()].iter_mut() {
+            automaton.sort_unstable();
+            assert_eq!(*automaton, vec![(1, 2), (2, 2), (3, 2)]);
+        }
+
+        assert_eq!(dense.run_until_stable(10), None);
+        assert_eq!(dense.generation(), 11);
+        assert_eq!(
+            sparse.find_cycle(10),
+            Some(Cycle {
+                start: 1,
+                period: 2
+            })
+        );
+        assert_eq!(sparse.generation(), 3);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        // A block is stable, while the surrounding cells of a glider on a
+        // bounded grid eventually settle into one.
+        let grid = SquareGrid {
+            rows: 6,
+            cols: 6,
+            diagonals: true,
+        };
+        let glider = vec![(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)];
+        let mut automaton = Automaton::dense(grid, conway(), glider);
+        let stable = automaton.run_until_stable(100).unwrap();
+        assert_eq!(automaton.generation(), stable);
+        assert_eq!(automaton.population(), 4);
+        assert!(!automaton.step());
+        assert_eq!(
+            automaton.find_cycle(5).map(|c| c.period),
+            Some(1),
+            "a fixed point is a cycle of period one"
+        );
+    }
+
+    #[test]
+    fn test_sparse() {
+        // The glider travels forever on the unbounded lattice.
+        let glider = vec![[0, 1], [1, 2], [2, 0], [2, 1], [2, 2]];
+        let mut automaton = Automaton::sparse(Lattice::<2>, conway(), glider.clone());
+        let populations = automaton.generations().take(8).collect::<Vec<_>>();
+        assert_eq!(populations, vec![5; 8]);
+        let mut alive = automaton.alive();
+        alive.sort_unstable();
+        let mut moved = glider
+            .iter()
+            .map(|c| [c[0] + 2, c[1] + 2])
+            .collect::<Vec<_>>();
+        moved.sort_unstable();
+        assert_eq!(alive, moved);
+        assert_eq!(automaton.find_cycle(20), None);
+
+        // Rules can be given as closures.
+        let spread = |alive: bool, n: usize| alive || n > 0;
+        let mut automaton = Automaton::sparse(HexGrid, spread, vec![(0, 0)]);
+        automaton.run(2);
+        assert_eq!(automaton.population(), 19);
+    }
+}
@@ -0,0 +1,333 @@
+//! This module contains the benchmark mode, which runs the solutions
+//! repeatedly and reports the time spent on parsing the input and on
+//! computing the answer.
+//!
+//! The median timings can be saved as a baseline in a TOML file, which
+//! contains one table per day with the timings in nanoseconds:
+//!
+//! ```toml
+//! [day15]
+//! part1 = { parse = 1200, solve = 95000 }
+//! part2 = { parse = 1100, solve = 1650000000 }
+//! ```
+//!
+//! Later measurements are compared against the baseline to notice
+//! performance regressions.
+use crate::answers::malformed_key;
+use crate::{AocError, Day, Part, Result};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::path::Path;
+use std::time::Duration;
+
+/// The time spent on a single run of a solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timing {
+    /// The time spent on parsing the puzzle input.
+    pub parse: Duration,
+    /// The time spent on computing the answer from the parsed input.
+    pub solve: Duration,
+}
+
+impl Timing {
+    /// The time spent on parsing and solving.
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// The minimum, median and maximum of a series of durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// The shortest duration.
+    pub min: Duration,
+    /// The median duration.
+    pub median: Duration,
+    /// The longest duration.
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Compute the statistics of `samples`.
+    ///
+    /// Panics if `samples` is empty.
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let n = samples.len();
+        Self {
+            min: samples[0],
+            median: (samples[(n - 1) / 2] + samples[n / 2]) / 2,
+            max: samples[n - 1],
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:.3?} / {:.3?} / {:.3?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+/// The result of benchmarking a part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    /// The answer computed by the solution.
+    pub answer: String,
+    /// The statistics of the time spent on parsing.
+    pub parse: Stats,
+    /// The statistics of the time spent on solving.
+    pub solve: Stats,
+}
+
+impl Measurement {
+    /// The median timing, as stored in a [`Baseline`].
+    pub fn median(&self) -> Timing {
+        Timing {
+            parse: self.parse.median,
+            solve: self.solve.median,
+        }
+    }
+}
+
+/// Run `part` of `day` on the puzzle input `data` the given number of `runs`,
+/// which is at least one.
+pub fn measure(day: &Day, data: &str, part: Part, runs: usize) -> Result<Measurement> {
+    let mut answer = String::new();
+    let (mut parse, mut solve) = (vec![], vec![]);
+    for _ in 0..runs.max(1) {
+        let (result, timing) = day.solve_timed(data, part)?;
+        answer = result;
+        parse.push(timing.parse);
+        solve.push(timing.solve);
+    }
+    Ok(Measurement {
+        answer,
+        parse: Stats::new(&parse),
+        solve: Stats::new(&solve),
+    })
+}
+
+/// The change of a measurement with respect to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    /// The baseline contains no timing to compare against.
+    New,
+    /// The total time changed by the given percentage, within the tolerance.
+    Unchanged(f64),
+    /// The total time decreased by more than the tolerance.
+    Improved(f64),
+    /// The total time increased by more than the tolerance.
+    Regressed(f64),
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::New => write!(f, "new"),
+            Self::Unchanged(change) => write!(f, "{:+.1}%", change),
+            Self::Improved(change) => write!(f, "{:+.1}%, improved", change),
+            Self::Regressed(change) => write!(f, "{:+.1}%, regressed", change),
+        }
+    }
+}
+
+/// The median timings of the parts of the days.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    timings: HashMap<(u32, Part), Timing>,
+}
+
+impl Baseline {
+    /// Parse the baseline from its TOML representation in `data`.
+    pub fn parse(data: &str) -> Result<Self> {
+        let table = data.parse::<toml::Table>().map_err(|err| {
+            let start = err.span().map_or(data.len(), |span| span.start);
+            AocError::malformed_in(data, &data[start..start], err.message())
+        })?;
+
+        let mut timings = HashMap::new();
+        for (key, parts) in &table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u32>().ok())
+                .filter(|d| (1..=25).contains(d))
+                .ok_or_else(|| malformed_key(data, None, key, "expected a table `[day<1-25>]`"))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| malformed_key(data, None, key, "expected a table `[day<1-25>]`"))?;
+
+            for (name, timing) in parts {
+                let part = match name.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => {
+                        return Err(malformed_key(
+                            data,
+                            Some(key),
+                            name,
+                            "expected `part1` or `part2`",
+                        ))
+                    }
+                };
+                let nanos = |field: &str| {
+                    timing
+                        .get(field)
+                        .and_then(toml::Value::as_integer)
+                        .and_then(|n| u64::try_from(n).ok())
+                        .map(Duration::from_nanos)
+                };
+                let timing = match (nanos("parse"), nanos("solve")) {
+                    (Some(parse), Some(solve)) => Timing { parse, solve },
+                    _ => {
+                        return Err(malformed_key(
+                            data,
+                            Some(key),
+                            name,
+                            "expected `{ parse = <ns>, solve = <ns> }`",
+                        ))
+                    }
+                };
+                timings.insert((day, part), timing);
+            }
+        }
+
+        Ok(Self { timings })
+    }
+
+    /// Read the baseline from the file at `path`.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::parse(&crate::read_string(path)?)
+    }
+
+    /// Write the baseline to the file at `path`.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        Ok(std::fs::write(path, self.to_string())?)
+    }
+
+    /// Record the median `timing` of `part` of `day`.
+    pub fn insert(&mut self, day: u32, part: Part, timing: Timing) {
+        self.timings.insert((day, part), timing);
+    }
+
+    /// The recorded timing of `part` of `day`, if any.
+    pub fn get(&self, day: u32, part: Part) -> Option<Timing> {
+        self.timings.get(&(day, part)).copied()
+    }
+
+    /// Compare the total time of `timing` to the recorded timing of `part` of
+    /// `day`, where changes up to `tolerance` percent are considered noise.
+    pub fn compare(&self, day: u32, part: Part, timing: Timing, tolerance: f64) -> Comparison {
+        let baseline = match self.get(day, part) {
+            Some(baseline) if !baseline.total().is_zero() => baseline.total().as_secs_f64(),
+            _ => return Comparison::New,
+        };
+        let change = (timing.total().as_secs_f64() / baseline - 1.0) * 100.0;
+        if change > tolerance {
+            Comparison::Regressed(change)
+        } else if change < -tolerance {
+            Comparison::Improved(change)
+        } else {
+            Comparison::Unchanged(change)
+        }
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# The median timings of the solutions in nanoseconds.")?;
+        let mut keys = self.timings.keys().collect::<Vec<_>>();
+        keys.sort();
+        let mut previous = None;
+        for &(day, part) in keys {
+            if previous != Some(day) {
+                writeln!(f, "\n[day{}]", day)?;
+                previous = Some(day);
+            }
+            let timing = self.timings[&(day, part)];
+            writeln!(
+                f,
+                "part{} = {{ parse = {}, solve = {} }}",
+                part,
+                timing.parse.as_nanos(),
+                timing.solve.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&[ms(5), ms(1), ms(3)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(5)));
+        let stats = Stats::new(&[ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(8)));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        let timing = Timing {
+            parse: Duration::from_nanos(1200),
+            solve: Duration::from_nanos(95000),
+        };
+        baseline.insert(15, Part::One, timing);
+        baseline.insert(15, Part::Two, Timing::default());
+        baseline.insert(1, Part::Two, timing);
+
+        let parsed = Baseline::parse(&baseline.to_string()).unwrap();
+        assert_eq!(parsed, baseline);
+        assert_eq!(parsed.get(15, Part::One), Some(timing));
+        assert_eq!(parsed.get(1, Part::One), None);
+    }
+
+    #[test]
+    fn test_baseline_malformed() {
+        let line_of = |data| match Baseline::parse(data) {
+            Err(AocError::MalformedLine(err)) => err.line,
+            res => panic!("unexpected result {:?}", res),
+        };
+        assert_eq!(line_of("[day1]\npart1 = { parse = 1 }"), 2);
+        assert_eq!(line_of("[day1]\npart1 = { parse = 1, solve = -2 }"), 2);
+        assert_eq!(line_of("[day0]\npart1 = { parse = 1, solve = 2 }"), 1);
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = Baseline::default();
+        baseline.insert(
+            1,
+            Part::One,
+            Timing {
+                parse: ms(10),
+                solve: ms(90),
+            },
+        );
+        let timing = |millis| Timing {
+            parse: ms(0),
+            solve: ms(millis),
+        };
+        let compare = |millis| baseline.compare(1, Part::One, timing(millis), 10.0);
+        assert!(matches!(compare(105), Comparison::Unchanged(_)));
+        assert!(matches!(compare(120), Comparison::Regressed(c) if (c - 20.0).abs() < 1e-9));
+        assert!(matches!(compare(50), Comparison::Improved(c) if (c + 50.0).abs() < 1e-9));
+        assert_eq!(
+            baseline.compare(1, Part::Two, timing(1), 10.0),
+            Comparison::New
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! This module contains the error type shared by all solutions.
+//!
+//! Instead of panicking on unexpected input, the parsers and solvers
+//! report what went wrong by returning an [`AocError`].
+use std::fmt;
+use std::io;
+
+/// A specialized `Result` type for the solutions in this crate.
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// A line of the puzzle input that does not have the expected format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    /// The (1-based) number of the offending line.
+    pub line: usize,
+    /// The content of the offending line.
+    pub text: String,
+    /// A description of what was expected.
+    pub reason: String,
+}
+
+impl LineError {
+    /// Create an error for the line of `data` that contains `part`.
+    ///
+    /// See [`AocError::malformed_in`] for the requirements on `part`.
+    pub(crate) fn within(data: &str, part: &str, reason: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .saturating_sub(data.as_ptr() as usize)
+            .min(data.len());
+        let start = data[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = data[offset..].find('\n').map_or(data.len(), |i| offset + i);
+        Self {
+            line: data[..offset].matches('\n').count() + 1,
+            text: data[start..end].trim_end_matches('\r').to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}: malformed input `{}`: {}",
+            self.line, self.text, self.reason
+        )
+    }
+}
+
+/// The errors that can occur while fetching, reading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum AocError {
+    /// The puzzle input could not be read.
+    Io(io::Error),
+    /// A line of the puzzle input does not have the expected format.
+    MalformedLine(LineError),
+    /// Several lines of the puzzle input do not have the expected format.
+    MalformedLines(Vec<LineError>),
+    /// The puzzle input is well-formed, but the puzzle has no solution for it.
+    Unsolvable(String),
+    /// The Advent of Code server could not be reached or rejected the request.
+    Remote(String),
+}
+
+impl AocError {
+    /// Create an error for the (1-based) `line` with content `text`.
+    pub fn malformed(line: usize, text: &str, reason: impl Into<String>) -> Self {
+        Self::MalformedLine(LineError {
+            line,
+            text: text.to_string(),
+            reason: reason.into(),
+        })
+    }
+
+    /// Create an error for the line of `data` that contains `part`.
+    ///
+    /// `part` needs to be a sub-slice of `data` (e.g. obtained by splitting
+    /// `data`), since the line number is determined from its position. The
+    /// whole line containing `part` is reported.
+    pub fn malformed_in(data: &str, part: &str, reason: impl Into<String>) -> Self {
+        Self::MalformedLine(LineError::within(data, part, reason))
+    }
+
+    /// Create an error for input that ended before the `expected` content was found.
+    ///
+    /// The line reported is the one following the last line of `data`.
+    pub fn unexpected_end(data: &str, expected: impl Into<String>) -> Self {
+        Self::malformed(data.lines().count() + 1, "", expected)
+    }
+
+    /// Create an error for a puzzle that has no solution.
+    pub fn unsolvable(reason: impl Into<String>) -> Self {
+        Self::Unsolvable(reason.into())
+    }
+
+    /// Create an error for a request to the Advent of Code server that failed.
+    pub fn remote(reason: impl Into<String>) -> Self {
+        Self::Remote(reason.into())
+    }
+
+    /// The malformed lines of the puzzle input this error refers to, if any.
+    pub fn line_errors(&self) -> &[LineError] {
+        match self {
+            Self::MalformedLine(err) => std::slice::from_ref(err),
+            Self::MalformedLines(errs) => errs,
+            _ => &[],
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "unable to read input: {}", err),
+            Self::MalformedLine(err) => err.fmt(f),
+            Self::MalformedLines(errs) => {
+                write!(f, "{} malformed lines", errs.len())?;
+                for err in errs {
+                    write!(f, "\n  {}", err)?;
+                }
+                Ok(())
+            }
+            Self::Unsolvable(reason) => write!(f, "no solution: {}", reason),
+            Self::Remote(reason) => write!(f, "request failed: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_in() {
+        let data = "first\nsecond line\nthird";
+        let part = &data[13..17];
+        assert_eq!(part, "line");
+        match AocError::malformed_in(data, part, "oops") {
+            AocError::MalformedLine(err) => {
+                assert_eq!(err.line, 2);
+                assert_eq!(err.text, "second line");
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_unexpected_end() {
+        match AocError::unexpected_end("a\nb\n", "more") {
+            AocError::MalformedLine(err) => {
+                assert_eq!(err.line, 3);
+                assert_eq!(err.text, "");
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            AocError::malformed(3, "nop x", "expected a number").to_string(),
+            "line 3: malformed input `nop x`: expected a number"
+        );
+        assert_eq!(
+            AocError::MalformedLines(vec![
+                LineError {
+                    line: 1,
+                    text: "a".to_string(),
+                    reason: "expected a number".to_string(),
+                },
+                LineError {
+                    line: 4,
+                    text: "b".to_string(),
+                    reason: "expected a number".to_string(),
+                },
+            ])
+            .to_string(),
+            "2 malformed lines\n  line 1: malformed input `a`: expected a number\n  line 4: malformed input `b`: expected a number"
+        );
+        assert_eq!(
+            AocError::unsolvable("the program does not loop").to_string(),
+            "no solution: the program does not loop"
+        );
+    }
+}
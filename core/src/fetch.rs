@@ -0,0 +1,225 @@
+//! This module contains the input fetcher, which downloads the puzzle
+//! inputs from the Advent of Code server and caches them on disk.
+//!
+//! The inputs are cached as the `dayN.txt` files of a profile directory,
+//! such that the solutions can be run on them right away. A day that is
+//! cached is never fetched again.
+//!
+//! The server is accessed through an [`Endpoint`], which allows to replace
+//! it, e.g. by a local stand-in server with a different base URL.
+use crate::{AocError, Result};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The base URL of the Advent of Code server.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The time after which a request to the server is given up.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The identification of the requests sent to the server.
+pub(crate) const USER_AGENT: &str = concat!(
+    "github.com/FractalArt/AdventOfCode2020 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// A source of puzzle inputs.
+pub trait Endpoint {
+    /// Fetch the puzzle input of `day`.
+    fn fetch(&self, day: u32) -> Result<String>;
+}
+
+/// An HTTP server providing the puzzle inputs of a year at
+/// `<base URL>/<year>/day/<N>/input`, authenticated with a session token.
+#[derive(Debug)]
+pub struct HttpEndpoint {
+    pub(crate) base_url: String,
+    pub(crate) year: u32,
+    pub(crate) session: String,
+    pub(crate) agent: ureq::Agent,
+}
+
+impl HttpEndpoint {
+    /// An endpoint for the puzzles of `year` on the server at `base_url`,
+    /// authenticated with the `session` token.
+    pub fn new(base_url: &str, year: u32, session: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(REQUEST_TIMEOUT))
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year,
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// The base URL of the server.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+}
+
+impl Endpoint for HttpEndpoint {
+    fn fetch(&self, day: u32) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, self.year, day);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|err| AocError::remote(format!("GET {}: {}", url, err)))?;
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| AocError::remote(format!("GET {}: {}", url, err)))?;
+        match status {
+            200 => Ok(body),
+            400 | 401 | 403 => Err(AocError::remote(format!(
+                "GET {}: the session token was rejected ({})",
+                url, status
+            ))),
+            404 => Err(AocError::remote(format!(
+                "GET {}: the puzzle is not available ({})",
+                url, status
+            ))),
+            _ => Err(AocError::remote(format!(
+                "GET {}: unexpected status {}",
+                url, status
+            ))),
+        }
+    }
+}
+
+/// Where a fetched puzzle input came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already cached.
+    Cached,
+    /// The input was downloaded from the endpoint and cached.
+    Downloaded,
+}
+
+/// Fetches puzzle inputs from an [`Endpoint`] into the cache directory `dir`.
+#[derive(Debug)]
+pub struct Fetcher<E> {
+    endpoint: E,
+    dir: PathBuf,
+}
+
+impl<E: Endpoint> Fetcher<E> {
+    /// A fetcher caching the inputs of `endpoint` in the directory `dir`,
+    /// which is created when needed.
+    pub fn new<P: Into<PathBuf>>(endpoint: E, dir: P) -> Self {
+        Self {
+            endpoint,
+            dir: dir.into(),
+        }
+    }
+
+    /// The cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The location of the cached input of `day`.
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("day{}.txt", day))
+    }
+
+    /// Whether the input of `day` is cached.
+    pub fn is_cached(&self, day: u32) -> bool {
+        self.path(day).is_file()
+    }
+
+    /// The `days` whose input is not cached.
+    pub fn missing<I: IntoIterator<Item = u32>>(&self, days: I) -> Vec<u32> {
+        days.into_iter().filter(|&d| !self.is_cached(d)).collect()
+    }
+
+    /// Make sure that the input of `day` is cached, fetching it from the
+    /// endpoint unless it already is.
+    pub fn fetch(&self, day: u32) -> Result<Fetched> {
+        if !(1..=25).contains(&day) {
+            return Err(AocError::Io(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("`{}` is not a day between 1 and 25", day),
+            )));
+        }
+        if self.is_cached(day) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.endpoint.fetch(day)?;
+        if input.trim().is_empty() {
+            return Err(AocError::remote(format!(
+                "the input of day {} is empty",
+                day
+            )));
+        }
+        // Write to a temporary file first, such that an interrupted download
+        // never leaves a truncated input behind that counts as cached.
+        std::fs::create_dir_all(&self.dir)?;
+        let partial = self.dir.join(format!(".day{}.txt.part", day));
+        std::fs::write(&partial, input)?;
+        std::fs::rename(&partial, self.path(day))?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// An endpoint recording the days requested from it.
+    #[derive(Default)]
+    struct Recorder {
+        requests: RefCell<Vec<u32>>,
+    }
+
+    impl Endpoint for &Recorder {
+        fn fetch(&self, day: u32) -> Result<String> {
+            self.requests.borrow_mut().push(day);
+            match day {
+                13 => Err(AocError::remote("the puzzle is not available")),
+                14 => Ok("\n".to_string()),
+                _ => Ok(format!("input {}\n", day)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_fetch() {
+        let dir = std::env::temp_dir().join(format!("aoc2020-fetch-{}", std::process::id()));
+        let recorder = Recorder::default();
+        let fetcher = Fetcher::new(&recorder, &dir);
+
+        assert_eq!(fetcher.missing(1..=3), vec![1, 2, 3]);
+        assert_eq!(fetcher.fetch(2).unwrap(), Fetched::Downloaded);
+        assert_eq!(fetcher.fetch(2).unwrap(), Fetched::Cached);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("day2.txt")).unwrap(),
+            "input 2\n"
+        );
+        assert_eq!(fetcher.missing(1..=3), vec![1, 3]);
+
+        assert!(fetcher.fetch(13).is_err());
+        assert!(fetcher.fetch(14).is_err());
+        assert!(fetcher.fetch(26).is_err());
+        assert_eq!(fetcher.missing(13..=14), vec![13, 14]);
+        assert_eq!(*recorder.requests.borrow(), vec![2, 13, 14]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
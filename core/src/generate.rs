@@ -0,0 +1,173 @@
+//! This module contains the generation of synthetic puzzle inputs.
+//!
+//! Besides the one real input per day, the solutions can produce random
+//! inputs of a configurable size, see [`Solution::generate`](crate::Solution::generate).
+//! The inputs are generated from a seeded [`Rng`], such that the same seed
+//! always yields the same input, and come as a [`Sample`] along with the
+//! answers whenever they follow from the way the input is constructed.
+use crate::Part;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+/// A small, fast and reproducible pseudo-random number generator (SplitMix64).
+///
+/// It is not suited for anything but generating test data.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// A generator whose numbers are determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// The next random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A random number below `n`, which must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick a number below zero");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A random number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "cannot pick a number in an empty range");
+        match (high - low).checked_add(1) {
+            Some(len) => low + self.below(len),
+            None => self.next_u64() as usize,
+        }
+    }
+
+    /// Whether an event with the given `probability` happens.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < probability
+    }
+
+    /// A random element of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffle `items` in place, such that every order is equally likely.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A random word of `len` lowercase letters.
+    pub fn word(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+/// A generated puzzle input along with the answers to its parts, as far
+/// as they are known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    /// The content of the input file.
+    pub data: String,
+    /// The answer to the first part, if known.
+    pub answer_1: Option<String>,
+    /// The answer to the second part, if known.
+    pub answer_2: Option<String>,
+}
+
+impl Sample {
+    /// A sample of the input `data` whose answers are unknown.
+    pub fn new(data: impl Into<String>) -> Self {
+        Self {
+            data: data.into(),
+            answer_1: None,
+            answer_2: None,
+        }
+    }
+
+    /// The sample with the `answer` to the given `part`.
+    pub fn with_answer(mut self, part: Part, answer: impl Display) -> Self {
+        let answer = Some(answer.to_string());
+        match part {
+            Part::One => self.answer_1 = answer,
+            Part::Two => self.answer_2 = answer,
+        }
+        self
+    }
+
+    /// The answer to `part`, if known.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.answer_1.as_deref(),
+            Part::Two => self.answer_2.as_deref(),
+        }
+    }
+}
+
+/// The lines of `data` joined by line breaks, with a final one.
+pub fn lines<T: Display>(data: impl IntoIterator<Item = T>) -> String {
+    data.into_iter().map(|line| format!("{}\n", line)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            assert!(rng.below(7) < 7);
+            assert!((3..=5).contains(&rng.range(3..=5)));
+            assert_eq!(rng.range(4..=4), 4);
+        }
+        rng.range(0..=usize::MAX);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+        assert_eq!(rng.word(6).len(), 6);
+        assert!(rng.word(20).chars().all(|c| c.is_ascii_lowercase()));
+
+        // Every value is picked eventually.
+        let mut seen = [false; 4];
+        for _ in 0..100 {
+            seen[*rng.pick(&[0, 1, 2, 3])] = true;
+        }
+        assert_eq!(seen, [true; 4]);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(3);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sample() {
+        let sample = Sample::new("1\n2\n").with_answer(Part::Two, 3);
+        assert_eq!(sample.answer(Part::One), None);
+        assert_eq!(sample.answer(Part::Two), Some("3"));
+        assert_eq!(lines([1, 2]), sample.data);
+    }
+}
@@ -0,0 +1,426 @@
+//! This module contains a generic two-dimensional grid, as used by the
+//! challenges whose puzzle input is a map of characters.
+//!
+//! The cells are addressed by their position `(row, column)`, where the
+//! row grows downwards and the column to the right, starting at the top
+//! left corner `(0, 0)`.
+use crate::{AocError, Result};
+use std::ops::{Index, IndexMut};
+
+/// The position `(row, column)` of a cell.
+pub type Pos = (usize, usize);
+
+/// The steps `(row, column)` towards the four orthogonal neighbours,
+/// clockwise starting upwards.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The steps `(row, column)` towards the eight neighbours including the
+/// diagonal ones, clockwise starting upwards.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// The edges of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Edge {
+    /// All edges, clockwise starting at the top.
+    pub const ALL: [Edge; 4] = [Edge::Top, Edge::Right, Edge::Bottom, Edge::Left];
+}
+
+/// A rectangular grid of cells of type `T`, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `rows` by `cols` cells, all set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+
+    /// A grid of `rows` by `cols` cells, whose values are computed from their position by `cell`.
+    pub fn from_fn<F: FnMut(Pos) -> T>(rows: usize, cols: usize, mut cell: F) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: (0..rows * cols)
+                .map(|i| cell((i / cols, i % cols)))
+                .collect(),
+        }
+    }
+
+    /// Parse the map of characters `data`, translating each character into a cell by `cell`.
+    ///
+    /// Each non-blank line makes up a row, whose surrounding whitespace is
+    /// ignored. Characters rejected by `cell` and rows of a different length
+    /// than the first one are reported along with their line.
+    pub fn parse<F: Fn(char) -> Option<T>>(data: &str, cell: F) -> Result<Self> {
+        let (mut rows, mut cols, mut cells) = (0, 0, vec![]);
+        for line in data.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let len = cells.len();
+            for c in line.chars() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(AocError::malformed_in(
+                            data,
+                            line,
+                            format!("unexpected character `{}`", c),
+                        ))
+                    }
+                }
+            }
+            if rows == 0 {
+                cols = cells.len();
+            } else if cells.len() - len != cols {
+                return Err(AocError::malformed_in(
+                    data,
+                    line,
+                    format!("expected a row of {} cells", cols),
+                ));
+            }
+            rows += 1;
+        }
+        Ok(Self { rows, cols, cells })
+    }
+
+    /// The number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Whether the grid has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Whether `pos` lies within the grid.
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 < self.rows && pos.1 < self.cols
+    }
+
+    /// The cell at `pos`, if it lies within the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The mutable cell at `pos`, if it lies within the grid.
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(row, col)`, where the grid repeats itself indefinitely
+    /// in all directions. Only an empty grid has no cells.
+    pub fn get_wrapping(&self, row: isize, col: isize) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        let row = row.rem_euclid(self.rows as isize) as usize;
+        let col = col.rem_euclid(self.cols as isize) as usize;
+        self.get((row, col))
+    }
+
+    /// The position reached by taking the step `dir` from `pos`, if it lies within the grid.
+    pub fn step(&self, pos: Pos, dir: (isize, isize)) -> Option<Pos> {
+        let row = pos.0.checked_add_signed(dir.0)?;
+        let col = pos.1.checked_add_signed(dir.1)?;
+        Some((row, col)).filter(|&p| self.contains(p))
+    }
+
+    /// The positions of all cells, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.cells.len()).map(move |i| (i / cols, i % cols))
+    }
+
+    /// The cells, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The cells along with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The orthogonal neighbours of `pos` within the grid.
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS_4.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` within the grid.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS_8.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// The positions seen from `pos` when looking in the direction `dir`,
+    /// i.e. those reached by repeatedly taking the step `dir` until leaving
+    /// the grid. The position `pos` itself is not included.
+    pub fn ray(&self, pos: Pos, dir: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        let mut current = Some(pos).filter(|_| dir != (0, 0));
+        std::iter::from_fn(move || {
+            current = self.step(current?, dir);
+            current
+        })
+    }
+
+    /// The cells of row `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` lies outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {} outside the grid", row);
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The cells of column `col`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` lies outside the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {} outside the grid", col);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// Transform each cell by `f`, which also receives its position.
+    pub fn map<U, F: FnMut(Pos, &T) -> U>(&self, mut f: F) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+        }
+    }
+
+    /// Render the grid as a map of characters, translating each cell by `f`.
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        let mut map = String::with_capacity(self.cells.len() + self.rows);
+        for row in 0..self.rows {
+            if row > 0 {
+                map.push('\n');
+            }
+            map.extend(self.row(row).iter().map(&f));
+        }
+        map
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The cells along `edge`, from left to right or from top to bottom.
+    pub fn edge(&self, edge: Edge) -> Vec<T> {
+        if self.is_empty() {
+            return vec![];
+        }
+        match edge {
+            Edge::Top => self.row(0).to_vec(),
+            Edge::Bottom => self.row(self.rows - 1).to_vec(),
+            Edge::Left => self.column(0).cloned().collect(),
+            Edge::Right => self.column(self.cols - 1).cloned().collect(),
+        }
+    }
+
+    /// The grid rotated clockwise by a quarter turn.
+    pub fn rotate(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |(row, col)| {
+            self[(self.rows - 1 - col, row)].clone()
+        })
+    }
+
+    /// The grid mirrored at its vertical axis, i.e. with the columns reversed.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.rows, self.cols, |(row, col)| {
+            self[(row, self.cols - 1 - col)].clone()
+        })
+    }
+
+    /// The grid mirrored at its horizontal axis, i.e. with the rows reversed.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.rows, self.cols, |(row, col)| {
+            self[(self.rows - 1 - row, col)].clone()
+        })
+    }
+
+    /// The grid mirrored at its main diagonal.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |(row, col)| self[(col, row)].clone())
+    }
+
+    /// The eight orientations of the grid reached by rotating and flipping it,
+    /// starting with the grid itself and its three rotations.
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut orientations = Vec::with_capacity(8);
+        for start in [self.clone(), self.flip_horizontal()] {
+            let mut grid = start;
+            for _ in 0..4 {
+                let next = grid.rotate();
+                orientations.push(grid);
+                grid = next;
+            }
+        }
+        orientations
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("position {:?} outside the grid", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("position {:?} outside the grid", pos),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &str) -> Grid<char> {
+        Grid::parse(data, |c| Some(c).filter(char::is_ascii_alphanumeric)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = parse("\n abc \ndef\n\n");
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.render(|&c| c), "abc\ndef");
+
+        let bits = Grid::parse("#.\n.#", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(bits.cells().filter(|&&b| b).count(), 2);
+
+        let err = Grid::parse("ab\nc?", |c| Some(c).filter(char::is_ascii_alphabetic));
+        assert_eq!(err.unwrap_err().line_errors()[0].line, 2);
+        let err = Grid::parse("ab\n\nabc", Some);
+        assert_eq!(err.unwrap_err().line_errors()[0].line, 3);
+        assert!(Grid::parse("", Some).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = parse("abc\ndef");
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get_wrapping(3, -1), Some(&'f'));
+        assert_eq!(grid.get_wrapping(-2, 7), Some(&'b'));
+        assert_eq!(Grid::<char>::new(0, 0, 'x').get_wrapping(0, 0), None);
+
+        grid[(0, 0)] = 'z';
+        assert_eq!(grid.row(0), &['z', 'b', 'c']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.iter().nth(4),
+            Some(((1, 1), &'e')),
+            "cells are enumerated row by row"
+        );
+        assert_eq!(
+            grid.map(|(row, _), &c| (c, row)).get((1, 0)),
+            Some(&('d', 1))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = parse("abc\ndef\nghi");
+        let around = |pos, four: bool| {
+            let positions: Vec<_> = if four {
+                grid.neighbours_4(pos).collect()
+            } else {
+                grid.neighbours_8(pos).collect()
+            };
+            positions.into_iter().map(|p| grid[p]).collect::<String>()
+        };
+        assert_eq!(around((1, 1), true), "bfhd");
+        assert_eq!(around((1, 1), false), "bcfihgda");
+        assert_eq!(around((0, 0), true), "bd");
+        assert_eq!(around((0, 0), false), "bed");
+        assert_eq!(around((2, 2), false), "fhe");
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = parse("abcd\nefgh\nijkl");
+        let seen = |pos, dir| grid.ray(pos, dir).map(|p| grid[p]).collect::<String>();
+        assert_eq!(seen((0, 0), (0, 1)), "bcd");
+        assert_eq!(seen((0, 0), (1, 1)), "fk");
+        assert_eq!(seen((2, 3), (-1, -1)), "gb");
+        assert_eq!(seen((1, 0), (0, -1)), "");
+        assert_eq!(seen((1, 1), (0, 0)), "");
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = parse("abc\ndef");
+        assert_eq!(grid.edge(Edge::Top), vec!['a', 'b', 'c']);
+        assert_eq!(grid.edge(Edge::Right), vec!['c', 'f']);
+        assert_eq!(grid.edge(Edge::Bottom), vec!['d', 'e', 'f']);
+        assert_eq!(grid.edge(Edge::Left), vec!['a', 'd']);
+
+        assert_eq!(grid.rotate().render(|&c| c), "da\neb\nfc");
+        assert_eq!(grid.rotate().rotate().rotate().rotate(), grid);
+        assert_eq!(grid.flip_horizontal().render(|&c| c), "cba\nfed");
+        assert_eq!(grid.flip_vertical().render(|&c| c), "def\nabc");
+        assert_eq!(grid.transpose().render(|&c| c), "ad\nbe\ncf");
+
+        let orientations = grid.orientations();
+        assert_eq!(orientations.len(), 8);
+        assert!(orientations.contains(&grid.transpose()));
+        assert!(orientations.contains(&grid.flip_vertical()));
+        for (i, a) in orientations.iter().enumerate() {
+            assert!(orientations[i + 1..].iter().all(|b| a != b));
+        }
+    }
+}
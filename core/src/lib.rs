@@ -0,0 +1,562 @@
+//! # Advent Of Code
+//!
+//! The core shared by the solutions of all years.
+//!
+//! This module contains general utilities that are not associated
+//! to the challenge of a particular day or year, such as reading input
+//! data from a file, along with the interface the solutions implement
+//! and the registry of the days of a [`Year`].
+
+pub mod animate;
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod error;
+pub mod fetch;
+pub mod generate;
+pub mod grid;
+pub mod math;
+pub mod parse;
+pub mod profile;
+pub mod render;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+#[cfg(feature = "server")]
+pub mod serve;
+pub mod submit;
+pub mod trace;
+
+pub use animate::Frames;
+pub use answers::{Answers, Status};
+pub use bench::Timing;
+pub use error::{AocError, LineError, Result};
+pub use generate::{Rng, Sample};
+pub use grid::Grid;
+pub use profile::Profile;
+pub use render::Scene;
+
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{prelude::*, BufReader};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// The two parts each day's challenge consists of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The common interface of the solutions to each day's challenges.
+///
+/// The puzzle input is parsed once by [`parse`](Solution::parse) and the
+/// result is shared by both parts. Puzzle parameters that are not part of
+/// the input file (e.g. the number of cycles of a simulation) are fixed by
+/// the implementation.
+pub trait Solution {
+    /// The parsed representation of the puzzle input.
+    type Input;
+    /// The answer to the first part.
+    type Answer1: Display;
+    /// The answer to the second part.
+    type Answer2: Display;
+
+    /// Parse the raw content `data` of the puzzle input file.
+    fn parse(data: &str) -> Result<Self::Input>;
+
+    /// Compute the answer to the first part.
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    /// Compute the answer to the second part.
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Draw the spatial state the given `part` arrives at, for the days
+    /// that have one. By default there is nothing to draw.
+    fn render(_input: &Self::Input, _part: Part) -> Result<Option<Scene>> {
+        Ok(None)
+    }
+
+    /// The frames showing the intermediate states of the simulation of the
+    /// given `part`, for the days that have one. By default there are none.
+    fn animate(_input: &Self::Input, _part: Part) -> Result<Option<Frames>> {
+        Ok(None)
+    }
+
+    /// A random puzzle input whose size, e.g. its number of lines, is about
+    /// `size`, along with the answers if they are known. By default no input
+    /// can be generated.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<Sample> {
+        None
+    }
+
+    /// The solution of the given `part` that processes the lines of the
+    /// puzzle input one at a time as they are read, for the days that can.
+    /// By default there is none, and the whole input is parsed first.
+    fn streamer(_part: Part) -> Option<Streamer> {
+        None
+    }
+}
+
+/// A solution of a part that computes the answer from the lines of the
+/// puzzle input as they are read, see [`Solution::streamer`].
+pub type Streamer = fn(&mut dyn Iterator<Item = Result<String>>) -> Result<String>;
+
+/// The answer to a part that has not been solved (yet).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "To be done")
+    }
+}
+
+/// An entry of the day registry, allowing to run the [`Solution`]
+/// of a day without knowing its concrete types.
+#[derive(Clone, Copy)]
+pub struct Day {
+    /// The day of the challenge.
+    pub day: u32,
+    solve: fn(&str, Part) -> Result<(String, Timing)>,
+    render: fn(&str, Part) -> Result<Option<Scene>>,
+    animate: fn(&str, Part) -> Result<Option<Frames>>,
+    generate: fn(&mut Rng, usize) -> Option<Sample>,
+    streamer: fn(Part) -> Option<Streamer>,
+}
+
+impl Day {
+    /// Register the solution `S` for the given `day`.
+    pub const fn new<S: Solution>(day: u32) -> Self {
+        Self {
+            day,
+            solve: solve::<S>,
+            render: render::<S>,
+            animate: animate::<S>,
+            generate: S::generate,
+            streamer: S::streamer,
+        }
+    }
+
+    /// The default location of the puzzle input of this day, relative
+    /// to the directory of its [`Year`].
+    pub fn input_path(&self) -> String {
+        format!("data/day{}.txt", self.day)
+    }
+
+    /// Parse the puzzle input `data` and compute the answer to the given `part`.
+    pub fn solve(&self, data: &str, part: Part) -> Result<String> {
+        (self.solve)(data, part).map(|(answer, _)| answer)
+    }
+
+    /// Like [`Day::solve`], but also measure the time spent on parsing the
+    /// input and on computing the answer.
+    pub fn solve_timed(&self, data: &str, part: Part) -> Result<(String, Timing)> {
+        (self.solve)(data, part)
+    }
+
+    /// Parse the puzzle input `data` and draw the state the given `part`
+    /// arrives at, or `None` if the solution has nothing to draw.
+    pub fn render(&self, data: &str, part: Part) -> Result<Option<Scene>> {
+        (self.render)(data, part)
+    }
+
+    /// Parse the puzzle input `data` and animate the simulation of the given
+    /// `part`, or return `None` if the solution has nothing to animate.
+    pub fn animate(&self, data: &str, part: Part) -> Result<Option<Frames>> {
+        (self.animate)(data, part)
+    }
+
+    /// Generate a random puzzle input of about the given `size` from `seed`,
+    /// or return `None` if the solution cannot generate inputs.
+    pub fn generate(&self, size: usize, seed: u64) -> Option<Sample> {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+
+    /// Whether the given `part` can be solved while its puzzle input is
+    /// read, see [`Day::stream`].
+    pub fn streams(&self, part: Part) -> bool {
+        (self.streamer)(part).is_some()
+    }
+
+    /// Compute the answer to the given `part` from the lines of `reader` as
+    /// they are read, or return `None` if the solution needs the whole input.
+    pub fn stream<R: BufRead>(&self, reader: R, part: Part) -> Option<Result<String>> {
+        (self.streamer)(part).map(|streamer| streamer(&mut read_lines(reader)))
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Day").field("day", &self.day).finish()
+    }
+}
+
+/// Parse `data` using the solution `S` and compute the answer to `part`.
+fn solve<S: Solution>(data: &str, part: Part) -> Result<(String, Timing)> {
+    let start = Instant::now();
+    let input = S::parse(data)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        Part::One => S::part_1(&input)?.to_string(),
+        Part::Two => S::part_2(&input)?.to_string(),
+    };
+    let solve = start.elapsed();
+    Ok((answer, Timing { parse, solve }))
+}
+
+/// Parse `data` using the solution `S` and draw the state of `part`.
+fn render<S: Solution>(data: &str, part: Part) -> Result<Option<Scene>> {
+    S::render(&S::parse(data)?, part)
+}
+
+/// Parse `data` using the solution `S` and animate the simulation of `part`.
+fn animate<S: Solution>(data: &str, part: Part) -> Result<Option<Frames>> {
+    S::animate(&S::parse(data)?, part)
+}
+
+/// The solutions to the challenges of one year, i.e. one Advent of Code event.
+///
+/// Each year lives in a crate of its own, which registers its days along
+/// with the directory holding its `data/`.
+#[derive(Debug, Clone, Copy)]
+pub struct Year {
+    /// The year of the event.
+    pub year: u32,
+    days: &'static [Day],
+    dir: &'static str,
+}
+
+impl Year {
+    /// Register the `days` of `year`, whose crate is located at `dir`.
+    ///
+    /// The crate of a year passes `env!("CARGO_MANIFEST_DIR")` as `dir`,
+    /// such that its data is found regardless of the working directory.
+    pub const fn new(year: u32, days: &'static [Day], dir: &'static str) -> Self {
+        Self { year, days, dir }
+    }
+
+    /// The registered days, in order.
+    pub fn days(&self) -> &'static [Day] {
+        self.days
+    }
+
+    /// The registered day `day`, if any.
+    pub fn find_day(&self, day: u32) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+
+    /// The directory of the crate of this year.
+    pub fn dir(&self) -> &'static Path {
+        Path::new(self.dir)
+    }
+
+    /// The directory holding the puzzle inputs of this year, see [`profile::DATA_DIR`].
+    pub fn data_dir(&self) -> PathBuf {
+        self.dir().join(profile::DATA_DIR)
+    }
+}
+
+/// Parse each line of `data` into a `T`.
+///
+/// This is the in-memory counterpart of [`read_data`] and is used
+/// by the solutions whose input consists of one item per line.
+/// All the lines that cannot be parsed are reported in the error.
+pub fn parse_lines<T>(data: &str) -> Result<Vec<T>>
+where
+    T: std::str::FromStr,
+    <T as std::str::FromStr>::Err: Display,
+{
+    parse_numbered_lines(data.lines().map(Ok))
+}
+
+/// Parse each of the `lines` with `parser`.
+///
+/// All the lines rejected by `parser` are reported in the error, stating
+/// the `expected` format of the line.
+pub fn parse_each<'a, T, F>(lines: &'a [String], expected: &str, parser: F) -> Result<Vec<T>>
+where
+    F: Fn(&'a str) -> Option<T>,
+{
+    collect_line_results(lines.iter().enumerate().map(|(i, l)| {
+        parser(l).ok_or_else(|| LineError {
+            line: i + 1,
+            text: l.to_string(),
+            reason: expected.to_string(),
+        })
+    }))
+}
+
+/// Trim and parse each of the `lines` into a `T` as they are read, for the
+/// solutions that process their input incrementally.
+///
+/// Unlike [`parse_lines`], which reports all malformed lines at once, each
+/// value is yielded as soon as its line is parsed, and a malformed line
+/// yields its error instead.
+pub fn stream_data<T, S, I>(lines: I) -> impl Iterator<Item = Result<T>>
+where
+    T: std::str::FromStr,
+    <T as std::str::FromStr>::Err: Display,
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+{
+    lines.into_iter().enumerate().map(|(i, line)| {
+        let line = line?;
+        let line = line.as_ref();
+        line.trim()
+            .parse::<T>()
+            .map_err(|e| AocError::malformed(i + 1, line, e.to_string()))
+    })
+}
+
+/// Parse each of the `lines` with `parser` as they are read, like
+/// [`stream_data`] does with [`parse_lines`].
+///
+/// A line rejected by `parser` yields an error stating the `expected` format.
+pub fn stream_each<'a, T, S, I, F>(
+    lines: I,
+    expected: &'a str,
+    parser: F,
+) -> impl Iterator<Item = Result<T>> + 'a
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+    I::IntoIter: 'a,
+    F: Fn(&str) -> Option<T> + 'a,
+{
+    lines.into_iter().enumerate().map(move |(i, line)| {
+        let line = line?;
+        let line = line.as_ref();
+        parser(line).ok_or_else(|| AocError::malformed(i + 1, line, expected))
+    })
+}
+
+/// Trim and parse each of the `lines` into a `T`, where the first
+/// error while obtaining a line aborts the parsing.
+fn parse_numbered_lines<T, S, I>(lines: I) -> Result<Vec<T>>
+where
+    T: std::str::FromStr,
+    <T as std::str::FromStr>::Err: Display,
+    S: AsRef<str>,
+    I: Iterator<Item = Result<S>>,
+{
+    let mut results = vec![];
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.as_ref();
+        results.push(line.trim().parse::<T>().map_err(|e| LineError {
+            line: i + 1,
+            text: line.to_string(),
+            reason: e.to_string(),
+        }));
+    }
+    collect_line_results(results.into_iter())
+}
+
+/// Collect the values parsed from the lines of the input, unless
+/// some of the lines are malformed, in which case all of them are reported.
+pub(crate) fn collect_line_results<T, I>(results: I) -> Result<Vec<T>>
+where
+    I: Iterator<Item = std::result::Result<T, LineError>>,
+{
+    let mut values = vec![];
+    let mut errors = vec![];
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(AocError::MalformedLines(errors))
+    }
+}
+
+/// Split `data` into the blocks of lines that are separated by blank lines.
+///
+/// The blocks are sub-slices of `data` without their trailing line break,
+/// such that they can be used with [`AocError::malformed_in`]. Leading,
+/// trailing and repeated blank lines do not produce empty blocks and
+/// both `\n` and `\r\n` line endings are supported.
+pub fn blocks(data: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut block: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in data.split_inclusive('\n') {
+        let content = line.trim_end_matches(&['\n', '\r'][..]);
+        if content.trim().is_empty() {
+            if let Some((start, end)) = block.take() {
+                blocks.push(&data[start..end]);
+            }
+        } else {
+            let start = block.map_or(offset, |(start, _)| start);
+            block = Some((start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end)) = block {
+        blocks.push(&data[start..end]);
+    }
+    blocks
+}
+
+/// Read the data from the input file.
+///
+/// The path of the file is given by `path`.
+///
+/// The type into which each line shall be parsed
+/// is given by `T`. If some of the lines cannot be parsed,
+/// all of them are reported together with their line numbers.
+pub fn read_data<T, P: AsRef<Path>>(path: P) -> Result<Vec<T>>
+where
+    T: std::str::FromStr,
+    <T as std::str::FromStr>::Err: Display,
+{
+    let f = File::open(path)?;
+    parse_numbered_lines(BufReader::new(f).lines().map(|l| l.map_err(AocError::from)))
+}
+
+/// Open the input file at `path` to read it line by line, where the path
+/// `-` stands for stdin.
+pub fn open_input<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        Ok(Box::new(std::io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+/// The lines of `reader`, which are read one at a time as they are consumed.
+///
+/// Together with [`open_input`], this feeds the solutions that process their
+/// input incrementally, e.g. with [`stream_data`]:
+///
+/// ```no_run
+/// # use aoc_core::{open_input, read_lines, stream_data};
+/// let entries = stream_data::<u32, _, _>(read_lines(open_input("-")?));
+/// # Ok::<(), aoc_core::AocError>(())
+/// ```
+pub fn read_lines<R: BufRead>(reader: R) -> impl Iterator<Item = Result<String>> {
+    reader.lines().map(|line| line.map_err(AocError::from))
+}
+
+/// Read the blocks of lines separated by blank lines from the input file.
+///
+/// The path of the file is given by `path`. See [`blocks`] for
+/// how the blocks are delimited.
+pub fn read_blocks<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
+    Ok(blocks(&read_string(path)?)
+        .into_iter()
+        .map(str::to_string)
+        .collect())
+}
+
+/// Read the whole content of the input file as it is.
+///
+/// The path of the file is given by `path`.
+pub fn read_string<P: AsRef<Path>>(path: P) -> Result<String> {
+    Ok(std::fs::read_to_string(path)?)
+}
+
+/// Read the whole content of the input file at `path`, where the path `-`
+/// stands for stdin.
+pub fn read_input<P: AsRef<Path>>(path: P) -> Result<String> {
+    let mut content = String::new();
+    open_input(path)?.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines::<u32>("1\n 2 \n3").unwrap(), vec![1, 2, 3]);
+
+        let err = parse_lines::<u32>("1\nx\n3\n-4").unwrap_err();
+        let lines = err.line_errors();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].line, 2);
+        assert_eq!(lines[0].text, "x");
+        assert_eq!(lines[1].line, 4);
+        assert_eq!(lines[1].text, "-4");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let data = "a\nb\n\nc\r\n\r\n\n d \n";
+        assert_eq!(blocks(data), vec!["a\nb", "c", " d "]);
+        assert_eq!(blocks("\n\na\n\n"), vec!["a"]);
+        assert!(blocks("").is_empty());
+
+        // The blocks point into the data, such that line numbers can be recovered.
+        match AocError::malformed_in(data, blocks(data)[1], "oops") {
+            AocError::MalformedLine(err) => assert_eq!(err.line, 4),
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_read_data() {
+        let path = std::env::temp_dir().join(format!("aoc2020-read-data-{}", std::process::id()));
+        std::fs::write(&path, "1\n2\n\nfour\n5").unwrap();
+
+        match read_data::<u32, _>(&path).unwrap_err() {
+            AocError::MalformedLines(errs) => {
+                assert_eq!(errs.iter().map(|e| e.line).collect::<Vec<_>>(), vec![3, 4]);
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+        assert_eq!(read_data::<String, _>(&path).unwrap().len(), 5);
+        assert_eq!(read_blocks(&path).unwrap(), vec!["1\n2", "four\n5"]);
+        assert_eq!(read_string(&path).unwrap(), "1\n2\n\nfour\n5");
+        assert_eq!(read_input(&path).unwrap(), "1\n2\n\nfour\n5");
+        let lines = read_lines(open_input(&path).unwrap());
+        assert_eq!(lines.map(Result::unwrap).collect::<Vec<_>>()[3], "four");
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(read_string(&path), Err(AocError::Io(_))));
+        assert!(matches!(open_input(&path), Err(AocError::Io(_))));
+    }
+
+    #[test]
+    fn test_stream_data() {
+        let lines = ["1", " 2 ", "x", "4"].iter().map(Ok);
+        let mut values = stream_data::<u32, _, _>(lines);
+        assert_eq!(values.next().unwrap().unwrap(), 1);
+        assert_eq!(values.next().unwrap().unwrap(), 2);
+        match values.next().unwrap().unwrap_err() {
+            AocError::MalformedLine(err) => assert_eq!((err.line, err.text.as_str()), (3, "x")),
+            err => panic!("unexpected error {:?}", err),
+        }
+        assert_eq!(values.next().unwrap().unwrap(), 4);
+
+        let even = |line: &str| line.parse::<u32>().ok().filter(|n| n % 2 == 0);
+        let values = stream_each(["2", "3"].iter().map(Ok), "an even number", even)
+            .map(|value| value.map_err(|err| err.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(values[0], Ok(2));
+        assert!(values[1].as_ref().unwrap_err().contains("an even number"));
+
+        // Errors while reading are passed on.
+        let lines = vec![Ok("1"), Err(AocError::unsolvable("broken pipe"))];
+        assert!(stream_data::<u32, _, _>(lines).nth(1).unwrap().is_err());
+    }
+}
@@ -0,0 +1,519 @@
+//! This module contains the number theory needed by several puzzles.
+//!
+//! All functions are generic over the primitive integer types (see [`Int`]).
+//! Moduli always need to be positive and results are reduced to the range
+//! `0..m`, also for negative arguments. Products are computed without
+//! overflowing, so any modulus that fits into the integer type can be used.
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The primitive integer types the functions of this module can be used with.
+pub trait Int:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// The number `0`.
+    const ZERO: Self;
+    /// The number `1`.
+    const ONE: Self;
+
+    /// Multiply `self` with `rhs`, returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Compute `n` modulo `m` in the range `0..m` (corresponding to python's `%` operator).
+pub fn modulo<T: Int>(n: T, m: T) -> T {
+    let r = n % m;
+    if r < T::ZERO {
+        r + m
+    } else {
+        r
+    }
+}
+
+/// Compute `(a + b) mod m`.
+pub fn add_mod<T: Int>(a: T, b: T, m: T) -> T {
+    let (a, b) = (modulo(a, m), modulo(b, m));
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Compute `(a - b) mod m`.
+pub fn sub_mod<T: Int>(a: T, b: T, m: T) -> T {
+    let (a, b) = (modulo(a, m), modulo(b, m));
+    if a >= b {
+        a - b
+    } else {
+        a + (m - b)
+    }
+}
+
+/// Compute `(a * b) mod m`.
+///
+/// If the product overflows the integer type, it is computed by doubling
+/// and adding instead.
+pub fn mul_mod<T: Int>(a: T, b: T, m: T) -> T {
+    let (mut a, mut b) = (modulo(a, m), modulo(b, m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    let two = T::ONE + T::ONE;
+    let mut acc = T::ZERO;
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            acc = add_mod(acc, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b / two;
+    }
+    acc
+}
+
+/// Compute `base^exp mod m` by repeated squaring.
+///
+/// The exponent `exp` needs to be non-negative.
+pub fn pow_mod<T: Int>(base: T, mut exp: T, m: T) -> T {
+    let two = T::ONE + T::ONE;
+    let mut base = modulo(base, m);
+    let mut acc = T::ONE % m;
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            acc = mul_mod(acc, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp = exp / two;
+    }
+    acc
+}
+
+/// Compute the (non-negative) greatest common divisor of `a` and `b`.
+pub fn gcd<T: Int>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    if a < T::ZERO {
+        T::ZERO - a
+    } else {
+        a
+    }
+}
+
+/// Compute the (non-negative) least common multiple of `a` and `b`.
+pub fn lcm<T: Int>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    let l = a / gcd(a, b) * b;
+    if l < T::ZERO {
+        T::ZERO - l
+    } else {
+        l
+    }
+}
+
+/// Compute the greatest common divisor `g` of `a` and `b` together with
+/// Bézout coefficients `x` and `y`, such that `a * x + b * y = g`.
+///
+/// The result is returned as tuple `(g, x, y)`, where `g` is non-negative.
+#[allow(clippy::many_single_char_names)]
+pub fn extended_gcd<T: Int + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    let mut r = (a, b);
+    let mut s = (T::ONE, T::ZERO);
+    let mut t = (T::ZERO, T::ONE);
+
+    while r.1 != T::ZERO {
+        let q = r.0 / r.1;
+        r = (r.1, r.0 - q * r.1);
+        s = (s.1, s.0 - q * s.1);
+        t = (t.1, t.0 - q * t.1);
+    }
+    if r.0 < T::ZERO {
+        (-r.0, -s.0, -t.0)
+    } else {
+        (r.0, s.0, t.0)
+    }
+}
+
+/// Compute the modular inverse of `n` modulo `m` in the range `0..m`.
+///
+/// The inverse only exists if `n` and `m` are co-prime, otherwise `None`
+/// is returned.
+pub fn mod_inv<T: Int>(n: T, m: T) -> Option<T> {
+    // The extended euclidean algorithm, where only the coefficient of `n`
+    // is tracked and kept in the range `0..m` to avoid negative numbers.
+    let mut r = (m, modulo(n, m));
+    let mut t = (T::ZERO, T::ONE % m);
+
+    while r.1 != T::ZERO {
+        let q = r.0 / r.1;
+        r = (r.1, r.0 - q * r.1);
+        t = (t.1, sub_mod(t.0, mul_mod(q, t.1, m), m));
+    }
+    if r.0 == T::ONE {
+        Some(t.0)
+    } else {
+        None
+    }
+}
+
+/// Combine the congruences `x = a1 mod m1` and `x = a2 mod m2` into one.
+///
+/// The congruences are given as tuples `(a, m)` and the result `(a, m)` holds
+/// the solutions `x = a mod m`, where `m` is the least common multiple of
+/// `m1` and `m2`, which needs to fit into the integer type. The moduli do not
+/// need to be co-prime, but if the congruences contradict each other,
+/// `None` is returned.
+pub fn chinese_remainder<T: Int>(c1: (T, T), c2: (T, T)) -> Option<(T, T)> {
+    let (a1, m1) = (modulo(c1.0, c1.1), c1.1);
+    let g = gcd(m1, c2.1);
+    let m2 = c2.1 / g;
+    // The difference `a2 - a1` modulo `g * m2`, which needs to be a multiple
+    // of `g` for a solution to exist.
+    let diff = sub_mod(c2.0, a1, c2.1);
+    if diff % g != T::ZERO {
+        return None;
+    }
+
+    // x = a1 + m1 * k, where (m1 / g) * k = diff / g mod m2.
+    let k = mul_mod(diff / g, mod_inv(m1 / g, m2)?, m2);
+    Some((a1 + m1 * k, m1 * m2))
+}
+
+/// Combine all the `congruences` given as tuples `(a, m)` for `x = a mod m`.
+///
+/// See [`chinese_remainder`] for the format of the result. If there are no
+/// congruences, every number is a solution, i.e. `(0, 1)` is returned.
+pub fn solve_congruences<T: Int>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), chinese_remainder)
+}
+
+/// Find the smallest non-negative `x` such that `base^x = target mod m`.
+///
+/// This uses the baby-step giant-step algorithm, which takes time and memory
+/// proportional to the square root of `m`. The base and the modulus do not
+/// need to be co-prime. If no such `x` exists, `None` is returned.
+pub fn discrete_log<T: Int>(base: T, target: T, m: T) -> Option<T> {
+    discrete_log_from(base, target, m, T::ZERO)
+}
+
+/// Like [`discrete_log`], but find the smallest `x` that is at least `min`,
+/// e.g. a positive one for `min = 1` when `target` is one.
+pub fn discrete_log_from<T: Int>(base: T, target: T, mut m: T, min: T) -> Option<T> {
+    let base = modulo(base, m);
+    let mut target = modulo(target, m);
+
+    // Since base^x = base^min * base^(x - min), start from `factor = base^min`.
+    // Dividing out the common factors of `base` and `m` then leaves the
+    // problem `factor * base^x = target mod m` with a co-prime base.
+    let mut offset = min;
+    let mut factor = pow_mod(base, min, m);
+    loop {
+        if target == factor {
+            return Some(offset);
+        }
+        let g = gcd(base, m);
+        if g == T::ONE {
+            break;
+        }
+        if target % g != T::ZERO {
+            return None;
+        }
+        target = target / g;
+        m = m / g;
+        factor = mul_mod(factor, base / g, m);
+        offset = offset + T::ONE;
+    }
+    discrete_log_coprime(base, target, factor, m).map(|x| x + offset)
+}
+
+/// Find the smallest positive `x` such that `factor * base^x = target mod m`,
+/// where `base` and `m` are co-prime.
+fn discrete_log_coprime<T: Int>(base: T, target: T, factor: T, m: T) -> Option<T> {
+    // The smallest `n` with `n * n >= m`.
+    let mut n = T::ONE;
+    while n < (m - T::ONE) / n + T::ONE {
+        n = n + T::ONE;
+    }
+
+    // Baby steps: target * base^j for j < n, keeping the largest `j` per value,
+    // since x = i * n - j is smallest for the largest `j`.
+    let mut baby_steps = HashMap::new();
+    let mut value = target;
+    let mut j = T::ZERO;
+    while j < n {
+        baby_steps.insert(value, j);
+        value = mul_mod(value, base, m);
+        j = j + T::ONE;
+    }
+
+    // Giant steps: factor * base^(i * n) for 1 <= i <= n.
+    let giant_step = pow_mod(base, n, m);
+    let mut value = factor;
+    let mut i = T::ONE;
+    while i <= n {
+        value = mul_mod(value, giant_step, m);
+        if let Some(&j) = baby_steps.get(&value) {
+            return Some(i * n - j);
+        }
+        i = i + T::ONE;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modulo() {
+        assert_eq!(modulo(-7, 2), 1);
+        assert_eq!(modulo(6, 2), 0);
+        assert_eq!(modulo(5, 2), 1);
+        assert_eq!(modulo(-4, 2), 0);
+        assert_eq!(modulo(-1i8, 127), 126);
+        assert_eq!(modulo(i128::MIN, 3), 1);
+        assert_eq!(modulo(17u32, 5), 2);
+    }
+
+    #[test]
+    fn test_add_sub_mod() {
+        assert_eq!(add_mod(5, 4, 7), 2);
+        assert_eq!(add_mod(-5, 4, 7), 6);
+        assert_eq!(sub_mod(2, 5, 7), 4);
+        assert_eq!(sub_mod(2u8, 5, 7), 4);
+        assert_eq!(add_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX), u64::MAX - 2);
+        assert_eq!(add_mod(200u8, 100, 255), 45);
+        assert_eq!(sub_mod(i64::MIN, i64::MAX, i64::MAX), i64::MAX - 1);
+    }
+
+    #[test]
+    fn test_mul_mod() {
+        assert_eq!(mul_mod(6, 7, 10), 2);
+        assert_eq!(mul_mod(-6, 7, 10), 8);
+        assert_eq!(mul_mod(200u8, 200, 251), (200 * 200 % 251) as u8);
+
+        // Compare overflowing products against the widened computation.
+        let m = u64::MAX - 58; // the largest 64-bit prime
+        for &(a, b) in &[
+            (u64::MAX, u64::MAX),
+            (m - 1, m - 1),
+            (1 << 63, 3),
+            (0xdead_beef_cafe_babe, 0x1234_5678_9abc_def0),
+        ] {
+            let expected = (a as u128 % m as u128) * (b as u128 % m as u128) % m as u128;
+            assert_eq!(mul_mod(a, b, m) as u128, expected);
+        }
+        assert_eq!(mul_mod(i128::MAX - 1, i128::MAX - 1, i128::MAX), 1);
+        assert_eq!(mul_mod(u128::MAX, 2, u128::MAX - 1), 2);
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        assert_eq!(pow_mod(7u64, 8, 20201227), 5764801);
+        assert_eq!(pow_mod(7u64, 11, 20201227), 17807724);
+        assert_eq!(pow_mod(5, 0, 13), 1);
+        assert_eq!(pow_mod(5, 0, 1), 0);
+        assert_eq!(pow_mod(-2, 3, 5), 2);
+        // Fermat's little theorem for the largest 64-bit prime.
+        let p = u64::MAX - 58;
+        assert_eq!(pow_mod(123_456_789, p - 1, p), 1);
+        assert_eq!(pow_mod(3u128, 1000, u128::MAX), {
+            (0..1000).fold(1, |acc, _| mul_mod(acc, 3u128, u128::MAX))
+        });
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(17u32, 5), 1);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(7u64, 13), 91);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(0, 0), (0, 1, 0));
+        for a in -30i64..=30 {
+            for b in -30..=30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(g, gcd(a, b), "gcd({a}, {b})");
+                assert_eq!(a * x + b * y, g, "coefficients for ({a}, {b})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        assert_eq!(mod_inv(12, 8), None);
+        assert_eq!(mod_inv(4, 7), Some(2));
+        assert_eq!(mod_inv(9, 13), Some(3));
+        assert_eq!(mod_inv(-3, 7), Some(2));
+        assert_eq!(mod_inv(5, 1), Some(0));
+
+        // Unit tests generated with Python
+        assert_eq!(mod_inv(1973066479553i128, 41), Some(1));
+        assert_eq!(mod_inv(17i128, 80895725661673), Some(9517144195491));
+        assert_eq!(mod_inv(80895725661673i128, 17), Some(15));
+
+        for m in 1u32..=40 {
+            for n in 0..m {
+                let expected = (0..m).find(|&x| n * x % m == 1 % m);
+                assert_eq!(mod_inv(n, m), expected, "inverse of {n} mod {m}");
+            }
+        }
+        let p = u64::MAX - 58;
+        assert_eq!(mul_mod(mod_inv(2, p).unwrap(), 2, p), 1);
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(chinese_remainder((0, 3), (4, 5)), Some((9, 15)));
+        assert_eq!(chinese_remainder((9, 15), (0, 11)), Some((99, 15 * 11)));
+        assert_eq!(chinese_remainder((9, 15), (0, 1)), Some((9, 15)));
+        assert_eq!(chinese_remainder((-1, 4), (1, 6)), Some((7, 12)));
+
+        // Non-coprime moduli
+        assert_eq!(chinese_remainder((2, 4), (0, 6)), Some((6, 12)));
+        assert_eq!(chinese_remainder((2u8, 4), (4, 6)), Some((10, 12)));
+        assert_eq!(chinese_remainder((1, 4), (0, 6)), None);
+        assert_eq!(chinese_remainder((3, 10), (8, 15)), Some((23, 30)));
+
+        // Unit test generated with Python
+        assert_eq!(
+            chinese_remainder((1250064264i128, 4577880463), (381, 431)),
+            Some((56184629820, 1973066479553))
+        );
+        assert_eq!(
+            chinese_remainder((56184629820i128, 1973066479553), (22, 41)),
+            Some((53328979577751, 80895725661673))
+        );
+        assert_eq!(
+            chinese_remainder((53328979577751i128, 80895725661673), (1, 17)),
+            Some((538703333547789, 1375227336248441))
+        );
+
+        // Compare against brute force for all small systems.
+        for m1 in 1i32..=12 {
+            for m2 in 1..=12 {
+                let l = lcm(m1, m2);
+                for a1 in 0..m1 {
+                    for a2 in 0..m2 {
+                        let expected = (0..l)
+                            .find(|x| x % m1 == a1 && x % m2 == a2)
+                            .map(|x| (x, l));
+                        assert_eq!(
+                            chinese_remainder((a1, m1), (a2, m2)),
+                            expected,
+                            "x = {a1} mod {m1}, x = {a2} mod {m2}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_congruences() {
+        assert_eq!(solve_congruences(Vec::<(i64, i64)>::new()), Some((0, 1)));
+        assert_eq!(
+            solve_congruences(vec![(0, 7), (-1, 13), (-4, 59), (-6, 31), (-7, 19)]),
+            Some((1068781, 7 * 13 * 59 * 31 * 19))
+        );
+        assert_eq!(solve_congruences(vec![(0, 6), (2, 4), (1, 9)]), None);
+        assert_eq!(solve_congruences(vec![(0u64, 6), (1, 4)]), None);
+        assert_eq!(
+            solve_congruences(vec![(0, 6), (2, 4), (6, 9)]),
+            Some((6, 36))
+        );
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(discrete_log(7u64, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7u64, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(7u64, 20201227, 20201227), None);
+        assert_eq!(discrete_log(3, 1, 7), Some(0));
+        assert_eq!(discrete_log(2, 0, 1), Some(0));
+        assert_eq!(discrete_log(2, 3, 7), None);
+        assert_eq!(discrete_log(-2, 4, 7), Some(2));
+
+        // Compare against brute force for all small problems, including
+        // bases sharing factors with the modulus.
+        for m in 1u32..=40 {
+            for base in 0..m {
+                for target in 0..m {
+                    let expected = (0..2 * m).find(|&x| pow_mod(base, x, m) == target);
+                    assert_eq!(
+                        discrete_log(base, target, m),
+                        expected,
+                        "{base}^x = {target} mod {m}"
+                    );
+                }
+            }
+        }
+
+        for m in 1u32..=20 {
+            for base in 0..m {
+                for target in 0..m {
+                    for min in 0..3 {
+                        let expected = (min..2 * m + min).find(|&x| pow_mod(base, x, m) == target);
+                        assert_eq!(
+                            discrete_log_from(base, target, m, min),
+                            expected,
+                            "{base}^x = {target} mod {m}, x >= {min}"
+                        );
+                    }
+                }
+            }
+        }
+        assert_eq!(discrete_log_from(7u64, 1, 20201227, 1), Some(20201226));
+
+        // 5 is a primitive root modulo this prime, so the logarithm is unique.
+        let p = 1_000_000_007u64;
+        assert_eq!(
+            discrete_log(5, pow_mod(5, 123_456_789, p), p),
+            Some(123_456_789)
+        );
+    }
+}
@@ -0,0 +1,525 @@
+//! This module contains a small toolkit for parsing the puzzle input.
+//!
+//! A parser is a function that takes a [`Cursor`] over the input, consumes
+//! what it recognizes and returns the parsed value. The cursor provides the
+//! building blocks the puzzles keep asking for (literals, signed integers,
+//! words, keyword alternatives and `key:value` pairs) as well as combinators
+//! that build larger parsers from smaller ones (delimited lists and optional
+//! parts).
+//!
+//! When parsing fails, the returned [`ParseError`] points at the line and
+//! column where the unexpected input starts. The functions [`each_line`],
+//! [`each_block`] and [`parse_in`] run a parser on (parts of) the puzzle
+//! input and turn these errors into an [`AocError`] for the offending line.
+use crate::{AocError, LineError, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// The result of a parser.
+pub type PResult<T> = std::result::Result<T, ParseError>;
+
+/// The input does not have the format a parser expects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The (1-based) line of the parsed input the error occurred in.
+    pub line: usize,
+    /// The (1-based) column of that line where the unexpected input starts.
+    pub column: usize,
+    /// A description of what was expected.
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "column {}: expected {}", self.column, self.expected)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A position in the input of a parser.
+///
+/// Parsing advances the cursor past the consumed input. When a parser
+/// fails, the position of the cursor is unspecified, unless the parser
+/// is wrapped with [`optional`](Cursor::optional).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Create a cursor at the start of `input`.
+    pub fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    /// The input that has not been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    /// Check whether the whole input has been consumed.
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    /// Create an error for the current position, stating what was `expected`.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.pos, expected)
+    }
+
+    fn error_at(&self, pos: usize, expected: impl Into<String>) -> ParseError {
+        let start = self.input[..pos].rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: self.input[..pos].matches('\n').count() + 1,
+            column: self.input[start..pos].chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// The next character of the input, without consuming it.
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Consume the next character of the input.
+    pub fn char(&mut self) -> PResult<char> {
+        let c = self.peek().ok_or_else(|| self.error("a character"))?;
+        self.pos += c.len_utf8();
+        Ok(c)
+    }
+
+    /// Consume `tag` if the input continues with it and report whether it did.
+    pub fn eat(&mut self, tag: &str) -> bool {
+        let found = self.rest().starts_with(tag);
+        if found {
+            self.pos += tag.len();
+        }
+        found
+    }
+
+    /// Consume `tag`, failing if the input does not continue with it.
+    pub fn tag(&mut self, tag: &str) -> PResult<()> {
+        if self.eat(tag) {
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}`", tag)))
+        }
+    }
+
+    /// Consume the (possibly empty) run of characters satisfying `predicate`.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Consume the input up to (but excluding) the next occurrence of `pattern`.
+    ///
+    /// If `pattern` does not occur, the rest of the input is consumed.
+    pub fn take_until(&mut self, pattern: &str) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(pattern).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Skip any whitespace, including line breaks.
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Consume a non-empty run of alphanumeric characters.
+    pub fn word(&mut self) -> PResult<&'a str> {
+        match self.take_while(char::is_alphanumeric) {
+            "" => Err(self.error("a word")),
+            word => Ok(word),
+        }
+    }
+
+    /// Consume an integer with an optional `+` or `-` sign.
+    ///
+    /// The integer needs to fit into `T`, otherwise an error pointing at
+    /// the start of the integer is returned.
+    pub fn int<T: FromStr>(&mut self) -> PResult<T> {
+        let start = self.pos;
+        if !self.eat("+") {
+            self.eat("-");
+        }
+        if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+            return Err(self.error_at(start, "an integer"));
+        }
+        let text = &self.input[start..self.pos];
+        text.trim_start_matches('+').parse().map_err(|_| {
+            self.error_at(
+                start,
+                format!("an integer of type `{}`", std::any::type_name::<T>()),
+            )
+        })
+    }
+
+    /// Consume one of the `keywords`, returning the value associated with it.
+    ///
+    /// If several keywords match, the longest one wins.
+    pub fn keyword<T: Clone>(&mut self, keywords: &[(&str, T)]) -> PResult<T> {
+        let rest = self.rest();
+        match keywords
+            .iter()
+            .filter(|(keyword, _)| rest.starts_with(keyword))
+            .max_by_key(|(keyword, _)| keyword.len())
+        {
+            Some((keyword, value)) => {
+                self.pos += keyword.len();
+                Ok(value.clone())
+            }
+            None => Err(self.error(one_of(keywords.iter().map(|(k, _)| *k)))),
+        }
+    }
+
+    /// Consume a `key:value` pair, where `separator` separates the key from the value.
+    ///
+    /// Neither the key nor the value may be empty or contain whitespace.
+    pub fn key_value(&mut self, separator: char) -> PResult<(&'a str, &'a str)> {
+        let key = self.take_while(|c| c != separator && !c.is_whitespace());
+        if key.is_empty() {
+            return Err(self.error("a key"));
+        }
+        if self.peek() != Some(separator) {
+            return Err(self.error(format!("`{}`", separator)));
+        }
+        self.pos += separator.len_utf8();
+        match self.take_while(|c| !c.is_whitespace()) {
+            "" => Err(self.error("a value")),
+            value => Ok((key, value)),
+        }
+    }
+
+    /// Parse the input with `parser`, leaving the cursor untouched if it fails.
+    pub fn optional<T>(&mut self, parser: impl FnOnce(&mut Self) -> PResult<T>) -> Option<T> {
+        let start = self.pos;
+        let result = parser(self).ok();
+        if result.is_none() {
+            self.pos = start;
+        }
+        result
+    }
+
+    /// Parse a non-empty list of items delimited by `separator`.
+    ///
+    /// The list ends before the first `separator` that is not followed by
+    /// an item. An item that fails after consuming some input is reported
+    /// as error instead.
+    pub fn list<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> PResult<T>,
+    ) -> PResult<Vec<T>> {
+        let mut items = vec![item(self)?];
+        loop {
+            let end = self.pos;
+            if !self.eat(separator) {
+                break;
+            }
+            let start = self.pos;
+            match item(self) {
+                Ok(next) => items.push(next),
+                Err(_) if self.pos == start => {
+                    self.pos = end;
+                    break;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(items)
+    }
+
+    /// Check that the whole input has been consumed.
+    pub fn end(&self) -> PResult<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("the end of the input"))
+        }
+    }
+}
+
+/// Describe a choice between `alternatives`, e.g. "one of `a`, `b` or `c`".
+fn one_of<'a>(alternatives: impl Iterator<Item = &'a str>) -> String {
+    let alternatives: Vec<_> = alternatives.map(|a| format!("`{}`", a)).collect();
+    match alternatives.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, init)) => format!("one of {} or {}", init.join(", "), last),
+        None => "nothing".to_string(),
+    }
+}
+
+/// Parse the whole `input` with `parser`.
+pub fn parse<'a, T>(
+    input: &'a str,
+    parser: impl FnOnce(&mut Cursor<'a>) -> PResult<T>,
+) -> PResult<T> {
+    let mut cursor = Cursor::new(input);
+    let value = parser(&mut cursor)?;
+    cursor.end()?;
+    Ok(value)
+}
+
+/// Parse the whole `part` of the puzzle input `data` with `parser`.
+///
+/// `part` needs to be a sub-slice of `data`, so that failures can be
+/// reported for the right line of `data` (see [`AocError::malformed_in`]).
+pub fn parse_in<'a, T>(
+    data: &'a str,
+    part: &'a str,
+    parser: impl FnOnce(&mut Cursor<'a>) -> PResult<T>,
+) -> Result<T> {
+    parse(part, parser).map_err(|err| AocError::MalformedLine(line_error(data, part, err)))
+}
+
+/// Parse each of the `lines` with `parser`, reporting all malformed lines.
+pub fn each_line<'a, T, F>(lines: &'a [String], parser: F) -> Result<Vec<T>>
+where
+    F: Fn(&mut Cursor<'a>) -> PResult<T>,
+{
+    crate::collect_line_results(lines.iter().enumerate().map(|(i, l)| {
+        parse(l, &parser).map_err(|err| LineError {
+            line: i + 1,
+            text: l.to_string(),
+            reason: err.to_string(),
+        })
+    }))
+}
+
+/// Parse each of the `lines` with `parser` as they are read, for the solutions
+/// that process their input incrementally.
+///
+/// Unlike [`each_line`], which reports all malformed lines at once, each
+/// value is yielded as soon as its line is parsed, and a malformed line
+/// yields its error instead. Since the lines are dropped once parsed, the
+/// values cannot borrow from them.
+pub fn stream_each_line<T, S, I, F>(lines: I, parser: F) -> impl Iterator<Item = Result<T>>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = Result<S>>,
+    F: for<'a> Fn(&mut Cursor<'a>) -> PResult<T>,
+{
+    lines.into_iter().enumerate().map(move |(i, line)| {
+        let line = line?;
+        let line = line.as_ref();
+        parse(line, &parser).map_err(|err| AocError::malformed(i + 1, line, err.to_string()))
+    })
+}
+
+/// Parse each block of `data` with `parser`, reporting all malformed blocks.
+///
+/// The blocks are separated by blank lines (see [`blocks`](crate::blocks)).
+pub fn each_block<'a, T, F>(data: &'a str, parser: F) -> Result<Vec<T>>
+where
+    F: Fn(&mut Cursor<'a>) -> PResult<T>,
+{
+    crate::collect_line_results(
+        crate::blocks(data)
+            .into_iter()
+            .map(|block| parse(block, &parser).map_err(|err| line_error(data, block, err))),
+    )
+}
+
+/// Turn the `err` of parsing `part` into an error for the right line of `data`.
+fn line_error(data: &str, part: &str, err: ParseError) -> LineError {
+    let line = part.split('\n').nth(err.line - 1).unwrap_or(part);
+    LineError::within(data, line, err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives() {
+        let mut cursor = Cursor::new("mem[8] = -11");
+        assert_eq!(cursor.tag("mem["), Ok(()));
+        assert_eq!(cursor.int::<usize>(), Ok(8));
+        assert!(cursor.eat("] = "));
+        assert!(!cursor.eat("+"));
+        assert_eq!(cursor.int::<i64>(), Ok(-11));
+        assert!(cursor.is_empty());
+        assert_eq!(cursor.end(), Ok(()));
+
+        let mut cursor = Cursor::new("light red bags");
+        assert_eq!(cursor.word(), Ok("light"));
+        assert_eq!(cursor.take_until(" bags"), " red");
+        assert_eq!(cursor.rest(), " bags");
+        cursor.skip_whitespace();
+        assert_eq!(cursor.take_while(|c| c != 's'), "bag");
+        assert_eq!(cursor.char(), Ok('s'));
+        assert!(cursor.char().is_err());
+
+        assert_eq!(parse("+42", |c| c.int::<i32>()), Ok(42));
+        assert_eq!(parse("-0", |c| c.int::<i32>()), Ok(0));
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = parse("nop +x", |c| {
+            c.word()?;
+            c.tag(" ")?;
+            c.int::<isize>()
+        })
+        .unwrap_err();
+        assert_eq!(err.line, 1);
+        assert_eq!(err.column, 5);
+        assert_eq!(err.to_string(), "column 5: expected an integer");
+
+        let err = parse("a: -3", |c| {
+            c.tag("a: ")?;
+            c.int::<usize>()
+        })
+        .unwrap_err();
+        assert_eq!(err.column, 4);
+        assert_eq!(err.expected, "an integer of type `usize`");
+
+        let err = parse("ab\ncd", |c| c.tag("ab\nce")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("ab\ncdx", |c| {
+            c.tag("ab\ncd")?;
+            Ok(())
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "the end of the input");
+    }
+
+    #[test]
+    fn test_keyword() {
+        let keywords = [("e", 'E'), ("se", 'S'), ("sw", 'W'), ("s", 's')];
+        assert_eq!(parse("se", |c| c.keyword(&keywords)), Ok('S'));
+        assert_eq!(parse("s", |c| c.keyword(&keywords)), Ok('s'));
+        assert_eq!(
+            parse("n", |c| c.keyword(&keywords)).unwrap_err().expected,
+            "one of `e`, `se`, `sw` or `s`"
+        );
+        assert_eq!(
+            parse("x", |c| c.keyword(&[("y", ())]))
+                .unwrap_err()
+                .expected,
+            "`y`"
+        );
+    }
+
+    #[test]
+    fn test_key_value() {
+        let pairs = parse("ecl:gry pid:860033327\nhcl:#fffffd", |c| {
+            c.list("", |c| {
+                c.skip_whitespace();
+                c.key_value(':')
+            })
+        });
+        assert_eq!(
+            pairs,
+            Ok(vec![
+                ("ecl", "gry"),
+                ("pid", "860033327"),
+                ("hcl", "#fffffd")
+            ])
+        );
+
+        let err = parse("ecl:gry pid", |c| c.list(" ", |c| c.key_value(':'))).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (12, "`:`"));
+        assert_eq!(
+            parse("ecl:", |c| c.key_value(':')).unwrap_err().expected,
+            "a value"
+        );
+        assert_eq!(
+            parse(":gry", |c| c.key_value(':')).unwrap_err().expected,
+            "a key"
+        );
+        assert_eq!(
+            parse("ecl", |c| c.key_value(':')).unwrap_err().expected,
+            "`:`"
+        );
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(
+            parse("1,-2,3", |c| c.list(",", |c| c.int())),
+            Ok(vec![1, -2, 3])
+        );
+        assert_eq!(
+            parse("1 2 | 3", |c| {
+                let left = c.list(" ", |c| c.int::<u8>())?;
+                c.tag(" | ")?;
+                Ok((left, c.list(" ", |c| c.int::<u8>())?))
+            }),
+            Ok((vec![1, 2], vec![3]))
+        );
+        assert_eq!(
+            parse("1,x", |c| c.list(",", |c| c.int::<u8>()))
+                .unwrap_err()
+                .column,
+            2
+        );
+
+        let mut cursor = Cursor::new("12 apples");
+        assert_eq!(cursor.optional(|c| c.tag("no")), None);
+        assert_eq!(cursor.optional(|c| c.int::<u8>()), Some(12));
+        assert_eq!(cursor.rest(), " apples");
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let lines = vec![
+            "nop +0".to_string(),
+            "acc +".to_string(),
+            "jmp x".to_string(),
+        ];
+        let err = each_line(&lines, |c| {
+            c.keyword(&[("nop", ()), ("acc", ()), ("jmp", ())])?;
+            c.tag(" ")?;
+            c.int::<isize>()?;
+            c.tag("")
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2 malformed lines\n  line 2: malformed input `acc +`: column 5: expected an integer\n  line 3: malformed input `jmp x`: column 5: expected an integer"
+        );
+
+        let data = "a:1 b:2\n\nc:3\nd\n";
+        let err = each_block(data, |c| {
+            c.list("", |c| {
+                c.skip_whitespace();
+                c.key_value(':')
+            })
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "1 malformed lines\n  line 4: malformed input `d`: column 2: expected `:`"
+        );
+
+        let err = parse_in(data, &data[9..], |c| c.tag("c:3")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3: malformed input `c:3`: column 4: expected the end of the input"
+        );
+    }
+
+    #[test]
+    fn test_stream_each_line() {
+        let lines = ["+1", "-x", "3"].iter().map(Ok);
+        let values = stream_each_line(lines, |c| c.int::<i8>())
+            .map(|value| value.map_err(|err| err.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                Ok(1),
+                Err("line 2: malformed input `-x`: column 1: expected an integer".to_string()),
+                Ok(3)
+            ]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! This module contains the profiles, i.e. the sets of puzzle inputs
+//! along with their known answers.
+//!
+//! Every participant gets different puzzle inputs, so the inputs of each
+//! of them are kept in their own directory below `data/`:
+//!
+//! ```text
+//! data/
+//! ├── answers.toml     <- the default profile
+//! ├── day1.txt
+//! ├── ...
+//! └── alice/           <- the profile `alice`
+//!     ├── answers.toml
+//!     ├── day1.txt
+//!     └── ...
+//! ```
+//!
+//! A profile does not need to provide the inputs of all days, and a missing
+//! `answers.toml` simply leaves all of its answers unknown.
+use crate::{Answers, AocError, Result, Year};
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The directory containing the default profile and the other profiles.
+pub const DATA_DIR: &str = "data";
+
+/// The name of the profile stored directly in the data directory.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// A set of puzzle inputs along with their known answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    name: String,
+    dir: PathBuf,
+}
+
+impl Profile {
+    /// The profile `name` stored in the data directory `root`.
+    ///
+    /// The profile [`DEFAULT_PROFILE`] refers to `root` itself.
+    pub fn named<P: AsRef<Path>>(root: P, name: &str) -> Result<Self> {
+        let root = root.as_ref();
+        let dir = if name == DEFAULT_PROFILE {
+            root.to_path_buf()
+        } else {
+            root.join(name)
+        };
+        if name.is_empty() || name.contains(&['/', '\\'][..]) || !dir.is_dir() {
+            return Err(AocError::Io(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no profile `{}` in `{}`", name, root.display()),
+            )));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            dir,
+        })
+    }
+
+    /// All profiles stored in the data directory `root`, sorted by name
+    /// with the default profile first.
+    ///
+    /// Every subdirectory of `root` is a profile.
+    pub fn discover<P: AsRef<Path>>(root: P) -> Result<Vec<Self>> {
+        let root = root.as_ref();
+        let mut profiles = vec![];
+        for entry in std::fs::read_dir(root)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                if let Some(name) = entry.file_name().to_str() {
+                    profiles.push(Self {
+                        name: name.to_string(),
+                        dir: entry.path(),
+                    });
+                }
+            }
+        }
+        profiles.sort_by(|a, b| a.name.cmp(&b.name));
+        profiles.insert(
+            0,
+            Self {
+                name: DEFAULT_PROFILE.to_string(),
+                dir: root.to_path_buf(),
+            },
+        );
+        Ok(profiles)
+    }
+
+    /// All profiles in the data directory of `year` that provide the puzzle
+    /// input of `day`.
+    pub fn with_input(year: &Year, day: u32) -> Result<Vec<Self>> {
+        Ok(Self::discover(year.data_dir())?
+            .into_iter()
+            .filter(|p| p.input_path(day).is_file())
+            .collect())
+    }
+
+    /// The name of the profile.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The directory containing the puzzle inputs of the profile.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The location of the puzzle input of `day`.
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("day{}.txt", day))
+    }
+
+    /// The location of the manifest of the known answers.
+    pub fn answers_path(&self) -> PathBuf {
+        self.dir.join("answers.toml")
+    }
+
+    /// The known answers to the puzzle inputs of the profile, which are
+    /// all unknown if the profile has no manifest.
+    pub fn answers(&self) -> Result<Answers> {
+        let path = self.answers_path();
+        if path.is_file() {
+            Answers::read(path)
+        } else {
+            Ok(Answers::default())
+        }
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Part, Status};
+
+    #[test]
+    fn test_discover() {
+        let root = std::env::temp_dir().join(format!("aoc2020-profiles-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("bob")).unwrap();
+        std::fs::create_dir_all(root.join("alice")).unwrap();
+        std::fs::write(root.join("day1.txt"), "1").unwrap();
+        std::fs::write(root.join("bob").join("answers.toml"), "[day1]\npart1 = 5").unwrap();
+
+        let profiles = Profile::discover(&root).unwrap();
+        let names = profiles.iter().map(Profile::name).collect::<Vec<_>>();
+        assert_eq!(names, vec!["default", "alice", "bob"]);
+        assert_eq!(profiles[0].input_path(1), root.join("day1.txt"));
+        assert_eq!(profiles[2].input_path(3), root.join("bob").join("day3.txt"));
+        assert_eq!(profiles[1].answers().unwrap(), Answers::default());
+        assert_eq!(
+            profiles[2].answers().unwrap().check(1, Part::One, 5),
+            Status::Pass
+        );
+
+        assert_eq!(Profile::named(&root, "bob").unwrap(), profiles[2]);
+        assert_eq!(Profile::named(&root, "default").unwrap(), profiles[0]);
+        assert!(Profile::named(&root, "carol").is_err());
+        assert!(Profile::named(&root, "../bob").is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_with_input() {
+        let root = std::env::temp_dir().join(format!("aoc2020-with-input-{}", std::process::id()));
+        let data = root.join(DATA_DIR);
+        std::fs::create_dir_all(data.join("bob")).unwrap();
+        std::fs::create_dir_all(data.join("alice")).unwrap();
+        std::fs::write(data.join("day1.txt"), "1").unwrap();
+        std::fs::write(data.join("bob").join("day1.txt"), "2").unwrap();
+
+        // The profiles are found in the directory of the year, wherever the
+        // current directory is.
+        let dir: &'static str = Box::leak(root.display().to_string().into_boxed_str());
+        let year = Year::new(2020, &[], dir);
+        let profiles = Profile::with_input(&year, 1).unwrap();
+        let names = profiles.iter().map(Profile::name).collect::<Vec<_>>();
+        assert_eq!(names, vec!["default", "bob"]);
+        assert!(Profile::with_input(&year, 2).unwrap().is_empty());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,679 @@
+//! This module contains the rendering of spatial puzzle states to images.
+//!
+//! A solution describes what it wants to show as a [`Scene`] of simple
+//! shapes, whose colors are given as indices into a [`Palette`]. This way
+//! the same scene can be drawn with different palettes. Scenes are either
+//! exported as vector graphics ([SVG](Scene::to_svg)) or rasterized into an
+//! [`Image`], which can be written as PPM or PNG.
+use crate::grid::Grid;
+use crate::parse::{self, Cursor, PResult, ParseError};
+use std::fmt::{self, Write as _};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+/// A color given by its red, green and blue components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = ParseError;
+
+    /// Parse a color in the hexadecimal notation `#rrggbb`.
+    fn from_str(s: &str) -> PResult<Self> {
+        parse::parse(s, parse_rgb)
+    }
+}
+
+/// Parse a color in the hexadecimal notation `#rrggbb`.
+fn parse_rgb(cursor: &mut Cursor) -> PResult<Rgb> {
+    cursor.tag("#")?;
+    let start = *cursor;
+    let digits = cursor.take_while(|c| c.is_ascii_hexdigit());
+    match u32::from_str_radix(digits, 16) {
+        Ok(rgb) if digits.len() == 6 => Ok(Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)),
+        _ => Err(start.error("six hexadecimal digits")),
+    }
+}
+
+/// The colors a [`Scene`] is drawn with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    /// The color of the parts of the scene not covered by any shape.
+    pub background: Rgb,
+    /// The colors of the shapes, which refer to them by their index.
+    pub colors: Vec<Rgb>,
+}
+
+impl Palette {
+    /// The names of the predefined palettes, which can be parsed with [`str::parse`].
+    pub const NAMES: &'static [&'static str] = &["default", "mono", "dark"];
+
+    /// Create a palette from its `background` and the `colors` of the shapes.
+    pub fn new(background: Rgb, colors: Vec<Rgb>) -> Self {
+        Self { background, colors }
+    }
+
+    /// The color of the shapes with the given `index`.
+    ///
+    /// Indices beyond the number of colors wrap around, and a palette without
+    /// colors draws everything in its background color.
+    pub fn color(&self, index: usize) -> Rgb {
+        match self.colors.len() {
+            0 => self.background,
+            len => self.colors[index % len],
+        }
+    }
+
+    /// Black shapes on a white background.
+    pub fn mono() -> Self {
+        Self::new(Rgb(255, 255, 255), vec![Rgb(0, 0, 0)])
+    }
+
+    /// Bright shapes on a dark background.
+    pub fn dark() -> Self {
+        Self::new(
+            Rgb(0x12, 0x12, 0x1a),
+            vec![
+                Rgb(0xf0, 0xf0, 0xf0),
+                Rgb(0xf0, 0xa0, 0x30),
+                Rgb(0x40, 0xa0, 0xf0),
+                Rgb(0x60, 0xd0, 0x70),
+                Rgb(0xf0, 0x50, 0x50),
+            ],
+        )
+    }
+}
+
+impl Default for Palette {
+    /// Dark shapes, followed by a few distinct colors, on a light background.
+    fn default() -> Self {
+        Self::new(
+            Rgb(0xf8, 0xf8, 0xf4),
+            vec![
+                Rgb(0x30, 0x30, 0x38),
+                Rgb(0xe0, 0x70, 0x20),
+                Rgb(0x20, 0x70, 0xc0),
+                Rgb(0x30, 0xa0, 0x50),
+                Rgb(0xc0, 0x30, 0x30),
+            ],
+        )
+    }
+}
+
+impl FromStr for Palette {
+    type Err = ParseError;
+
+    /// Parse either the name of a predefined palette (see [`Palette::NAMES`])
+    /// or a comma-separated list of colors `#rrggbb`, starting with the background.
+    fn from_str(s: &str) -> PResult<Self> {
+        match s {
+            "default" => Ok(Self::default()),
+            "mono" => Ok(Self::mono()),
+            "dark" => Ok(Self::dark()),
+            _ => parse::parse(s, |c| {
+                let mut colors = c.list(",", parse_rgb)?;
+                let background = colors.remove(0);
+                Ok(Self::new(background, colors))
+            }),
+        }
+    }
+}
+
+/// A shape of a [`Scene`], whose color is an index into the [`Palette`].
+///
+/// Coordinates grow to the right and downwards.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// An axis-aligned rectangle with its top left corner at `(x, y)`.
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: usize,
+    },
+    /// A filled polygon with the given corners.
+    Polygon {
+        points: Vec<(f64, f64)>,
+        color: usize,
+    },
+    /// A line of the given `width` through the given points.
+    Polyline {
+        points: Vec<(f64, f64)>,
+        width: f64,
+        color: usize,
+    },
+}
+
+/// A drawing of a spatial puzzle state, made up of [`Shape`]s.
+///
+/// The scene covers the area from `(0, 0)` to `(width, height)` in units
+/// that are scaled to pixels when it is exported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scene {
+    width: f64,
+    height: f64,
+    shapes: Vec<Shape>,
+}
+
+impl Scene {
+    /// Create an empty scene of the given size.
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            shapes: vec![],
+        }
+    }
+
+    /// Draw the cells of `grid` as unit squares, in the color `color` returns
+    /// for their content. Cells without a color are left to the background.
+    pub fn grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Option<usize>) -> Self {
+        let mut scene = Self::new(grid.cols() as f64, grid.rows() as f64);
+        for ((row, col), cell) in grid.iter() {
+            if let Some(color) = color(cell) {
+                scene.push(Shape::Rect {
+                    x: col as f64,
+                    y: row as f64,
+                    width: 1.0,
+                    height: 1.0,
+                    color,
+                });
+            }
+        }
+        scene
+    }
+
+    /// Draw hexagonal `cells` given in the doubled coordinates of a
+    /// [`HexGrid`](crate::automaton::HexGrid) together with their color.
+    ///
+    /// The hexagons have a unit circumradius and point upwards, and the scene
+    /// is just large enough to hold all of them.
+    pub fn hex_grid(cells: impl IntoIterator<Item = ((isize, isize), usize)>) -> Self {
+        let half_width = 3f64.sqrt() / 2.0;
+        let hexagons = cells
+            .into_iter()
+            .map(|((x, y), color)| ((x as f64 * half_width, y as f64 * 1.5), color))
+            .collect::<Vec<_>>();
+        let (min_x, max_x, min_y, max_y) = bounds(hexagons.iter().map(|&(center, _)| center));
+
+        let mut scene = Self::new(max_x - min_x + 2.0 * half_width, max_y - min_y + 2.0);
+        for ((x, y), color) in hexagons {
+            let (x, y) = (x - min_x + half_width, y - min_y + 1.0);
+            let points = (0..6)
+                .map(|i| {
+                    let angle = (60.0 * i as f64 + 30.0).to_radians();
+                    (x + angle.cos(), y + angle.sin())
+                })
+                .collect();
+            scene.push(Shape::Polygon { points, color });
+        }
+        scene
+    }
+
+    /// Draw a path through `points`, e.g. the trajectory of a moving object.
+    ///
+    /// The scene is just large enough to hold the path with a small margin,
+    /// and the width of the line is chosen relative to its size.
+    pub fn path(points: &[(isize, isize)], color: usize) -> Self {
+        let (min_x, max_x, min_y, max_y) =
+            bounds(points.iter().map(|&(x, y)| (x as f64, y as f64)));
+        let size = (max_x - min_x).max(max_y - min_y).max(1.0);
+        let margin = size / 50.0;
+
+        let mut scene = Self::new(max_x - min_x + 2.0 * margin, max_y - min_y + 2.0 * margin);
+        scene.push(Shape::Polyline {
+            points: points
+                .iter()
+                .map(|&(x, y)| (x as f64 - min_x + margin, y as f64 - min_y + margin))
+                .collect(),
+            width: size / 250.0,
+            color,
+        });
+        scene
+    }
+
+    /// The width of the scene.
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// The height of the scene.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// The shapes of the scene, in the order they are drawn.
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    /// Add `shape` on top of the shapes drawn so far.
+    pub fn push(&mut self, shape: Shape) {
+        self.shapes.push(shape);
+    }
+
+    /// The factor to scale the scene with, such that its longer side is `size` pixels.
+    pub fn scale_to(&self, size: u32) -> f64 {
+        size as f64 / self.width.max(self.height).max(f64::EPSILON)
+    }
+
+    /// Export the scene as SVG document, drawn with the colors of `palette`
+    /// and `scale` pixels per unit.
+    pub fn to_svg(&self, palette: &Palette, scale: f64) -> String {
+        let mut svg = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+            (self.width * scale).ceil(),
+            (self.height * scale).ceil(),
+            self.width,
+            self.height
+        );
+        let _ = writeln!(
+            svg,
+            r#"  <rect width="100%" height="100%" fill="{}"/>"#,
+            palette.background
+        );
+        let points = |points: &[(f64, f64)]| {
+            let points = points
+                .iter()
+                .map(|(x, y)| format!("{},{}", x, y))
+                .collect::<Vec<_>>();
+            points.join(" ")
+        };
+        for shape in &self.shapes {
+            let _ = match shape {
+                Shape::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => writeln!(
+                    svg,
+                    r#"  <rect x="{}" y="{}" width="{}" height="{}" fill="{}" shape-rendering="crispEdges"/>"#,
+                    x,
+                    y,
+                    width,
+                    height,
+                    palette.color(*color)
+                ),
+                Shape::Polygon { points: p, color } => writeln!(
+                    svg,
+                    r#"  <polygon points="{}" fill="{}"/>"#,
+                    points(p),
+                    palette.color(*color)
+                ),
+                Shape::Polyline {
+                    points: p,
+                    width,
+                    color,
+                } => writeln!(
+                    svg,
+                    r#"  <polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round" stroke-linecap="round"/>"#,
+                    points(p),
+                    palette.color(*color),
+                    width
+                ),
+            };
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Rasterize the scene with the colors of `palette` and `scale` pixels per unit.
+    ///
+    /// A pixel takes the color of the last shape covering its center. Lines
+    /// are at least one pixel wide.
+    pub fn rasterize(&self, palette: &Palette, scale: f64) -> Image {
+        let mut image = Image::new(
+            ((self.width * scale).ceil() as usize).max(1),
+            ((self.height * scale).ceil() as usize).max(1),
+            palette.background,
+        );
+        for shape in &self.shapes {
+            match shape {
+                Shape::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => {
+                    let rgb = palette.color(*color);
+                    for py in pixel_range(*y, y + height, scale, image.height) {
+                        for px in pixel_range(*x, x + width, scale, image.width) {
+                            image.set(px, py, rgb);
+                        }
+                    }
+                }
+                Shape::Polygon { points, color } => {
+                    let rgb = palette.color(*color);
+                    let (min_x, max_x, min_y, max_y) = bounds(points.iter().copied());
+                    for py in pixel_range(min_y, max_y, scale, image.height) {
+                        for px in pixel_range(min_x, max_x, scale, image.width) {
+                            if contains(points, pixel_center(px, py, scale)) {
+                                image.set(px, py, rgb);
+                            }
+                        }
+                    }
+                }
+                Shape::Polyline {
+                    points,
+                    width,
+                    color,
+                } => {
+                    let rgb = palette.color(*color);
+                    let radius = (width / 2.0).max(0.5 / scale);
+                    for segment in points.windows(2) {
+                        let (min_x, max_x, min_y, max_y) = bounds(segment.iter().copied());
+                        for py in pixel_range(min_y - radius, max_y + radius, scale, image.height) {
+                            for px in
+                                pixel_range(min_x - radius, max_x + radius, scale, image.width)
+                            {
+                                let center = pixel_center(px, py, scale);
+                                if distance(center, segment[0], segment[1]) <= radius {
+                                    image.set(px, py, rgb);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        image
+    }
+}
+
+/// The bounding box `(min_x, max_x, min_y, max_y)` of `points`, or all zeros
+/// if there are none.
+fn bounds(points: impl Iterator<Item = (f64, f64)>) -> (f64, f64, f64, f64) {
+    points
+        .fold(None, |bounds, (x, y)| match bounds {
+            None => Some((x, x, y, y)),
+            Some((min_x, max_x, min_y, max_y)) => {
+                Some((min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y)))
+            }
+        })
+        .unwrap_or((0.0, 0.0, 0.0, 0.0))
+}
+
+/// The pixels whose centers lie between `from` and `to` (in units), clamped to `0..len`.
+fn pixel_range(from: f64, to: f64, scale: f64, len: usize) -> std::ops::Range<usize> {
+    let clamp = |v: f64| (v.round().max(0.0) as usize).min(len);
+    clamp(from * scale)..clamp(to * scale)
+}
+
+/// The center of the pixel `(px, py)` in units.
+fn pixel_center(px: usize, py: usize, scale: f64) -> (f64, f64) {
+    ((px as f64 + 0.5) / scale, (py as f64 + 0.5) / scale)
+}
+
+/// Check whether `point` lies inside the polygon with the corners `points`.
+fn contains(points: &[(f64, f64)], (x, y): (f64, f64)) -> bool {
+    let mut inside = false;
+    for (i, &(x1, y1)) in points.iter().enumerate() {
+        let (x2, y2) = points[(i + 1) % points.len()];
+        if (y1 > y) != (y2 > y) && x < x1 + (y - y1) * (x2 - x1) / (y2 - y1) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// The distance of `point` from the line segment between `a` and `b`.
+fn distance(point: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len = dx * dx + dy * dy;
+    let t = if len == 0.0 {
+        0.0
+    } else {
+        (((point.0 - a.0) * dx + (point.1 - a.1) * dy) / len).clamp(0.0, 1.0)
+    };
+    ((point.0 - a.0 - t * dx).powi(2) + (point.1 - a.1 - t * dy).powi(2)).sqrt()
+}
+
+/// A raster image, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Create an image of the given size filled with `color`.
+    pub fn new(width: usize, height: usize, color: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; width * height],
+        }
+    }
+
+    /// The width of the image in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the image in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The color of the pixel in column `x` and row `y`.
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Set the color of the pixel in column `x` and row `y`.
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// The color components of all pixels, row by row.
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect()
+    }
+
+    /// Write the image as binary PPM (`P6`).
+    pub fn write_ppm<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.bytes())
+    }
+
+    /// Write the image as PNG.
+    pub fn write_png<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.bytes()))
+            .map_err(io::Error::other)
+    }
+}
+
+/// The file formats a [`Scene`] can be saved in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    /// Determine the format from the extension of `path`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "ppm" => Some(Self::Ppm),
+            "png" => Some(Self::Png),
+            "svg" => Some(Self::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// Save `scene` in the given `format` to `path`, drawn with `palette` and
+/// scaled to `size` pixels along its longer side.
+pub fn save(
+    scene: &Scene,
+    palette: &Palette,
+    size: u32,
+    format: Format,
+    path: &Path,
+) -> io::Result<()> {
+    let scale = scene.scale_to(size);
+    let mut writer = BufWriter::new(File::create(path)?);
+    match format {
+        Format::Ppm => scene.rasterize(palette, scale).write_ppm(&mut writer)?,
+        Format::Png => scene.rasterize(palette, scale).write_png(&mut writer)?,
+        Format::Svg => writer.write_all(scene.to_svg(palette, scale).as_bytes())?,
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette() {
+        assert_eq!("#ff8000".parse(), Ok(Rgb(255, 128, 0)));
+        assert_eq!(Rgb(1, 171, 255).to_string(), "#01abff");
+        assert_eq!(
+            "#ff80".parse::<Rgb>().unwrap_err().to_string(),
+            "column 2: expected six hexadecimal digits"
+        );
+
+        assert_eq!("mono".parse(), Ok(Palette::mono()));
+        for name in Palette::NAMES {
+            assert!(name.parse::<Palette>().is_ok());
+        }
+        let palette: Palette = "#000000,#ffffff,#ff0000".parse().unwrap();
+        assert_eq!(palette.background, Rgb(0, 0, 0));
+        assert_eq!(palette.color(0), Rgb(255, 255, 255));
+        assert_eq!(palette.color(3), Rgb(255, 0, 0));
+        assert_eq!(Palette::new(Rgb(1, 2, 3), vec![]).color(2), Rgb(1, 2, 3));
+        assert_eq!(
+            "#000000,#fffff".parse::<Palette>().unwrap_err().to_string(),
+            "column 10: expected six hexadecimal digits"
+        );
+        assert!("pastel".parse::<Palette>().is_err());
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse("#.\n.o", Some).unwrap();
+        let scene = Scene::grid(&grid, |&c| match c {
+            '#' => Some(0),
+            'o' => Some(1),
+            _ => None,
+        });
+        assert_eq!((scene.width(), scene.height()), (2.0, 2.0));
+        assert_eq!(scene.shapes().len(), 2);
+
+        let palette = Palette::new(Rgb(0, 0, 0), vec![Rgb(255, 0, 0), Rgb(0, 255, 0)]);
+        let image = scene.rasterize(&palette, 3.0);
+        assert_eq!((image.width(), image.height()), (6, 6));
+        assert_eq!(image.get(0, 0), Rgb(255, 0, 0));
+        assert_eq!(image.get(2, 2), Rgb(255, 0, 0));
+        assert_eq!(image.get(3, 0), Rgb(0, 0, 0));
+        assert_eq!(image.get(3, 3), Rgb(0, 255, 0));
+        assert_eq!(image.get(5, 5), Rgb(0, 255, 0));
+
+        let svg = scene.to_svg(&palette, 3.0);
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="6" height="6" viewBox="0 0 2 2">"#
+        ));
+        assert!(svg.contains(r##"<rect x="1" y="1" width="1" height="1" fill="#00ff00""##));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_hex_grid() {
+        let scene = Scene::hex_grid(vec![((0, 0), 0), ((2, 0), 1), ((1, 1), 0)]);
+        let half_width = 3f64.sqrt() / 2.0;
+        assert!((scene.width() - 4.0 * half_width).abs() < 1e-9);
+        assert!((scene.height() - 3.5).abs() < 1e-9);
+
+        let palette = Palette::new(Rgb(0, 0, 0), vec![Rgb(255, 0, 0), Rgb(0, 255, 0)]);
+        let scale = 20.0;
+        let image = scene.rasterize(&palette, scale);
+        let pixel = |x: f64, y: f64| image.get((x * scale) as usize, (y * scale) as usize);
+        assert_eq!(pixel(half_width, 1.0), Rgb(255, 0, 0));
+        assert_eq!(pixel(3.0 * half_width, 1.0), Rgb(0, 255, 0));
+        assert_eq!(pixel(2.0 * half_width, 2.5), Rgb(255, 0, 0));
+        assert_eq!(pixel(0.05, 3.4), Rgb(0, 0, 0));
+        assert_eq!(scene.to_svg(&palette, scale).matches("<polygon").count(), 3);
+    }
+
+    #[test]
+    fn test_path() {
+        let scene = Scene::path(&[(0, 0), (100, 0), (100, 50)], 0);
+        assert!((scene.width() - 104.0).abs() < 1e-9);
+        assert!((scene.height() - 54.0).abs() < 1e-9);
+
+        let palette = Palette::mono();
+        let image = scene.rasterize(&palette, 1.0);
+        assert_eq!(image.get(50, 2), Rgb(0, 0, 0));
+        assert_eq!(image.get(102, 30), Rgb(0, 0, 0));
+        assert_eq!(image.get(50, 30), Rgb(255, 255, 255));
+        assert!(scene
+            .to_svg(&palette, 1.0)
+            .contains(r##"<polyline points="2,2 102,2 102,52" fill="none" stroke="#000000""##));
+    }
+
+    #[test]
+    fn test_write() {
+        let mut image = Image::new(2, 1, Rgb(1, 2, 3));
+        image.set(1, 0, Rgb(4, 5, 6));
+
+        let mut ppm = vec![];
+        image.write_ppm(&mut ppm).unwrap();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\x01\x02\x03\x04\x05\x06");
+
+        let mut png = vec![];
+        image.write_png(&mut png).unwrap();
+        let mut reader = png::Decoder::new(io::Cursor::new(png)).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (2, 1));
+        assert_eq!(&pixels[..info.buffer_size()], &[1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(Format::from_path(Path::new("a/b.PNG")), Some(Format::Png));
+        assert_eq!(Format::from_path(Path::new("b.svg")), Some(Format::Svg));
+        assert_eq!(Format::from_path(Path::new("b.ppm")), Some(Format::Ppm));
+        assert_eq!(Format::from_path(Path::new("b.gif")), None);
+        assert_eq!(Format::from_path(Path::new("b")), None);
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let scene = Scene::path(&[(0, 0), (10, 10)], 0);
+        for format in [Format::Ppm, Format::Png, Format::Svg] {
+            let path = dir.join(format!("scene.{:?}", format));
+            save(&scene, &Palette::default(), 100, format, &path).unwrap();
+            assert!(std::fs::metadata(&path).unwrap().len() > 0);
+        }
+        let missing = dir.join("missing").join("scene.png");
+        assert!(save(&scene, &Palette::default(), 100, Format::Png, &missing).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
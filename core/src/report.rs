@@ -0,0 +1,279 @@
+//! This module contains the machine-readable reports of the results
+//! of running the solutions, which can be written as JSON or CSV.
+use crate::runner::Failure;
+use crate::{Part, Result, Status};
+use serde::Serialize;
+use std::fmt::{self, Display};
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The outcome of running a part of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    /// The answer matches the known answer.
+    Pass,
+    /// The answer differs from the known answer.
+    Fail,
+    /// There is no known answer to compare against.
+    Unknown,
+    /// The solution returned an error.
+    Error,
+    /// The solution panicked.
+    Panic,
+    /// The solution did not finish in time.
+    Timeout,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail => write!(f, "fail"),
+            Self::Unknown => write!(f, "unknown"),
+            Self::Error => write!(f, "error"),
+            Self::Panic => write!(f, "panic"),
+            Self::Timeout => write!(f, "timeout"),
+        }
+    }
+}
+
+impl From<&Failure> for Outcome {
+    fn from(failure: &Failure) -> Self {
+        match failure {
+            Failure::Error(_) => Self::Error,
+            Failure::Panic(_) => Self::Panic,
+            Failure::Timeout(_) => Self::Timeout,
+        }
+    }
+}
+
+impl From<Status> for Outcome {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Pass => Self::Pass,
+            Status::Fail => Self::Fail,
+            Status::Unknown => Self::Unknown,
+        }
+    }
+}
+
+/// The result of running a part of a day.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    /// The profile providing the puzzle input, if any.
+    pub profile: Option<String>,
+    /// The day of the challenge.
+    pub day: u32,
+    /// The part of the challenge, either 1 or 2.
+    pub part: u8,
+    /// The computed answer, unless an error occurred.
+    pub answer: Option<String>,
+    /// The known answer, if any.
+    pub expected: Option<String>,
+    /// The outcome of comparing the answer to the known answer.
+    pub status: Outcome,
+    /// The time spent on computing the answer in milliseconds.
+    pub elapsed_ms: Option<f64>,
+    /// The reason why the answer could not be computed.
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// The record of computing `answer` to `part` of `day` in the time `elapsed`.
+    pub fn new(
+        day: u32,
+        part: Part,
+        answer: String,
+        expected: Option<&str>,
+        status: Status,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            profile: None,
+            day,
+            part: part_number(part),
+            answer: Some(answer),
+            expected: expected.map(String::from),
+            status: status.into(),
+            elapsed_ms: Some(elapsed.as_nanos() as f64 / 1e6),
+            error: None,
+        }
+    }
+
+    /// The record of failing to compute the answer to `part` of `day`.
+    pub fn failure(day: u32, part: Part, expected: Option<&str>, failure: &Failure) -> Self {
+        Self {
+            profile: None,
+            day,
+            part: part_number(part),
+            answer: None,
+            expected: expected.map(String::from),
+            status: failure.into(),
+            elapsed_ms: None,
+            error: Some(failure.to_string()),
+        }
+    }
+
+    /// Attribute the record to the given `profile`.
+    pub fn with_profile(mut self, profile: impl Display) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// The formats the results can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable lines.
+    Text,
+    /// A JSON array of records.
+    Json,
+    /// A CSV table with one record per row.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> std::result::Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!(
+                "`{}` is not a format, expected `text`, `json` or `csv`",
+                format
+            )),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Json => write!(f, "json"),
+            Self::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// Write `records` to `writer` as a JSON array.
+pub fn write_json<W: Write>(mut writer: W, records: &[Record]) -> Result<()> {
+    serde_json::to_writer_pretty(&mut writer, records).map_err(std::io::Error::from)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+/// Write `records` to `writer` as a CSV table with a header row.
+pub fn write_csv<W: Write>(writer: W, records: &[Record]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    if records.is_empty() {
+        writer
+            .write_record([
+                "profile",
+                "day",
+                "part",
+                "answer",
+                "expected",
+                "status",
+                "elapsed_ms",
+                "error",
+            ])
+            .map_err(std::io::Error::from)?;
+    }
+    for record in records {
+        writer.serialize(record).map_err(std::io::Error::from)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AocError;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::new(
+                1,
+                Part::One,
+                "270144".to_string(),
+                Some("270144"),
+                Status::Pass,
+                Duration::from_micros(1500),
+            ),
+            Record::failure(
+                1,
+                Part::Two,
+                None,
+                &Failure::Error(AocError::unsolvable("\"a, b\"")),
+            )
+            .with_profile("alice"),
+            Record::failure(2, Part::One, None, &Failure::Panic("oops".to_string())),
+        ]
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut out = vec![];
+        write_json(&mut out, &records()).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "profile": null, "day": 1, "part": 1, "answer": "270144",
+                    "expected": "270144", "status": "pass", "elapsed_ms": 1.5, "error": null
+                },
+                {
+                    "profile": "alice", "day": 1, "part": 2, "answer": null,
+                    "expected": null, "status": "error", "elapsed_ms": null,
+                    "error": "no solution: \"a, b\""
+                },
+                {
+                    "profile": null, "day": 2, "part": 1, "answer": null,
+                    "expected": null, "status": "panic", "elapsed_ms": null,
+                    "error": "panicked: oops"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = vec![];
+        write_csv(&mut out, &records()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "profile,day,part,answer,expected,status,elapsed_ms,error\n\
+             ,1,1,270144,270144,pass,1.5,\n\
+             alice,1,2,,,error,,\"no solution: \"\"a, b\"\"\"\n\
+             ,2,1,,,panic,,panicked: oops\n"
+        );
+
+        let mut out = vec![];
+        write_csv(&mut out, &[]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "profile,day,part,answer,expected,status,elapsed_ms,error\n"
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+        assert_eq!(Format::Csv.to_string(), "csv");
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
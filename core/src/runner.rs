@@ -0,0 +1,351 @@
+//! This module contains the runner, which solves the parts of the days
+//! concurrently on a pool of worker threads.
+//!
+//! Every part runs in isolation, such that an error, a panic or a timeout
+//! of one solution is reported as its [`Failure`] without affecting the
+//! others. Since threads cannot be stopped, a part that times out keeps
+//! running in the background while its worker is replaced.
+use crate::bench::{self, Measurement, Stats};
+use crate::{AocError, Day, Part, Result};
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The stack size of the worker threads, matching the one of the main thread.
+pub(crate) const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// A part of a day to solve for a puzzle input.
+#[derive(Debug, Clone)]
+pub struct Job {
+    /// The day to solve.
+    pub day: &'static Day,
+    /// The part to solve.
+    pub part: Part,
+    /// The puzzle input.
+    pub input: Input,
+}
+
+/// The puzzle input of a job.
+#[derive(Debug, Clone)]
+pub enum Input {
+    /// The content of the input, which may be shared by several jobs.
+    Data(Arc<str>),
+    /// The path of the input file, where `-` stands for stdin, which is read
+    /// one line at a time while the part is solved, see [`Day::stream`].
+    /// Parts that cannot be solved this way read the whole file first.
+    Stream(PathBuf),
+}
+
+/// The reasons why a job did not produce an answer.
+#[derive(Debug)]
+pub enum Failure {
+    /// The solution returned an error.
+    Error(AocError),
+    /// The solution panicked with the given message.
+    Panic(String),
+    /// The solution did not finish within the given time.
+    Timeout(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Error(err) => err.fmt(f),
+            Self::Panic(message) => write!(f, "panicked: {}", message),
+            Self::Timeout(timeout) => write!(f, "timed out after {:?}", timeout),
+        }
+    }
+}
+
+/// The result of running a job.
+pub type JobResult = std::result::Result<Measurement, Failure>;
+
+/// Runs jobs concurrently and isolates their failures.
+#[derive(Debug, Clone)]
+pub struct Runner {
+    threads: usize,
+    timeout: Option<Duration>,
+    runs: usize,
+}
+
+impl Runner {
+    /// A runner running up to `threads` jobs at a time, each of them once
+    /// and without a time limit.
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            timeout: None,
+            runs: 1,
+        }
+    }
+
+    /// Give up on jobs that do not finish within `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Run every job the given number of `runs`, as in [`bench::measure`].
+    /// The timeout applies to all runs of a job together.
+    pub fn runs(mut self, runs: usize) -> Self {
+        self.runs = runs.max(1);
+        self
+    }
+
+    /// Run the `jobs` and pass the result of each of them to `on_result`
+    /// along with its index, in the order of the jobs.
+    pub fn run<F>(&self, jobs: Vec<Job>, mut on_result: F)
+    where
+        F: FnMut(usize, JobResult),
+    {
+        let (sender, receiver) = mpsc::channel();
+        let mut pending = jobs.into_iter().enumerate();
+        let mut running = HashMap::new();
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+
+        loop {
+            while running.len() < self.threads {
+                let (index, job) = match pending.next() {
+                    Some(next) => next,
+                    None => break,
+                };
+                let sender = sender.clone();
+                let runs = self.runs;
+                let spawned = thread::Builder::new()
+                    .name(format!("day {}, task {}", job.day.day, job.part))
+                    .stack_size(STACK_SIZE)
+                    .spawn(move || {
+                        let _ = sender.send((index, run_job(&job, runs)));
+                    });
+                match spawned {
+                    Ok(_) => {
+                        running.insert(index, Instant::now());
+                    }
+                    Err(err) => {
+                        finished.insert(index, Err(Failure::Error(err.into())));
+                    }
+                }
+            }
+            if running.is_empty() && finished.is_empty() {
+                break;
+            }
+
+            // A deadline too far in the future to be represented is never reached.
+            let deadline = running
+                .values()
+                .min()
+                .and_then(|&started| started.checked_add(self.timeout?));
+            let received = match (deadline, running.is_empty()) {
+                (Some(deadline), false) => {
+                    receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+                (None, false) => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                (_, true) => Err(RecvTimeoutError::Disconnected),
+            };
+            match received {
+                Ok((index, result)) => {
+                    // Results of jobs that already timed out are dropped.
+                    if running.remove(&index).is_some() {
+                        finished.insert(index, result);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    let timeout = self.timeout.unwrap_or_default();
+                    let now = Instant::now();
+                    running.retain(|&index, started| {
+                        let expired = now.duration_since(*started) >= timeout;
+                        if expired {
+                            finished.insert(index, Err(Failure::Timeout(timeout)));
+                        }
+                        !expired
+                    });
+                }
+                Err(RecvTimeoutError::Disconnected) => {}
+            }
+
+            while let Some(result) = finished.remove(&next) {
+                on_result(next, result);
+                next += 1;
+            }
+        }
+    }
+}
+
+/// Run `job` the given number of `runs`, catching any panic.
+pub(crate) fn run_job(job: &Job, runs: usize) -> JobResult {
+    match panic::catch_unwind(AssertUnwindSafe(|| match &job.input {
+        Input::Data(data) => bench::measure(job.day, data, job.part, runs),
+        Input::Stream(path) => stream(job.day, path, job.part, runs),
+    })) {
+        Ok(Ok(measurement)) => Ok(measurement),
+        Ok(Err(err)) => Err(Failure::Error(err)),
+        Err(payload) => Err(Failure::Panic(panic_message(payload))),
+    }
+}
+
+/// Solve `part` of `day` while reading the puzzle input from `path`, where
+/// the time spent on reading counts as solving. A streamed input is read
+/// once, so it is solved once regardless of the `runs`.
+fn stream(day: &Day, path: &Path, part: Part, runs: usize) -> Result<Measurement> {
+    if !day.streams(part) {
+        return bench::measure(day, &crate::read_input(path)?, part, runs);
+    }
+    let start = Instant::now();
+    let answer = day
+        .stream(crate::open_input(path)?, part)
+        .expect("the part can be streamed")?;
+    let solve = start.elapsed();
+    Ok(Measurement {
+        answer,
+        parse: Stats::new(&[Duration::ZERO]),
+        solve: Stats::new(&[solve]),
+    })
+}
+
+/// The message passed to `panic!`, if it is a string.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown cause".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Result, Solution, Streamer, Unsolved};
+
+    struct Echo;
+
+    impl Solution for Echo {
+        type Input = String;
+        type Answer1 = String;
+        type Answer2 = Unsolved;
+
+        fn parse(data: &str) -> Result<Self::Input> {
+            Ok(data.to_string())
+        }
+
+        fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+            match input.as_str() {
+                "panic" => panic!("Task 1 should finish"),
+                "sleep" => {
+                    thread::sleep(Duration::from_secs(2));
+                    Ok(input.clone())
+                }
+                _ => Ok(input.clone()),
+            }
+        }
+
+        fn part_2(_: &Self::Input) -> Result<Self::Answer2> {
+            Err(AocError::unsolvable("no part 2"))
+        }
+
+        fn streamer(part: Part) -> Option<Streamer> {
+            match part {
+                Part::One => Some(|lines| Ok(lines.collect::<Result<Vec<_>>>()?.join(","))),
+                Part::Two => None,
+            }
+        }
+    }
+
+    static ECHO: Day = Day::new::<Echo>(1);
+
+    fn job(part: Part, input: &str) -> Job {
+        Job {
+            day: &ECHO,
+            part,
+            input: Input::Data(input.into()),
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let jobs = vec![
+            job(Part::One, "sleep"),
+            job(Part::One, "a"),
+            job(Part::One, "panic"),
+            job(Part::Two, "b"),
+            job(Part::One, "c"),
+        ];
+        let mut results = vec![];
+        Runner::new(3)
+            .timeout(Duration::from_millis(500))
+            .run(jobs, |index, result| results.push((index, result)));
+
+        assert_eq!(
+            results.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        assert!(matches!(results[0].1, Err(Failure::Timeout(_))));
+        assert_eq!(results[1].1.as_ref().unwrap().answer, "a");
+        assert!(matches!(&results[2].1, Err(Failure::Panic(m)) if m == "Task 1 should finish"));
+        assert!(matches!(
+            results[3].1,
+            Err(Failure::Error(AocError::Unsolvable(_)))
+        ));
+        assert_eq!(results[4].1.as_ref().unwrap().answer, "c");
+    }
+
+    #[test]
+    fn test_run_stream() {
+        let path = std::env::temp_dir().join(format!("aoc2020-stream-{}", std::process::id()));
+        std::fs::write(&path, "a\nb\n").unwrap();
+        let stream = |part: Part, path: &Path| Job {
+            day: &ECHO,
+            part,
+            input: Input::Stream(path.to_path_buf()),
+        };
+        let mut results = vec![];
+        Runner::new(2).runs(3).run(
+            vec![
+                stream(Part::One, &path),
+                stream(Part::Two, &path),
+                stream(Part::One, &path.with_extension("missing")),
+            ],
+            |_, result| results.push(result),
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        let measurement = results[0].as_ref().unwrap();
+        assert_eq!(measurement.answer, "a,b");
+        assert_eq!(measurement.parse.max, Duration::ZERO);
+        // The part that cannot be streamed reads the whole file instead.
+        assert!(matches!(
+            results[1],
+            Err(Failure::Error(AocError::Unsolvable(_)))
+        ));
+        assert!(matches!(results[2], Err(Failure::Error(AocError::Io(_)))));
+    }
+
+    #[test]
+    fn test_run_unbounded_timeout() {
+        let mut answers = vec![];
+        Runner::new(1)
+            .timeout(Duration::MAX)
+            .run(vec![job(Part::One, "a")], |_, result| {
+                answers.push(result.unwrap().answer)
+            });
+        assert_eq!(answers, vec!["a"]);
+    }
+
+    #[test]
+    fn test_run_sequential() {
+        let mut answers = vec![];
+        Runner::new(1).runs(3).run(
+            vec![job(Part::One, "a"), job(Part::One, "b")],
+            |_, result| answers.push(result.unwrap().answer),
+        );
+        assert_eq!(answers, vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,307 @@
+//! This module contains the scaffolding generator for the solution of a
+//! new day.
+//!
+//! For day `N`, it creates the module `src/day_N.rs` with a unit-test
+//! skeleton for the example input and the integration test `tests/day_N.rs`
+//! in the crate of the year, declares the module in its `src/lib.rs` and
+//! registers the day in its `DAYS`, such that the runner picks it up right away.
+use crate::{AocError, Result};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The ordinal numbers of the days, as used in the module documentation.
+const ORDINALS: [&str; 25] = [
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+    "twentieth",
+    "twenty-first",
+    "twenty-second",
+    "twenty-third",
+    "twenty-fourth",
+    "twenty-fifth",
+];
+
+/// The line opening the registry of the days in `src/lib.rs`.
+const REGISTRY_START: &str = "pub static DAYS: &[Day] = &[";
+
+/// The source of the module `src/day_N.rs` of `day` in `year`.
+pub fn module_source(year: u32, day: u32) -> String {
+    let ordinal = ORDINALS[day as usize - 1];
+    format!(
+        r#"//! This module contains the code
+//! for the solution of the {ordinal} day's challenges.
+//!
+//! The problem formulation for these challenges can
+//! be found [here](https://adventofcode.com/{year}/day/{day}).
+use crate::{{Result, Solution, Unsolved}};
+
+/// The solution to the {ordinal} day's challenges.
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+    type Answer1 = Unsolved;
+    type Answer2 = Unsolved;
+
+    fn parse(data: &str) -> Result<Self::Input> {{
+        crate::parse_lines(data)
+    }}
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {{
+        task_1(input)
+    }}
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {{
+        task_2(input)
+    }}
+}}
+
+/// Compute the answer to the first task.
+pub fn task_1(_data: &[String]) -> Result<Unsolved> {{
+    Ok(Unsolved)
+}}
+
+/// Compute the answer to the second task.
+pub fn task_2(_data: &[String]) -> Result<Unsolved> {{
+    Ok(Unsolved)
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    /// The example input from the problem formulation.
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn test_day_{day}_task_1() {{
+        let input = Day{day}::parse(EXAMPLE).unwrap();
+        assert_eq!(task_1(&input).unwrap(), Unsolved);
+    }}
+
+    #[test]
+    fn test_day_{day}_task_2() {{
+        let input = Day{day}::parse(EXAMPLE).unwrap();
+        assert_eq!(task_2(&input).unwrap(), Unsolved);
+    }}
+}}
+"#,
+        year = year,
+        day = day,
+        ordinal = ordinal
+    )
+}
+
+/// The source of the integration test `tests/day_N.rs` of `day` in `year`.
+///
+/// The test is ignored, since the answers of the skeleton do not pass,
+/// until the solution is written.
+pub fn test_source(year: u32, day: u32) -> String {
+    format!(
+        r#"use aoc{year}::{{self, read_data, Part, Profile, Status}};
+
+#[test]
+#[ignore = "day {day} is not solved yet"]
+fn test_day_{day}() {{
+    for profile in Profile::with_input(&aoc{year}::YEAR, {day}).unwrap() {{
+        let answers = profile.answers().unwrap();
+        let data = read_data::<String, _>(profile.input_path({day})).unwrap();
+
+        let task_1 = aoc{year}::day_{day}::task_1(&data).unwrap();
+        assert_eq!(
+            answers.check({day}, Part::One, task_1),
+            Status::Pass,
+            "{{profile}}"
+        );
+
+        let task_2 = aoc{year}::day_{day}::task_2(&data).unwrap();
+        assert_eq!(
+            answers.check({day}, Part::Two, task_2),
+            Status::Pass,
+            "{{profile}}"
+        );
+    }}
+}}
+"#,
+        year = year,
+        day = day
+    )
+}
+
+/// Declare the module of `day` and register it in the source `lib` of `src/lib.rs`.
+///
+/// The module declarations are kept in lexicographic order and the registry
+/// in the order of the days.
+pub fn register(lib: &str, day: u32) -> Result<String> {
+    let declaration = format!("pub mod day_{};", day);
+    let entry = format!("    Day::new::<day_{0}::Day{0}>({0}),", day);
+    let mut lines = lib.lines().map(String::from).collect::<Vec<_>>();
+    if lines.iter().any(|l| l.trim() == declaration) {
+        return Err(already_exists(format!("`{}` in src/lib.rs", declaration)));
+    }
+
+    let name = format!("day_{}", day);
+    let module_name = |line: &str| {
+        let line = line.trim_start_matches("pub mod ");
+        line.trim_end_matches(';').to_string()
+    };
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with("pub mod "))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let position = modules
+        .iter()
+        .find(|&&i| module_name(&lines[i]) > name)
+        .copied()
+        .or_else(|| modules.last().map(|i| i + 1))
+        .ok_or_else(|| AocError::unexpected_end(lib, "expected module declarations"))?;
+    lines.insert(position, declaration);
+
+    let start = lines
+        .iter()
+        .position(|l| l.trim() == REGISTRY_START)
+        .ok_or_else(|| {
+            AocError::unexpected_end(lib, format!("expected the registry `{}`", REGISTRY_START))
+        })?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l.trim() == "];")
+            .ok_or_else(|| AocError::unexpected_end(lib, "expected the end of the registry"))?;
+    let registered = |line: &str| {
+        line.trim()
+            .trim_end_matches("),")
+            .rsplit('(')
+            .next()
+            .and_then(|n| n.parse::<u32>().ok())
+    };
+    let position = (start + 1..end)
+        .find(|&i| registered(&lines[i]).is_some_and(|d| d > day))
+        .unwrap_or(end);
+    lines.insert(position, entry);
+
+    let mut source = lines.join("\n");
+    if lib.ends_with('\n') {
+        source.push('\n');
+    }
+    Ok(source)
+}
+
+/// Generate the scaffolding of `day` in the crate of `year` at `root`,
+/// returning the paths of the created and modified files.
+///
+/// Existing solutions or tests are never overwritten.
+pub fn generate<P: AsRef<Path>>(root: P, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("`{}` is not a day between 1 and 25", day),
+        )));
+    }
+    let root = root.as_ref();
+    let module = root.join("src").join(format!("day_{}.rs", day));
+    let test = root.join("tests").join(format!("day_{}.rs", day));
+    let lib = root.join("src").join("lib.rs");
+    for path in &[&module, &test] {
+        if path.exists() {
+            return Err(already_exists(path.display()));
+        }
+    }
+
+    let registered = register(&crate::read_string(&lib)?, day)?;
+    std::fs::create_dir_all(root.join("tests"))?;
+    std::fs::write(&module, module_source(year, day))?;
+    std::fs::write(&test, test_source(year, day))?;
+    std::fs::write(&lib, registered)?;
+    Ok(vec![module, test, lib])
+}
+
+fn already_exists(what: impl std::fmt::Display) -> AocError {
+    AocError::Io(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("{} already exists", what),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "//! Docs\n\npub mod answers;\npub mod day_1;\npub mod day_10;\npub mod day_3;\npub mod error;\n\n\
+        pub static DAYS: &[Day] = &[\n    Day::new::<day_1::Day1>(1),\n    Day::new::<day_3::Day3>(3),\n    \
+        Day::new::<day_10::Day10>(10),\n];\n";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register(LIB, 2).unwrap(),
+            "//! Docs\n\npub mod answers;\npub mod day_1;\npub mod day_10;\npub mod day_2;\npub mod day_3;\npub mod error;\n\n\
+             pub static DAYS: &[Day] = &[\n    Day::new::<day_1::Day1>(1),\n    Day::new::<day_2::Day2>(2),\n    \
+             Day::new::<day_3::Day3>(3),\n    Day::new::<day_10::Day10>(10),\n];\n"
+        );
+        let registered = register(LIB, 25).unwrap();
+        assert!(registered.contains("pub mod day_10;\npub mod day_25;\npub mod day_3;\n"));
+        assert!(registered.contains("(10),\n    Day::new::<day_25::Day25>(25),\n];"));
+        assert!(register(LIB, 3).is_err());
+        assert!(register("pub mod day_1;\n", 2).is_err());
+    }
+
+    #[test]
+    fn test_sources() {
+        let module = module_source(2020, 21);
+        assert!(module.contains("solution of the twenty-first day's challenges"));
+        assert!(module.contains("https://adventofcode.com/2020/day/21"));
+        assert!(module.contains("pub struct Day21;"));
+        assert!(module.contains("fn test_day_21_task_2()"));
+        let test = test_source(2021, 21);
+        assert!(test.contains("Profile::with_input(&aoc2021::YEAR, 21)"));
+        assert!(test.contains("use aoc2021::{"));
+        assert!(test.contains("aoc2021::day_21::task_2(&data)"));
+        assert!(test.contains("#[ignore = \"day 21 is not solved yet\"]\nfn test_day_21()"));
+    }
+
+    #[test]
+    fn test_generate() {
+        let root = std::env::temp_dir().join(format!("aoc2020-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src").join("lib.rs"), LIB).unwrap();
+
+        let paths = generate(&root, 2020, 4).unwrap();
+        assert_eq!(paths.len(), 3);
+        assert_eq!(
+            crate::read_string(root.join("src").join("day_4.rs")).unwrap(),
+            module_source(2020, 4)
+        );
+        assert_eq!(
+            crate::read_string(root.join("tests").join("day_4.rs")).unwrap(),
+            test_source(2020, 4)
+        );
+        let lib = crate::read_string(root.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("pub mod day_4;"));
+        assert!(lib.contains("Day::new::<day_4::Day4>(4),"));
+
+        assert!(generate(&root, 2020, 4).is_err());
+        assert!(generate(&root, 2020, 0).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,368 @@
+//! This module contains the HTTP service, which exposes the solutions of a
+//! [`Year`] to tools that do not link against them.
+//!
+//! The raw puzzle input is posted as the body of `POST /day/{n}/part/{p}`.
+//! The response is a JSON object with the answer and the time spent on
+//! parsing and on solving, in nanoseconds:
+//!
+//! ```json
+//! {"day": 1, "part": 1, "answer": "514579", "timing": {"parse": 1200, "solve": 3400}}
+//! ```
+//!
+//! If the request is invalid or no answer is found, the object holds an
+//! `error` instead, and the status tells the cause apart:
+//!
+//! | Status | Cause                                                     |
+//! |--------|-----------------------------------------------------------|
+//! | 400    | The puzzle input is not UTF-8.                            |
+//! | 404    | There is no such day or part.                             |
+//! | 405    | The method is not `POST`.                                 |
+//! | 413    | The puzzle input exceeds [`Limits::max_input`].           |
+//! | 422    | The solution returned an error, e.g. a malformed line.    |
+//! | 500    | The solution panicked.                                    |
+//! | 503    | The solution did not finish within [`Limits::timeout`],   |
+//! |        | or [`Limits::max_solvers`] solutions are still running.   |
+//!
+//! Every solution runs on a thread of its own, such that a failing solution
+//! does not take the service down. Since threads cannot be stopped, one that
+//! times out keeps running until it finishes, and counts towards the limit
+//! of running solutions until then.
+use crate::runner::{self, Failure, Input, Job, JobResult};
+use crate::{Part, Year};
+use serde_json::{json, Value};
+use std::io::{self, Read};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// The limits on the requests the service accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum size of a puzzle input, in bytes.
+    pub max_input: usize,
+    /// The time after which the service gives up on a solution.
+    pub timeout: Duration,
+    /// The maximum number of solutions running at the same time, including
+    /// the ones that timed out but did not finish yet.
+    pub max_solvers: usize,
+}
+
+impl Default for Limits {
+    /// One MiB of puzzle input, solved within ten seconds by one of up to
+    /// eight solutions at a time.
+    fn default() -> Self {
+        Self {
+            max_input: 1 << 20,
+            timeout: Duration::from_secs(10),
+            max_solvers: 8,
+        }
+    }
+}
+
+/// The response to a request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The JSON object sent as body.
+    pub body: Value,
+}
+
+impl Reply {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+/// Answers requests using the solutions of a year, within the given limits.
+#[derive(Debug)]
+pub struct Service {
+    year: &'static Year,
+    limits: Limits,
+    solvers: Arc<AtomicUsize>,
+}
+
+/// A running solution, which is counted until it is dropped at its end.
+struct Solver(Arc<AtomicUsize>);
+
+impl Drop for Solver {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Service {
+    /// A service for the solutions of `year`, which enforces the `limits`.
+    pub fn new(year: &'static Year, limits: Limits) -> Self {
+        Self {
+            year,
+            limits,
+            solvers: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// The number of solutions that are still running.
+    pub fn running(&self) -> usize {
+        self.solvers.load(Ordering::SeqCst)
+    }
+
+    /// Answer the request with the given `method` and `url`, whose body is
+    /// read from `body`.
+    pub fn handle<R: Read>(&self, method: &str, url: &str, body: R) -> Reply {
+        let (year, limits) = (self.year, &self.limits);
+        let path = url.split('?').next().unwrap_or_default();
+        let (day, part) = match path.split('/').collect::<Vec<_>>()[..] {
+            ["", "day", day, "part", part] => (day, part),
+            _ => return Reply::error(404, format!("no such endpoint `{}`", path)),
+        };
+        if method != "POST" {
+            return Reply::error(405, format!("expected `POST {}`", path));
+        }
+        let day = match day.parse().ok().and_then(|day| year.find_day(day)) {
+            Some(day) => day,
+            None => {
+                return Reply::error(
+                    404,
+                    format!("there is no solution of day `{}` of {}", day, year.year),
+                )
+            }
+        };
+        let (part, number) = match part {
+            "1" => (Part::One, 1),
+            "2" => (Part::Two, 2),
+            _ => return Reply::error(404, format!("`{}` is not a part, expected 1 or 2", part)),
+        };
+
+        let mut input = vec![];
+        if let Err(err) = body
+            .take(limits.max_input as u64 + 1)
+            .read_to_end(&mut input)
+        {
+            return Reply::error(400, format!("cannot read the puzzle input: {}", err));
+        }
+        if input.len() > limits.max_input {
+            return Reply::error(
+                413,
+                format!("the puzzle input exceeds {} bytes", limits.max_input),
+            );
+        }
+        let input = match String::from_utf8(input) {
+            Ok(input) => input,
+            Err(_) => return Reply::error(400, "the puzzle input is not UTF-8"),
+        };
+
+        let job = Job {
+            day,
+            part,
+            input: Input::Data(input.into()),
+        };
+        let mut body = json!({ "day": day.day, "part": number });
+        let status = match self.solve(job) {
+            Some(Ok(measurement)) => {
+                let timing = measurement.median();
+                body["answer"] = json!(measurement.answer);
+                body["timing"] = json!({
+                    "parse": timing.parse.as_nanos() as u64,
+                    "solve": timing.solve.as_nanos() as u64,
+                });
+                200
+            }
+            Some(Err(failure)) => {
+                body["error"] = json!(failure.to_string());
+                match failure {
+                    Failure::Error(_) => 422,
+                    Failure::Panic(_) => 500,
+                    Failure::Timeout(_) => 503,
+                }
+            }
+            None => {
+                body["error"] = json!(format!(
+                    "{} solutions are still running, try again later",
+                    limits.max_solvers
+                ));
+                503
+            }
+        };
+        Reply { status, body }
+    }
+
+    /// Run `job` on a thread of its own and wait for its result until the
+    /// timeout, or return `None` if too many solutions are running already.
+    fn solve(&self, job: Job) -> Option<JobResult> {
+        if self.solvers.fetch_add(1, Ordering::SeqCst) >= self.limits.max_solvers {
+            self.solvers.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        let solver = Solver(Arc::clone(&self.solvers));
+        let (sender, receiver) = mpsc::channel();
+        let spawned = thread::Builder::new()
+            .name(format!("day {}, task {}", job.day.day, job.part))
+            .stack_size(runner::STACK_SIZE)
+            .spawn(move || {
+                let _solver = solver;
+                let _ = sender.send(runner::run_job(&job, 1));
+            });
+        if let Err(err) = spawned {
+            return Some(Err(Failure::Error(err.into())));
+        }
+        Some(
+            receiver
+                .recv_timeout(self.limits.timeout)
+                .unwrap_or(Err(Failure::Timeout(self.limits.timeout))),
+        )
+    }
+
+    /// Answer the requests to `server` on the given number of `threads`
+    /// until the server is unblocked.
+    pub fn serve(&self, server: &Server, threads: usize) {
+        thread::scope(|scope| {
+            for _ in 0..threads.max(1) {
+                scope.spawn(|| {
+                    for request in server.incoming_requests() {
+                        // A client that went away is not the service's concern.
+                        let _ = self.respond(request);
+                    }
+                });
+            }
+        });
+    }
+
+    /// Answer `request`, refusing bodies that announce to be too large before
+    /// reading them.
+    fn respond(&self, mut request: Request) -> io::Result<()> {
+        let max_input = self.limits.max_input;
+        let reply = match request.body_length() {
+            Some(length) if length > max_input && *request.method() == Method::Post => {
+                Reply::error(413, format!("the puzzle input exceeds {} bytes", max_input))
+            }
+            _ => {
+                let method = request.method().to_string();
+                let url = request.url().to_string();
+                self.handle(&method, &url, request.as_reader())
+            }
+        };
+        let content_type =
+            Header::from_bytes("Content-Type", "application/json").expect("the header is valid");
+        request.respond(
+            Response::from_string(reply.body.to_string())
+                .with_status_code(reply.status)
+                .with_header(content_type),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AocError, Day, Result, Solution, Unsolved};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = Unsolved;
+
+        fn parse(data: &str) -> Result<Self::Input> {
+            crate::parse_lines(data)
+        }
+
+        fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+            match input[..] {
+                [0] => panic!("Task 1 should finish"),
+                [1] => {
+                    thread::sleep(Duration::from_secs(1));
+                    Ok(1)
+                }
+                _ => Ok(input.iter().sum()),
+            }
+        }
+
+        fn part_2(_: &Self::Input) -> Result<Self::Answer2> {
+            Err(AocError::unsolvable("no part 2"))
+        }
+    }
+
+    static DAYS: [Day; 1] = [Day::new::<Sum>(3)];
+    static YEAR: Year = Year::new(2020, &DAYS, "");
+
+    fn service() -> Service {
+        let limits = Limits {
+            max_input: 16,
+            timeout: Duration::from_millis(200),
+            max_solvers: 2,
+        };
+        Service::new(&YEAR, limits)
+    }
+
+    fn post(url: &str, body: &str) -> Reply {
+        service().handle("POST", url, body.as_bytes())
+    }
+
+    #[test]
+    fn test_handle() {
+        let reply = post("/day/3/part/1", "1\n2\n3\n");
+        assert_eq!(reply.status, 200);
+        assert_eq!(reply.body["day"], 3);
+        assert_eq!(reply.body["part"], 1);
+        assert_eq!(reply.body["answer"], "6");
+        assert!(reply.body["timing"]["parse"].is_u64());
+        assert!(reply.body["timing"]["solve"].is_u64());
+        assert!(reply.body.get("error").is_none());
+
+        let reply = post("/day/3/part/1?verbose", "2\n2\n");
+        assert_eq!(reply.body["answer"], "4");
+    }
+
+    #[test]
+    fn test_handle_errors() {
+        let status = |url: &str, body: &str| post(url, body).status;
+        assert_eq!(status("/day/3", "1"), 404);
+        assert_eq!(status("/day/3/part/1/x", "1"), 404);
+        assert_eq!(status("/day/4/part/1", "1"), 404);
+        assert_eq!(status("/day/x/part/1", "1"), 404);
+        assert_eq!(status("/day/3/part/3", "1"), 404);
+        assert_eq!(status("/day/3/part/1", &"1\n".repeat(9)), 413);
+        assert_eq!(status("/day/3/part/1", &"1\n".repeat(8)), 200);
+        assert_eq!(status("/day/3/part/1", "x"), 422);
+        assert_eq!(status("/day/3/part/2", "1"), 422);
+        assert_eq!(status("/day/3/part/1", "0"), 500);
+        assert_eq!(status("/day/3/part/1", "1"), 503);
+
+        let reply = post("/day/3/part/1", "x");
+        assert_eq!(reply.body["day"], 3);
+        assert!(reply.body["error"].as_str().unwrap().contains("line 1"));
+        assert!(reply.body.get("answer").is_none());
+
+        let service = Service::new(&YEAR, Limits::default());
+        let reply = service.handle("GET", "/day/3/part/1", &b""[..]);
+        assert_eq!(reply.status, 405);
+        let reply = service.handle("POST", "/day/3/part/1", &[0xff][..]);
+        assert_eq!(reply.status, 400);
+    }
+
+    #[test]
+    fn test_handle_max_solvers() {
+        let service = service();
+        let post = |body: &str| service.handle("POST", "/day/3/part/1", body.as_bytes());
+        assert_eq!(post("1").status, 503);
+        assert_eq!(post("1").status, 503);
+        assert_eq!(service.running(), 2);
+        let reply = post("2");
+        assert_eq!(reply.status, 503);
+        assert!(reply.body["error"]
+            .as_str()
+            .unwrap()
+            .contains("2 solutions are still running"));
+
+        // The solutions that timed out are counted until they finish.
+        thread::sleep(Duration::from_secs(2));
+        assert_eq!(service.running(), 0);
+        assert_eq!(post("2").body["answer"], "2");
+    }
+}
@@ -0,0 +1,537 @@
+//! This module contains the submission client, which sends answers to
+//! the Advent of Code server and interprets its response.
+//!
+//! Every attempt is recorded in a ledger next to the puzzle inputs, a TOML
+//! file such as `data/submissions.toml`:
+//!
+//! ```toml
+//! [[attempt]]
+//! day = 1
+//! part = 1
+//! answer = "270144"
+//! verdict = "right"
+//! timestamp = 1606798800
+//! ```
+//!
+//! Answers that the ledger already shows to be wrong are never submitted
+//! again, which includes answers beyond a known too high or too low answer
+//! and, once a part is solved, any other answer than the right one.
+use crate::answers::malformed_key;
+use crate::fetch::{HttpEndpoint, USER_AGENT};
+use crate::{AocError, Part, Result};
+use std::fmt::{self, Display};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The name of the ledger file in a profile directory.
+pub const LEDGER_FILE: &str = "submissions.toml";
+
+/// The response of the server to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer is right.
+    Right,
+    /// The answer is wrong.
+    Wrong,
+    /// The answer is wrong and too high.
+    TooHigh,
+    /// The answer is wrong and too low.
+    TooLow,
+    /// The answer was not checked since the previous one was submitted too
+    /// recently, with the time left to wait if known.
+    RateLimited(Option<Duration>),
+    /// The answer was not checked since the part is already solved.
+    AlreadySolved,
+    /// The response could not be interpreted.
+    Unrecognized,
+}
+
+impl Verdict {
+    /// Parse the verdict from the HTML `page` the server responds with.
+    pub fn from_response(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Self::Right
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Self::TooHigh
+            } else if page.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Self::RateLimited(parse_wait(page))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Self::AlreadySolved
+        } else {
+            Self::Unrecognized
+        }
+    }
+
+    /// Whether the verdict shows the answer to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+
+/// Parse the time left to wait from a message such as `You have 1m 5s left to wait.`
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let unit = amount.trim_start_matches(|c: char| c.is_ascii_digit());
+            let value = amount[..amount.len() - unit.len()].parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Right => write!(f, "right"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooHigh => write!(f, "too-high"),
+            Self::TooLow => write!(f, "too-low"),
+            Self::RateLimited(_) => write!(f, "rate-limited"),
+            Self::AlreadySolved => write!(f, "already-solved"),
+            Self::Unrecognized => write!(f, "unrecognized"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(verdict: &str) -> std::result::Result<Self, Self::Err> {
+        match verdict {
+            "right" => Ok(Self::Right),
+            "wrong" => Ok(Self::Wrong),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "rate-limited" => Ok(Self::RateLimited(None)),
+            "already-solved" => Ok(Self::AlreadySolved),
+            "unrecognized" => Ok(Self::Unrecognized),
+            _ => Err(format!("`{}` is not a verdict", verdict)),
+        }
+    }
+}
+
+/// A receiver of answers.
+pub trait AnswerEndpoint {
+    /// Submit `answer` to `part` of `day` and return the verdict.
+    fn submit(&self, day: u32, part: Part, answer: &str) -> Result<Verdict>;
+}
+
+impl AnswerEndpoint for HttpEndpoint {
+    fn submit(&self, day: u32, part: Part, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, self.year, day);
+        let level = part.to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .map_err(|err| AocError::remote(format!("POST {}: {}", url, err)))?;
+        let status = response.status().as_u16();
+        let page = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| AocError::remote(format!("POST {}: {}", url, err)))?;
+        match status {
+            200 => Ok(Verdict::from_response(&page)),
+            _ => Err(AocError::remote(format!(
+                "POST {}: unexpected status {}",
+                url, status
+            ))),
+        }
+    }
+}
+
+/// A recorded submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// The day of the challenge.
+    pub day: u32,
+    /// The part of the challenge.
+    pub part: Part,
+    /// The submitted answer.
+    pub answer: String,
+    /// The response of the server.
+    pub verdict: Verdict,
+    /// The time of the submission in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "[[attempt]]")?;
+        writeln!(f, "day = {}", self.day)?;
+        writeln!(f, "part = {}", self.part)?;
+        writeln!(f, "answer = {}", toml::Value::from(self.answer.as_str()))?;
+        writeln!(f, "verdict = \"{}\"", self.verdict)?;
+        writeln!(f, "timestamp = {}", self.timestamp)
+    }
+}
+
+/// The history of all submissions, stored in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// Open the ledger stored at `path`, which is empty if the file does not exist.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let path = path.into();
+        let attempts = if path.is_file() {
+            parse_attempts(&crate::read_string(&path)?)?
+        } else {
+            vec![]
+        };
+        Ok(Self { path, attempts })
+    }
+
+    /// The location of the ledger.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// All recorded attempts, in the order they were made.
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Record `attempt` by appending it to the file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", attempt)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// What the recorded attempts tell about `answer` to `part` of `day`:
+    /// the verdict on the same answer, that it is wrong since it lies beyond
+    /// a known too high or too low answer, or that it is wrong since the
+    /// part was solved with another answer.
+    pub fn known_verdict(&self, day: u32, part: Part, answer: &str) -> Option<Verdict> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.day == day && a.part == part);
+        let mut known = None;
+        for attempt in attempts {
+            let verdict = match attempt.verdict {
+                Verdict::Right if attempt.answer != answer => Verdict::Wrong,
+                _ if attempt.answer == answer => attempt.verdict,
+                Verdict::TooHigh if is_beyond(answer, &attempt.answer, true) => Verdict::TooHigh,
+                Verdict::TooLow if is_beyond(answer, &attempt.answer, false) => Verdict::TooLow,
+                _ => continue,
+            };
+            if verdict == Verdict::Right || verdict.is_wrong() {
+                known = Some(verdict);
+            }
+        }
+        known
+    }
+}
+
+/// Whether the integer `answer` is at least (`above`) or at most the integer `bound`.
+fn is_beyond(answer: &str, bound: &str, above: bool) -> bool {
+    match (answer.trim().parse::<i128>(), bound.trim().parse::<i128>()) {
+        (Ok(answer), Ok(bound)) if above => answer >= bound,
+        (Ok(answer), Ok(bound)) => answer <= bound,
+        _ => false,
+    }
+}
+
+/// Parse the attempts from the TOML representation of the ledger in `data`.
+fn parse_attempts(data: &str) -> Result<Vec<Attempt>> {
+    let table = data.parse::<toml::Table>().map_err(|err| {
+        let start = err.span().map_or(data.len(), |span| span.start);
+        AocError::malformed_in(data, &data[start..start], err.message())
+    })?;
+    let entries = match table.get("attempt") {
+        None => return Ok(vec![]),
+        Some(toml::Value::Array(entries)) => entries,
+        Some(_) => {
+            return Err(malformed_key(
+                data,
+                None,
+                "attempt",
+                "expected an array of tables `[[attempt]]`",
+            ))
+        }
+    };
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let field = |key: &str| entry.get(key);
+            let int = |key: &str| field(key).and_then(toml::Value::as_integer);
+            let string = |key: &str| field(key).and_then(toml::Value::as_str);
+            let attempt = (|| {
+                Some(Attempt {
+                    day: int("day").filter(|d| (1..=25).contains(d))? as u32,
+                    part: match int("part")? {
+                        1 => Part::One,
+                        2 => Part::Two,
+                        _ => return None,
+                    },
+                    answer: string("answer")?.to_string(),
+                    verdict: string("verdict")?.parse().ok()?,
+                    timestamp: int("timestamp").filter(|t| *t >= 0)? as u64,
+                })
+            })();
+            attempt.ok_or_else(|| malformed_attempt(data, i))
+        })
+        .collect()
+}
+
+/// Report the header of the `index`-th attempt in the ledger `data` as malformed.
+fn malformed_attempt(data: &str, index: usize) -> AocError {
+    let reason = "expected an `[[attempt]]` with a day, part, answer, verdict and timestamp";
+    match data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.trim() == "[[attempt]]")
+        .nth(index)
+    {
+        Some((i, line)) => AocError::malformed(i + 1, line, reason),
+        None => AocError::unexpected_end(data, reason),
+    }
+}
+
+/// The outcome of trying to submit an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Submission {
+    /// The answer was sent to the server, which responded with the verdict.
+    Sent(Verdict),
+    /// The answer was not sent, since the ledger already shows the verdict.
+    Refused(Verdict),
+}
+
+/// Submits answers to an [`AnswerEndpoint`] and records them in a [`Ledger`].
+#[derive(Debug)]
+pub struct Submitter<E> {
+    endpoint: E,
+    ledger: Ledger,
+}
+
+impl<E: AnswerEndpoint> Submitter<E> {
+    /// A submitter sending answers to `endpoint` and recording them in `ledger`.
+    pub fn new(endpoint: E, ledger: Ledger) -> Self {
+        Self { endpoint, ledger }
+    }
+
+    /// The ledger the attempts are recorded in.
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    /// Submit `answer` to `part` of `day`, unless the ledger already shows
+    /// whether it is right or wrong.
+    pub fn submit(&mut self, day: u32, part: Part, answer: &str) -> Result<Submission> {
+        let answer = answer.trim();
+        if answer.is_empty() {
+            return Err(AocError::remote("refusing to submit an empty answer"));
+        }
+        if let Some(verdict) = self.ledger.known_verdict(day, part, answer) {
+            return Ok(Submission::Refused(verdict));
+        }
+
+        let verdict = self.endpoint.submit(day, part, answer)?;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |t| t.as_secs());
+        self.ledger.record(Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            timestamp,
+        })?;
+        Ok(Submission::Sent(verdict))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_from_response() {
+        let page = |text| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Verdict::Right
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_response(&page("That's not the right answer. If you're stuck, ...")),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )),
+            Verdict::RateLimited(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Verdict::from_response(&page("You gave an answer too recently.")),
+            Verdict::RateLimited(None)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(Verdict::from_response("<html>"), Verdict::Unrecognized);
+    }
+
+    fn ledger_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc2020-{}-{}.toml", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_ledger() {
+        let path = ledger_path("ledger");
+        let mut ledger = Ledger::open(&path).unwrap();
+        assert!(ledger.attempts().is_empty());
+        let attempt = |answer: &str, verdict| Attempt {
+            day: 3,
+            part: Part::Two,
+            answer: answer.to_string(),
+            verdict,
+            timestamp: 1606798800,
+        };
+        ledger.record(attempt("100", Verdict::TooHigh)).unwrap();
+        ledger.record(attempt("10", Verdict::TooLow)).unwrap();
+        ledger.record(attempt("\"a\"", Verdict::Wrong)).unwrap();
+        ledger
+            .record(attempt("50", Verdict::RateLimited(None)))
+            .unwrap();
+
+        let ledger = Ledger::open(&path).unwrap();
+        assert_eq!(ledger.attempts().len(), 4);
+        assert_eq!(ledger.attempts()[2].answer, "\"a\"");
+        assert_eq!(
+            ledger.known_verdict(3, Part::Two, "100"),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            ledger.known_verdict(3, Part::Two, "120"),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            ledger.known_verdict(3, Part::Two, "7"),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            ledger.known_verdict(3, Part::Two, "\"a\""),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(ledger.known_verdict(3, Part::Two, "50"), None);
+        assert_eq!(ledger.known_verdict(3, Part::One, "100"), None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_ledger_malformed() {
+        let line_of = |data: &str| match parse_attempts(data) {
+            Err(AocError::MalformedLine(err)) => err.line,
+            res => panic!("unexpected result {:?}", res),
+        };
+        let valid =
+            "[[attempt]]\nday = 1\npart = 1\nanswer = \"1\"\nverdict = \"right\"\ntimestamp = 0\n";
+        assert_eq!(parse_attempts(valid).unwrap().len(), 1);
+        assert_eq!(
+            line_of(&format!("{}\n{}", valid, valid.replace("right", "meh"))),
+            8
+        );
+        assert_eq!(line_of(&valid.replace("part = 1", "part = 3")), 1);
+        assert_eq!(line_of("attempt = 1"), 1);
+    }
+
+    /// An endpoint answering with the given verdicts and recording the answers.
+    struct Mock {
+        verdicts: RefCell<Vec<Verdict>>,
+        answers: RefCell<Vec<String>>,
+    }
+
+    impl AnswerEndpoint for &Mock {
+        fn submit(&self, _: u32, _: Part, answer: &str) -> Result<Verdict> {
+            self.answers.borrow_mut().push(answer.to_string());
+            Ok(self.verdicts.borrow_mut().remove(0))
+        }
+    }
+
+    #[test]
+    fn test_submitter() {
+        let path = ledger_path("submitter");
+        let mock = Mock {
+            verdicts: RefCell::new(vec![Verdict::TooLow, Verdict::Right]),
+            answers: RefCell::new(vec![]),
+        };
+        let mut submitter = Submitter::new(&mock, Ledger::open(&path).unwrap());
+
+        assert_eq!(
+            submitter.submit(1, Part::One, "5").unwrap(),
+            Submission::Sent(Verdict::TooLow)
+        );
+        assert_eq!(
+            submitter.submit(1, Part::One, "4").unwrap(),
+            Submission::Refused(Verdict::TooLow)
+        );
+        assert_eq!(
+            submitter.submit(1, Part::One, "6").unwrap(),
+            Submission::Sent(Verdict::Right)
+        );
+        assert_eq!(
+            submitter.submit(1, Part::One, "6").unwrap(),
+            Submission::Refused(Verdict::Right)
+        );
+        assert_eq!(
+            submitter.submit(1, Part::One, "7").unwrap(),
+            Submission::Refused(Verdict::Wrong)
+        );
+        assert!(submitter.submit(1, Part::One, " ").is_err());
+        assert_eq!(*mock.answers.borrow(), vec!["5", "6"]);
+        assert_eq!(Ledger::open(&path).unwrap().attempts().len(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,300 @@
+//! This module contains the tracing of the steps a solution takes.
+//!
+//! Solutions report what they do as structured [`Event`]s with the
+//! [`trace!`](crate::trace!) macro, e.g. which field of a ticket was
+//! identified in which round. The events go to the [`Sink`] installed on the
+//! current thread by [`with_sink`], and are dropped if there is none. Unless a
+//! sink is installed anywhere, tracing costs a single atomic load: the
+//! values of the event are not even evaluated.
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Report an event named `$name` with the given fields to the sink of the
+/// current thread, if there is one. Field values can be anything serializable.
+///
+/// ```
+/// # use aoc_core::trace;
+/// let column = 3;
+/// aoc_core::trace!("identify", field = "row", column = column);
+/// ```
+#[macro_export]
+macro_rules! trace {
+    ($name:expr $(, $field:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit($crate::trace::Event::new(
+                $name,
+                vec![$((stringify!($field), $crate::trace::value(&$value))),*],
+            ));
+        }
+    };
+}
+
+/// A step of a solution, consisting of its name and the values describing it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+impl Event {
+    /// An event with the given `name` and `fields`.
+    pub fn new(name: &'static str, fields: Vec<(&'static str, Value)>) -> Self {
+        Self { name, fields }
+    }
+
+    /// The value of the field `name`, if the event has one.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields.iter().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+
+    /// The event as JSON object, with its name stored as `event`.
+    pub fn to_json(&self) -> Value {
+        let mut object = Map::new();
+        object.insert("event".to_string(), Value::from(self.name));
+        for (name, value) in &self.fields {
+            object.insert(name.to_string(), value.clone());
+        }
+        Value::Object(object)
+    }
+}
+
+impl Display for Event {
+    /// The name of the event followed by its fields as `name=value`, where
+    /// strings are not quoted.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for (name, value) in &self.fields {
+            match value {
+                Value::String(s) => write!(f, " {}={}", name, s)?,
+                value => write!(f, " {}={}", name, value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Convert a field value of an [`Event`], which is `null` if it cannot be
+/// represented as JSON.
+pub fn value<T: serde::Serialize + ?Sized>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// The receiver of the events of a solution.
+pub trait Sink {
+    fn event(&mut self, event: Event);
+}
+
+/// Collects the events.
+impl Sink for Vec<Event> {
+    fn event(&mut self, event: Event) {
+        self.push(event);
+    }
+}
+
+/// The formats in which a [`Writer`] writes the events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One line per event as shown by its [`Display`] implementation.
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown format `{}`, expected `text` or `json`", s)),
+        }
+    }
+}
+
+/// Writes the events line by line as they happen.
+///
+/// Since a sink cannot fail, the first error is kept and later events are
+/// dropped.
+pub struct Writer<W> {
+    writer: W,
+    format: Format,
+    written: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> Writer<W> {
+    /// A sink writing the events to `writer` in the given `format`.
+    pub fn new(writer: W, format: Format) -> Self {
+        Self {
+            writer,
+            format,
+            written: 0,
+            error: None,
+        }
+    }
+
+    /// Flush the writer, and return the number of events written or the
+    /// first error that occurred.
+    pub fn finish(mut self) -> io::Result<usize> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => self.writer.flush().map(|_| self.written),
+        }
+    }
+}
+
+impl<W: Write> Sink for Writer<W> {
+    fn event(&mut self, event: Event) {
+        if self.error.is_some() {
+            return;
+        }
+        let result = match self.format {
+            Format::Text => writeln!(self.writer, "{}", event),
+            Format::Json => writeln!(self.writer, "{}", event.to_json()),
+        };
+        match result {
+            Ok(()) => self.written += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+/// The number of sinks installed, on any thread.
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The sink of the current thread.
+    static SINK: RefCell<Option<Box<dyn Sink>>> = RefCell::new(None);
+}
+
+/// Whether the events reported on the current thread are received by a sink.
+#[inline]
+pub fn enabled() -> bool {
+    ACTIVE.load(Ordering::Relaxed) > 0 && SINK.with(|sink| sink.borrow().is_some())
+}
+
+/// Pass `event` to the sink of the current thread, if there is one.
+pub fn emit(event: Event) {
+    SINK.with(|sink| {
+        if let Some(sink) = sink.borrow_mut().as_mut() {
+            sink.event(event);
+        }
+    });
+}
+
+/// Run `f` with `sink` receiving the events reported on the current thread,
+/// and return the result of `f` along with the sink.
+///
+/// The previous sink of the thread, if any, is restored afterwards, even if
+/// `f` panics.
+pub fn with_sink<S: Sink + 'static, T>(sink: S, f: impl FnOnce() -> T) -> (T, S) {
+    /// Passes the events on to the sink shared with `with_sink`.
+    struct Shared<S>(Rc<RefCell<S>>);
+
+    impl<S: Sink> Sink for Shared<S> {
+        fn event(&mut self, event: Event) {
+            self.0.borrow_mut().event(event);
+        }
+    }
+
+    /// Restores the previous sink when dropped.
+    struct Guard(Option<Box<dyn Sink>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SINK.with(|sink| sink.replace(previous));
+            ACTIVE.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    let shared = Rc::new(RefCell::new(sink));
+    let previous = SINK.with(|sink| sink.replace(Some(Box::new(Shared(shared.clone())))));
+    ACTIVE.fetch_add(1, Ordering::Relaxed);
+    let guard = Guard(previous);
+    let result = f();
+    drop(guard);
+    match Rc::try_unwrap(shared) {
+        Ok(sink) => (result, sink.into_inner()),
+        Err(_) => unreachable!("the sink is only shared while it is installed"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event() {
+        let event = Event::new(
+            "identify",
+            vec![("field", value("row")), ("columns", value(&[1, 2]))],
+        );
+        assert_eq!(event.to_string(), "identify field=row columns=[1,2]");
+        assert_eq!(
+            event.to_json().to_string(),
+            r#"{"columns":[1,2],"event":"identify","field":"row"}"#
+        );
+        assert_eq!(event.get("field"), Some(&Value::from("row")));
+        assert_eq!(event.get("round"), None);
+    }
+
+    #[test]
+    fn test_with_sink() {
+        let mut evaluated = 0;
+        let mut count = || {
+            evaluated += 1;
+            evaluated
+        };
+
+        // Without a sink, the values are not evaluated.
+        crate::trace!("ignored", count = count());
+        let (answer, events) = with_sink(vec![], || {
+            crate::trace!("first", count = count());
+            let (_, inner) = with_sink(vec![], || crate::trace!("inner"));
+            assert_eq!(inner, vec![Event::new("inner", vec![])]);
+            crate::trace!("second", count = count(), text = "b");
+            42
+        });
+        crate::trace!("ignored", count = count());
+
+        assert_eq!(answer, 42);
+        assert_eq!(
+            events,
+            vec![
+                Event::new("first", vec![("count", value(&1))]),
+                Event::new("second", vec![("count", value(&2)), ("text", value("b"))]),
+            ]
+        );
+        assert!(!enabled());
+
+        // The sink is removed even if the traced code panics.
+        let result = std::panic::catch_unwind(|| with_sink(vec![], || panic!("failed")));
+        assert!(result.is_err());
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn test_writer() {
+        let (_, writer) = with_sink(Writer::new(vec![], Format::Text), || {
+            crate::trace!("round", number = 1, winner = "player 1");
+            crate::trace!("done");
+        });
+        assert_eq!(writer.writer, b"round number=1 winner=player 1\ndone\n");
+        assert_eq!(writer.finish().unwrap(), 2);
+
+        let (_, writer) = with_sink(Writer::new(vec![], Format::Json), || {
+            crate::trace!("round", number = 1);
+        });
+        assert_eq!(writer.writer, b"{\"event\":\"round\",\"number\":1}\n");
+
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_core::fetch::{Endpoint, Fetched, Fetcher, HttpEndpoint};
+use std::thread;
+use tiny_http::{Response, Server};
+
+/// Serve the inputs of the days 1 and 2 to the session `secret` on a local server,
+/// answering the given number of `requests`, and return its base URL.
+fn serve(requests: usize) -> String {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let port = server.server_addr().to_ip().unwrap().port();
+    thread::spawn(move || {
+        for request in server.incoming_requests().take(requests) {
+            let authorized = request
+                .headers()
+                .iter()
+                .any(|h| h.field.equiv("Cookie") && h.value.as_str() == "session=secret");
+            let response = match (authorized, request.url()) {
+                (false, _) => {
+                    Response::from_string("Puzzle inputs differ by user.").with_status_code(400)
+                }
+                (true, "/2020/day/1/input") => Response::from_string("1721\n979\n"),
+                (true, "/2020/day/2/input") => Response::from_string("1-3 a: abcde\n"),
+                (true, _) => Response::from_string("Not found").with_status_code(404),
+            };
+            request.respond(response).unwrap();
+        }
+    });
+    format!("http://127.0.0.1:{}/", port)
+}
+
+#[test]
+fn test_http_endpoint() {
+    let base_url = serve(3);
+    assert_eq!(
+        HttpEndpoint::new(&base_url, 2020, "secret")
+            .fetch(1)
+            .unwrap(),
+        "1721\n979\n"
+    );
+    assert!(HttpEndpoint::new(&base_url, 2020, "wrong")
+        .fetch(1)
+        .is_err());
+    assert!(HttpEndpoint::new(&base_url, 2020, "secret")
+        .fetch(3)
+        .is_err());
+}
+
+#[test]
+fn test_fetcher() {
+    let dir = std::env::temp_dir().join(format!("aoc2020-fetcher-{}", std::process::id()));
+    // The server only answers two requests, so cached days must not be requested again.
+    let fetcher = Fetcher::new(HttpEndpoint::new(&serve(2), 2020, "secret"), &dir);
+
+    assert_eq!(fetcher.fetch(1).unwrap(), Fetched::Downloaded);
+    assert_eq!(fetcher.fetch(1).unwrap(), Fetched::Cached);
+    assert_eq!(fetcher.fetch(2).unwrap(), Fetched::Downloaded);
+    assert_eq!(fetcher.fetch(2).unwrap(), Fetched::Cached);
+    assert_eq!(fetcher.missing(1..=3), vec![3]);
+    assert_eq!(
+        aoc_core::read_data::<u32, _>(fetcher.path(1)).unwrap(),
+        vec![1721, 979]
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
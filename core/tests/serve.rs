@@ -0,0 +1,98 @@
+#![cfg(feature = "server")]
+
+use aoc_core::serve::{Limits, Service};
+use aoc_core::{Day, Result, Solution, Year};
+use serde_json::Value;
+use std::thread;
+use std::time::Duration;
+use tiny_http::Server;
+
+/// Sums the numbers of the input, or multiplies them in part 2.
+struct Arithmetic;
+
+impl Solution for Arithmetic {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(data: &str) -> Result<Self::Input> {
+        aoc_core::parse_lines(data)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1> {
+        Ok(input.iter().sum())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2> {
+        if input.is_empty() {
+            thread::sleep(Duration::from_secs(2));
+        }
+        Ok(input.iter().product())
+    }
+}
+
+static DAYS: [Day; 1] = [Day::new::<Arithmetic>(1)];
+static YEAR: Year = Year::new(2020, &DAYS, "");
+
+/// Serve the solutions of [`YEAR`] on a local server and return its base URL.
+fn start() -> String {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let port = server.server_addr().to_ip().unwrap().port();
+    let limits = Limits {
+        max_input: 64,
+        timeout: Duration::from_millis(500),
+        max_solvers: 4,
+    };
+    thread::spawn(move || Service::new(&YEAR, limits).serve(&server, 2));
+    format!("http://127.0.0.1:{}", port)
+}
+
+/// Post `body` to `path` and return the status and the JSON body of the response.
+fn post(base_url: &str, path: &str, body: &str) -> (u16, Value) {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .build()
+        .into();
+    let mut response = agent
+        .post(&format!("{}{}", base_url, path))
+        .send(body)
+        .unwrap();
+    let status = response.status().as_u16();
+    let content_type = response.headers()["Content-Type"].to_str().unwrap();
+    assert_eq!(content_type, "application/json");
+    let body = response.body_mut().read_to_string().unwrap();
+    (status, serde_json::from_str(&body).unwrap())
+}
+
+#[test]
+fn test_serve() {
+    let base_url = start();
+
+    let (status, body) = post(&base_url, "/day/1/part/1", "1\n2\n3\n");
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "6");
+    assert!(body["timing"]["solve"].is_u64());
+    let (status, body) = post(&base_url, "/day/1/part/2", "2\n3\n4\n");
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "24");
+
+    let (status, body) = post(&base_url, "/day/1/part/1", "1\nx\n");
+    assert_eq!(status, 422);
+    assert!(body["error"].as_str().unwrap().contains("line 2"));
+    assert_eq!(post(&base_url, "/day/2/part/1", "1").0, 404);
+    assert_eq!(post(&base_url, "/day/1/part/1", &"1\n".repeat(100)).0, 413);
+    assert_eq!(post(&base_url, "/day/1/part/2", "").0, 503);
+}
+
+#[test]
+fn test_serve_concurrently() {
+    let base_url = start();
+    let slow = {
+        let base_url = base_url.clone();
+        thread::spawn(move || post(&base_url, "/day/1/part/2", "").0)
+    };
+    thread::sleep(Duration::from_millis(100));
+    // The second worker answers while the first one waits for the solution.
+    assert_eq!(post(&base_url, "/day/1/part/1", "5\n").1["answer"], "5");
+    assert_eq!(slow.join().unwrap(), 503);
+}
@@ -0,0 +1,110 @@
+use aoc_core::fetch::HttpEndpoint;
+use aoc_core::submit::{AnswerEndpoint, Ledger, Submission, Submitter, Verdict};
+use aoc_core::Part;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use tiny_http::{Response, Server};
+
+/// Serve a mock of the answer endpoint on a local server, which considers
+/// 42 the right answer and expects the session `secret`. Returns its base
+/// URL along with the received form bodies.
+fn serve() -> (String, Receiver<String>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let port = server.server_addr().to_ip().unwrap().port();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for mut request in server.incoming_requests() {
+            let authorized = request
+                .headers()
+                .iter()
+                .any(|h| h.field.equiv("Cookie") && h.value.as_str() == "session=secret");
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let text = match (authorized, request.url(), body.as_str()) {
+                (false, _, _) => {
+                    request.respond(Response::empty(400)).unwrap();
+                    continue;
+                }
+                (true, "/2020/day/1/answer", "level=1&answer=42") => "That's the right answer!",
+                (true, "/2020/day/1/answer", "level=1&answer=50") => {
+                    "That's not the right answer; your answer is too high."
+                }
+                (true, "/2020/day/1/answer", _) => {
+                    "You gave an answer too recently. You have 30s left to wait."
+                }
+                (true, _, _) => {
+                    request.respond(Response::empty(404)).unwrap();
+                    continue;
+                }
+            };
+            let _ = sender.send(body);
+            let page = format!(
+                "<html><main><article><p>{}</p></article></main></html>",
+                text
+            );
+            request.respond(Response::from_string(page)).unwrap();
+        }
+    });
+    (format!("http://127.0.0.1:{}", port), receiver)
+}
+
+#[test]
+fn test_http_endpoint() {
+    let (base_url, _) = serve();
+    let endpoint = HttpEndpoint::new(&base_url, 2020, "secret");
+    assert_eq!(endpoint.submit(1, Part::One, "42").unwrap(), Verdict::Right);
+    assert_eq!(
+        endpoint.submit(1, Part::One, "50").unwrap(),
+        Verdict::TooHigh
+    );
+    assert!(matches!(
+        endpoint.submit(1, Part::One, "7").unwrap(),
+        Verdict::RateLimited(Some(_))
+    ));
+    assert!(endpoint.submit(2, Part::One, "42").is_err());
+    assert!(HttpEndpoint::new(&base_url, 2020, "wrong")
+        .submit(1, Part::One, "42")
+        .is_err());
+}
+
+#[test]
+fn test_submitter() {
+    let path =
+        std::env::temp_dir().join(format!("aoc2020-submissions-{}.toml", std::process::id()));
+    let (base_url, bodies) = serve();
+    let mut submitter = Submitter::new(
+        HttpEndpoint::new(&base_url, 2020, "secret"),
+        Ledger::open(&path).unwrap(),
+    );
+
+    assert_eq!(
+        submitter.submit(1, Part::One, "50").unwrap(),
+        Submission::Sent(Verdict::TooHigh)
+    );
+    assert_eq!(
+        submitter.submit(1, Part::One, "60").unwrap(),
+        Submission::Refused(Verdict::TooHigh)
+    );
+    assert_eq!(
+        submitter.submit(1, Part::One, "42").unwrap(),
+        Submission::Sent(Verdict::Right)
+    );
+    assert_eq!(
+        submitter.submit(1, Part::One, "43").unwrap(),
+        Submission::Refused(Verdict::Wrong)
+    );
+    assert_eq!(
+        bodies.try_iter().collect::<Vec<_>>(),
+        vec!["level=1&answer=50", "level=1&answer=42"]
+    );
+
+    let ledger = Ledger::open(&path).unwrap();
+    let verdicts = ledger
+        .attempts()
+        .iter()
+        .map(|a| a.verdict)
+        .collect::<Vec<_>>();
+    assert_eq!(verdicts, vec![Verdict::TooHigh, Verdict::Right]);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -1,333 +1,1108 @@
-use aoc2020::{self, read_data};
+use aoc_core::animate::{Control, Player};
+use aoc_core::bench::{Baseline, Comparison};
+use aoc_core::fetch::{self, Fetched, Fetcher, HttpEndpoint};
+use aoc_core::profile::{self, Profile};
+use aoc_core::render::{self, Palette};
+use aoc_core::report::{self, Format, Outcome, Record};
+use aoc_core::runner::{Input, Job, Runner};
+use aoc_core::scaffold;
+use aoc_core::submit::{self, Ledger, Submission, Submitter, Verdict};
+use aoc_core::trace::{self, Writer};
+use aoc_core::{Answers, AocError, Part, Status, Year};
+use clap::{Args, Parser, Subcommand};
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
 
-fn main() {
-    print!("\nAdvent of Code 2020\n--------------------\n\n");
+/// The years whose solutions can be run.
+static YEARS: &[&Year] = &[&aoc2020::YEAR];
 
-    // Day 1
-    if let Ok(data_day_1) = read_data::<u32, _>("data/day1.txt") {
-        let day_1_task_1 = aoc2020::day_1::day_1(&data_day_1, 2, 2020);
-        println!("AOC 2020, day 1, task 1: {}", day_1_task_1);
-        assert_eq!(day_1_task_1, 270144);
+/// Compute the solutions to the Advent of Code challenges.
+#[derive(Debug, Parser)]
+#[command(name = "aoc", version, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
 
-        let day_1_task_2 = aoc2020::day_1::day_1(&data_day_1, 3, 2020);
-        println!("AOC 2020, day 1, task 2: {}", day_1_task_2);
-        assert_eq!(day_1_task_2, 261342720);
-    } else {
-        eprintln!("AOC 2020, day 1: Unable to read data for day 1. Skipping.");
-    }
+    /// The year of the challenges. Defaults to the latest year with solutions.
+    #[arg(short, long, global = true, value_parser = parse_year)]
+    year: Option<&'static Year>,
 
-    // Day 2
-    if let Ok(data_day_2) = read_data::<String, _>("data/day2.txt") {
-        let day_2_task_1 = aoc2020::day_2::task_1(&data_day_2);
-        println!("\nAOC 2020, day 2, task 1: {}", day_2_task_1);
-        assert_eq!(day_2_task_1, 528);
+    /// The day to run, either a single day (`5`) or an inclusive range (`3-7`).
+    /// All days are run if omitted.
+    #[arg(short, long, value_parser = parse_days)]
+    day: Option<(u32, u32)>,
 
-        let day_2_task_2 = aoc2020::day_2::task_2(&data_day_2);
-        println!("AOC 2020, day 2, task 2: {}", day_2_task_2);
-        assert_eq!(day_2_task_2, 497);
-    } else {
-        eprintln!("AOC 2020, day 2: Unable to read data for day 2. Skipping.");
-    }
+    /// Only compute the given part of each day.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
 
-    // Day 3
-    if let Ok(data_day_3) = read_data::<String, _>("data/day3.txt") {
-        let day_3_task_1 = aoc2020::day_3::task_1(&data_day_3, 3, 1);
-        println!("\nAOC 2020, day 3, task 1: {}", day_3_task_1);
-        assert_eq!(day_3_task_1, 156);
+    /// Read the puzzle input from this file instead of `data/day<N>.txt` of the year.
+    /// Use `-` to read from stdin. Requires a single day to be selected.
+    #[arg(short, long, value_name = "FILE", conflicts_with_all = ["profile", "all_profiles"])]
+    input: Option<String>,
 
-        let day_3_task_2 =
-            aoc2020::day_3::task_2(&data_day_3, &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]);
-        println!("AOC 2020, day 3, task 2: {}", day_3_task_2);
-        assert_eq!(day_3_task_2, 3521829480);
-    } else {
-        eprintln!("AOC 2020, day 3: Unable to read data for day 3. Skipping.");
-    }
+    /// Check the answers against this manifest instead of the one of the profile.
+    /// Without it, answers to a custom `--input` are not checked.
+    #[arg(short, long, value_name = "FILE", conflicts_with = "all_profiles")]
+    answers: Option<String>,
 
-    // Day 4
-    match std::fs::read_to_string("data/day4.txt") {
-        Ok(content) => {
-            let day_4_task_1 = aoc2020::day_4::task_1(&content);
-            println!("\nAOC 2020, day 4, task 1: {}", day_4_task_1);
-            assert_eq!(day_4_task_1, 222);
+    /// Run on the inputs of this profile, stored in `data/<NAME>/` of the year,
+    /// instead of the default profile in `data/`. Can be given multiple times.
+    #[arg(long, value_name = "NAME")]
+    profile: Vec<String>,
 
-            let day_4_task_2 = aoc2020::day_4::task_2(&content);
-            println!("AOC 2020, day 4, task 2: {}", day_4_task_2);
-            assert_eq!(day_4_task_2, 140);
-        }
-        Err(_) => eprintln!("AOC 2020, day 4: Unable to read data for day 4. Skipping"),
-    }
+    /// Run on the inputs of every profile.
+    #[arg(long, conflicts_with = "profile")]
+    all_profiles: bool,
 
-    // Day 5
-    if let Ok(data_day_5) = read_data::<String, _>("data/day5.txt") {
-        let day_5_task_1 = aoc2020::day_5::task_1(&data_day_5);
-        println!("\nAOC 2020, day 5, task 1: {}", day_5_task_1);
-        assert_eq!(day_5_task_1, 935);
+    /// Print more information, such as the input source and the elapsed time.
+    #[arg(short, long, conflicts_with_all = ["quiet", "format"])]
+    verbose: bool,
 
-        let day_5_task_2 = aoc2020::day_5::task_2(&data_day_5);
-        println!("AOC 2020, day 5, task 2: {}", day_5_task_2);
-        assert_eq!(day_5_task_2, 743);
-    } else {
-        eprintln!("AOC 2020, day 5: Unable to read data for day 5. Skipping.");
-    }
+    /// Only print the answers, one per line.
+    #[arg(short, long, conflicts_with = "format")]
+    quiet: bool,
+
+    /// Print the results as `text`, or as a `json` array or `csv` table of records
+    /// with the day, part, answer, expected answer, status and elapsed time.
+    #[arg(short, long, value_name = "FORMAT", default_value_t = Format::Text)]
+    format: Format,
+
+    /// Run each part repeatedly and report the min / median / max time spent
+    /// on parsing and on solving.
+    #[arg(short, long, conflicts_with_all = ["quiet", "all_profiles"])]
+    bench: bool,
+
+    /// The number of runs of each part in benchmark mode.
+    #[arg(long, value_name = "N", default_value_t = 10, requires = "bench",
+          value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Compare the median timings to the baseline stored in this file.
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    baseline: Option<String>,
+
+    /// Save the median timings as baseline to this file.
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    save_baseline: Option<String>,
+
+    /// The change of the total time in percent that is still considered noise
+    /// when comparing to the baseline.
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 10.0,
+        requires = "baseline"
+    )]
+    tolerance: f64,
+
+    /// The number of days to run in parallel. Defaults to the number of CPUs,
+    /// or to one in benchmark mode.
+    #[arg(short, long, value_name = "N", conflicts_with = "bench",
+          value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+
+    /// Give up on parts that do not finish within this number of seconds.
+    #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Download the puzzle inputs into a profile directory, skipping the
+    /// days that are already there, and report the days still missing.
+    Fetch(FetchArgs),
+    /// Submit an answer, unless it is already known to be wrong, and record
+    /// the verdict in the ledger of the profile.
+    Submit(SubmitArgs),
+    /// Generate the module and the integration test of a new day and
+    /// register it with the runner.
+    Scaffold(ScaffoldArgs),
+    /// Draw the state a part arrives at, e.g. the settled seats of day 11,
+    /// as `.ppm`, `.png` or `.svg` image.
+    Render(RenderArgs),
+    /// Animate the simulation of a part in the terminal, e.g. the seats of
+    /// day 11 filling up round by round.
+    Animate(AnimateArgs),
+    /// Solve a part and print the steps the solution takes, e.g. how the
+    /// fields of day 16 are identified, followed by the answer.
+    Trace(TraceArgs),
+    /// Generate a random puzzle input of a day, e.g. to stress-test its
+    /// solution, and report its answers if they are known.
+    Generate(GenerateArgs),
+}
+
+#[derive(Debug, Args)]
+struct GenerateArgs {
+    /// The day to generate the input of.
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// The size of the input, e.g. its number of lines. Some days limit it.
+    #[arg(short, long, default_value_t = 100)]
+    size: usize,
+
+    /// The seed of the random input. The same seed yields the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Write the input to this file instead of stdout.
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct TraceArgs {
+    /// The day to trace.
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// The part to trace.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Read the puzzle input from this file instead of the one of the profile.
+    /// Use `-` to read from stdin.
+    #[arg(short, long, value_name = "FILE", conflicts_with = "profile")]
+    input: Option<String>,
+
+    /// Use the input of this profile instead of the one in `data/`.
+    #[arg(long, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// Write the events to this file instead of stdout.
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Write the events as `text`, one per line, or as `json` objects, one per line.
+    #[arg(short, long, value_name = "FORMAT", default_value = "text")]
+    format: trace::Format,
+}
+
+#[derive(Debug, Args)]
+struct AnimateArgs {
+    /// The day to animate.
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// The part whose simulation to animate.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Read the puzzle input from this file instead of the one of the profile.
+    #[arg(short, long, value_name = "FILE", conflicts_with = "profile")]
+    input: Option<String>,
+
+    /// Use the input of this profile instead of the one in `data/`.
+    #[arg(long, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// The number of frames shown per second.
+    #[arg(long, value_name = "FPS", default_value_t = 10.0)]
+    fps: f64,
+
+    /// Start paused, waiting for the first step.
+    #[arg(long)]
+    paused: bool,
+
+    /// The colors to draw with, either `default`, `mono`, `dark` or a
+    /// comma-separated list `#rrggbb,...` starting with the background.
+    #[arg(long, value_name = "PALETTE", default_value = "default")]
+    palette: Palette,
+}
 
-    // Day 6
-    match std::fs::read_to_string("data/day6.txt") {
-        Ok(content) => {
-            let day_6_task_1 = aoc2020::day_6::task_1(&content);
-            println!("\nAOC 2020, day 6, task 1: {}", day_6_task_1);
-            assert_eq!(day_6_task_1, 6542);
+#[derive(Debug, Args)]
+struct RenderArgs {
+    /// The day to draw.
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
 
-            let day_6_task_2 = aoc2020::day_6::task_2(&content);
-            println!("AOC 2020, day 6, task 2: {}", day_6_task_2);
-            assert_eq!(day_6_task_2, 3299);
+    /// The part whose state to draw.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The image file to write, whose extension selects the format.
+    output: PathBuf,
+
+    /// Read the puzzle input from this file instead of the one of the profile.
+    /// Use `-` to read from stdin.
+    #[arg(short, long, value_name = "FILE", conflicts_with = "profile")]
+    input: Option<String>,
+
+    /// Use the input of this profile instead of the one in `data/`.
+    #[arg(long, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// The colors to draw with, either `default`, `mono`, `dark` or a
+    /// comma-separated list `#rrggbb,...` starting with the background.
+    #[arg(long, value_name = "PALETTE", default_value = "default")]
+    palette: Palette,
+
+    /// The length of the longer side of the image in pixels.
+    #[arg(long, value_name = "PIXELS", default_value_t = 800,
+          value_parser = clap::value_parser!(u32).range(1..=16384))]
+    size: u32,
+}
+
+#[derive(Debug, Args)]
+struct ScaffoldArgs {
+    /// The day to generate the scaffolding for.
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// The root of the crate to generate the scaffolding in. Defaults to the
+    /// crate of the year.
+    #[arg(long, value_name = "DIR")]
+    root: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct FetchArgs {
+    /// The day to fetch, either a single day (`5`) or an inclusive range (`3-7`).
+    /// All days are fetched if omitted.
+    #[arg(short, long, value_parser = parse_days)]
+    day: Option<(u32, u32)>,
+
+    /// Store the inputs in the directory of this profile instead of `data/`.
+    #[arg(long, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// The session token to authenticate with, taken from the cookie of a
+    /// logged in browser.
+    #[arg(long, value_name = "TOKEN", env = fetch::SESSION_VAR, hide_env_values = true)]
+    session: Option<String>,
+
+    /// The base URL of the server providing the inputs.
+    #[arg(long, value_name = "URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Only report the missing days without fetching them.
+    #[arg(long)]
+    check: bool,
+}
+
+#[derive(Debug, Args)]
+struct SubmitArgs {
+    /// The day to submit the answer for.
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// The part to submit the answer for.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The answer to submit. If omitted, it is computed from the input of the profile.
+    answer: Option<String>,
+
+    /// Use the input and the ledger of this profile instead of the ones in `data/`.
+    #[arg(long, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// The session token to authenticate with, taken from the cookie of a
+    /// logged in browser.
+    #[arg(long, value_name = "TOKEN", env = fetch::SESSION_VAR, hide_env_values = true)]
+    session: String,
+
+    /// The base URL of the server receiving the answers.
+    #[arg(long, value_name = "URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+/// The directory of the profile `name` of `year`, which need not exist yet.
+fn profile_dir(year: &Year, name: Option<&str>) -> Result<PathBuf, String> {
+    match name {
+        Some(name) if name != profile::DEFAULT_PROFILE => {
+            if name.is_empty() || name.contains(&['/', '\\'][..]) {
+                return Err(format!("`{}` is not a valid profile name", name));
+            }
+            Ok(year.data_dir().join(name))
         }
-        Err(_) => eprintln!("AOC 2020, day 6: Unable to read data for day 6. Skipping"),
+        _ => Ok(year.data_dir()),
     }
+}
 
-    // Day 7
-    if let Ok(data_day_7) = read_data::<String, _>("data/day7.txt") {
-        let day_7_task_1 = aoc2020::day_7::task_1(&data_day_7);
-        println!("\nAOC 2020, day 7, task 1: {}", day_7_task_1);
-        assert_eq!(day_7_task_1, 261);
+/// Generate the scaffolding of a new day, returning the exit code.
+fn run_scaffold(args: &ScaffoldArgs, year: &Year) -> i32 {
+    let root = args.root.as_deref().unwrap_or_else(|| year.dir());
+    match scaffold::generate(root, year.year, args.day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("wrote {}", path.display());
+            }
+            0
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            1
+        }
+    }
+}
 
-        let day_7_task_2 = aoc2020::day_7::task_2(&data_day_7);
-        println!("AOC 2020, day 7, task 2: {}", day_7_task_2);
-        assert_eq!(day_7_task_2, 3765);
-    } else {
-        eprintln!("AOC 2020, day 7: Unable to read data for day 7. Skipping.");
+/// The puzzle input of `day`, read from `input` if given, and from the
+/// directory of the `profile` otherwise.
+fn day_input(
+    year: &Year,
+    day: u32,
+    input: &Option<String>,
+    profile: &Option<String>,
+) -> Result<String, String> {
+    match input {
+        Some(path) => aoc_core::read_input(path).map_err(|err| err.to_string()),
+        None => {
+            let dir = profile_dir(year, profile.as_deref())?;
+            aoc_core::read_string(dir.join(format!("day{}.txt", day)))
+                .map_err(|err| err.to_string())
+        }
     }
+}
 
-    // Day 8
-    if let Ok(data_day_8) = read_data::<String, _>("data/day8.txt") {
-        let day_8_task_1 = aoc2020::day_8::task_1(&data_day_8);
-        println!("\nAOC 2020, day 8, task 1: {}", day_8_task_1);
-        assert_eq!(day_8_task_1, 2080);
+/// Animate the simulation of a part in the terminal, returning the exit code.
+fn run_animate(args: &AnimateArgs, year: &Year) -> i32 {
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let day = match year.find_day(args.day) {
+        Some(day) => day,
+        None => {
+            eprintln!("error: day {} has no solution yet", args.day);
+            return 2;
+        }
+    };
+    let data = match day_input(year, args.day, &args.input, &args.profile) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return 2;
+        }
+    };
+    let frames = match day.animate(&data, part) {
+        Ok(Some(frames)) => frames,
+        Ok(None) => {
+            eprintln!("error: day {} has nothing to animate", args.day);
+            return 2;
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return 1;
+        }
+    };
 
-        let day_8_task_2 = aoc2020::day_8::task_2(&data_day_8);
-        println!("AOC 2020, day 8, task 2: {}", day_8_task_2);
-        assert_eq!(day_8_task_2, 2477);
+    let mut player = Player::new(args.fps);
+    player.palette = args.palette.clone();
+    let interactive = io::stdin().is_terminal() && crossterm::terminal::enable_raw_mode().is_ok();
+    // Without a terminal to read keys from, the animation simply runs to its end.
+    player.paused = args.paused && interactive;
+    let result = if interactive {
+        let result = player.play(frames, read_control, io::stdout().lock());
+        let _ = crossterm::terminal::disable_raw_mode();
+        result
     } else {
-        eprintln!("AOC 2020, day 8: Unable to read data for day 8. Skipping.");
+        let wait = |timeout: Option<Duration>| {
+            thread::sleep(timeout.unwrap_or_default());
+            Ok(None)
+        };
+        player.play(frames, wait, io::stdout().lock())
+    };
+    match result {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            1
+        }
     }
+}
 
-    // Day 9
-    if let Ok(data_day_9) = read_data::<u64, _>("data/day9.txt") {
-        let day_9_task_1 = aoc2020::day_9::task_1(&data_day_9, 25);
-        println!("\nAOC 2020, day 9, task 1: {}", day_9_task_1);
-        assert_eq!(day_9_task_1, 257342611);
+/// Wait for a key controlling the animation, at most for `timeout` if given.
+fn read_control(timeout: Option<Duration>) -> io::Result<Option<Control>> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    loop {
+        if let Some(deadline) = deadline {
+            if !event::poll(deadline.saturating_duration_since(Instant::now()))? {
+                return Ok(None);
+            }
+        }
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        let control = match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Control::Quit,
+            KeyCode::Char(' ') | KeyCode::Char('p') => Control::Pause,
+            KeyCode::Char('n') | KeyCode::Right | KeyCode::Enter => Control::Step,
+            KeyCode::Char('+') | KeyCode::Up => Control::Faster,
+            KeyCode::Char('-') | KeyCode::Down => Control::Slower,
+            KeyCode::Char('q') | KeyCode::Esc => Control::Quit,
+            _ => continue,
+        };
+        return Ok(Some(control));
+    }
+}
 
-        let day_9_task_2 = aoc2020::day_9::task_2(&data_day_9, 25);
-        println!("AOC 2020, day 9, task 2: {}", day_9_task_2);
-        assert_eq!(day_9_task_2, 35602097);
-    } else {
-        eprintln!("AOC 2020, day 9: Unable to read data for day 9. Skipping.");
+/// Solve a part while writing the events of its trace, returning the exit code.
+fn run_trace(args: &TraceArgs, year: &Year) -> i32 {
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let day = match year.find_day(args.day) {
+        Some(day) => day,
+        None => {
+            eprintln!("error: day {} has no solution yet", args.day);
+            return 2;
+        }
+    };
+    let data = match day_input(year, args.day, &args.input, &args.profile) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return 2;
+        }
+    };
+    let out: Box<dyn io::Write> = match &args.output {
+        Some(path) => match std::fs::File::create(path) {
+            Ok(file) => Box::new(io::BufWriter::new(file)),
+            Err(err) => {
+                eprintln!("error: unable to create `{}`: {}", path.display(), err);
+                return 2;
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
+
+    let (answer, writer) =
+        trace::with_sink(Writer::new(out, args.format), || day.solve(&data, part));
+    let written = match writer.finish() {
+        Ok(written) => written,
+        Err(err) => {
+            eprintln!("error: unable to write the trace: {}", err);
+            return 1;
+        }
+    };
+    if let Some(path) = &args.output {
+        println!("wrote {} events to {}", written, path.display());
     }
+    match answer {
+        Ok(answer) => {
+            println!(
+                "AOC {}, day {}, task {}: {}",
+                year.year, args.day, part, answer
+            );
+            0
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            1
+        }
+    }
+}
 
-    // Day 10
-    if let Ok(data_day_10) = read_data::<u32, _>("data/day10.txt") {
-        let day_10_task_1 = aoc2020::day_10::task_1(&data_day_10);
-        println!("\nAOC 2020, day 10, task 1: {}", day_10_task_1);
-        assert_eq!(day_10_task_1, 2059);
+/// Generate a random input, returning the exit code.
+///
+/// The known answers are reported on stderr, such that the input can be
+/// piped into the solution.
+fn run_generate(args: &GenerateArgs, year: &Year) -> i32 {
+    let sample = match year
+        .find_day(args.day)
+        .map(|day| day.generate(args.size, args.seed))
+    {
+        Some(Some(sample)) => sample,
+        Some(None) => {
+            eprintln!("error: day {} cannot generate inputs", args.day);
+            return 2;
+        }
+        None => {
+            eprintln!("error: day {} has no solution yet", args.day);
+            return 2;
+        }
+    };
+    let written = match &args.output {
+        Some(path) => std::fs::write(path, &sample.data),
+        None => io::Write::write_all(&mut io::stdout(), sample.data.as_bytes()),
+    };
+    if let Err(err) = written {
+        eprintln!("error: unable to write the input: {}", err);
+        return 1;
+    }
+    for part in [Part::One, Part::Two] {
+        if let Some(answer) = sample.answer(part) {
+            eprintln!(
+                "AOC {}, day {}, task {}: {}",
+                year.year, args.day, part, answer
+            );
+        }
+    }
+    0
+}
 
-        let day_10_task_2 = aoc2020::day_10::task_2(&data_day_10);
-        println!("AOC 2020, day 10, task 2: {}", day_10_task_2);
-        assert_eq!(day_10_task_2, 86812553324672);
-    } else {
-        eprintln!("AOC 2020, day 10: Unable to read data for day 10. Skipping.");
+/// Draw the state of a part to an image file, returning the exit code.
+fn run_render(args: &RenderArgs, year: &Year) -> i32 {
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let format = match render::Format::from_path(&args.output) {
+        Some(format) => format,
+        None => {
+            eprintln!(
+                "error: unknown image format of `{}`, expected `.ppm`, `.png` or `.svg`",
+                args.output.display()
+            );
+            return 2;
+        }
+    };
+    let day = match year.find_day(args.day) {
+        Some(day) => day,
+        None => {
+            eprintln!("error: day {} has no solution yet", args.day);
+            return 2;
+        }
+    };
+    let data = match day_input(year, args.day, &args.input, &args.profile) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return 2;
+        }
+    };
+    let scene = match day.render(&data, part) {
+        Ok(Some(scene)) => scene,
+        Ok(None) => {
+            eprintln!("error: day {} has nothing to draw", args.day);
+            return 2;
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return 1;
+        }
+    };
+    match render::save(&scene, &args.palette, args.size, format, &args.output) {
+        Ok(()) => {
+            println!("wrote {}", args.output.display());
+            0
+        }
+        Err(err) => {
+            eprintln!(
+                "error: unable to write `{}`: {}",
+                args.output.display(),
+                err
+            );
+            1
+        }
     }
+}
 
-    // Day 11
-    match std::fs::read_to_string("data/day11.txt") {
-        Ok(content) => {
-            let day_11_task_1 =
-                aoc2020::day_11::task_1_2(&content, &aoc2020::day_11::Strategy::Adjacent, 4);
-            println!("\nAOC 2020, day 11, task 1: {}", day_11_task_1);
-            assert_eq!(day_11_task_1, 2361);
+/// Submit an answer, returning the exit code.
+fn run_submit(args: &SubmitArgs, year: &Year) -> i32 {
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let dir = match profile_dir(year, args.profile.as_deref()) {
+        Ok(dir) => dir,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return 2;
+        }
+    };
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let day = match year.find_day(args.day) {
+                Some(day) => day,
+                None => {
+                    eprintln!("error: day {} has no solution yet", args.day);
+                    return 2;
+                }
+            };
+            let path = dir.join(format!("day{}.txt", args.day));
+            match aoc_core::read_string(&path).and_then(|data| day.solve(&data, part)) {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!("error: unable to compute the answer: {}", err);
+                    return 2;
+                }
+            }
+        }
+    };
+    let ledger = match Ledger::open(dir.join(submit::LEDGER_FILE)) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            eprintln!("error: unable to open the ledger: {}", err);
+            return 2;
+        }
+    };
 
-            let day_11_task_2 =
-                aoc2020::day_11::task_1_2(&content, &aoc2020::day_11::Strategy::Visible, 5);
-            println!("AOC 2020, day 11, task 2: {}", day_11_task_2);
-            assert_eq!(day_11_task_2, 2119);
+    let endpoint = HttpEndpoint::new(&args.base_url, year.year, &args.session);
+    let mut submitter = Submitter::new(endpoint, ledger);
+    let prefix = format!(
+        "AOC {}, day {}, task {}: {}",
+        year.year, args.day, part, answer
+    );
+    match submitter.submit(args.day, part, &answer) {
+        Ok(Submission::Sent(Verdict::Right)) => {
+            println!("{} is right", prefix);
+            0
+        }
+        Ok(Submission::Sent(Verdict::RateLimited(wait))) => {
+            match wait {
+                Some(wait) => println!("{} was not checked, wait {:?}", prefix, wait),
+                None => println!("{} was not checked, wait a moment", prefix),
+            }
+            1
+        }
+        Ok(Submission::Sent(verdict)) => {
+            println!("{} is {}", prefix, verdict);
+            1
+        }
+        Ok(Submission::Refused(verdict)) => {
+            println!(
+                "{} is not submitted, since {} already shows it to be {}",
+                prefix,
+                submitter.ledger().path().display(),
+                verdict
+            );
+            if verdict == Verdict::Right {
+                0
+            } else {
+                1
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            2
         }
-        Err(_) => eprintln!("AOC 2020, day 11: Unable to read data for day 11. Skipping."),
     }
+}
 
-    // Day 12
-    if let Ok(data_day_12) = read_data::<String, _>("data/day12.txt") {
-        let day_12_task_1 = aoc2020::day_12::task_1(&data_day_12);
-        println!("\nAOC 2020, day 12, task 1: {}", day_12_task_1);
-        assert_eq!(day_12_task_1, 1482);
+/// Fetch the selected puzzle inputs, returning the exit code.
+fn run_fetch(args: &FetchArgs, year: &Year) -> i32 {
+    let (first, last) = args.day.unwrap_or((1, 25));
+    let dir = match profile_dir(year, args.profile.as_deref()) {
+        Ok(dir) => dir,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return 2;
+        }
+    };
+    let session = match (&args.session, args.check) {
+        (Some(session), _) => session.clone(),
+        (None, true) => String::new(),
+        (None, false) => {
+            eprintln!(
+                "error: a session token is required, pass it with `--session` or set `{}`",
+                fetch::SESSION_VAR
+            );
+            return 2;
+        }
+    };
+    let fetcher = Fetcher::new(HttpEndpoint::new(&args.base_url, year.year, &session), dir);
+
+    if !args.check {
+        for day in first..=last {
+            match fetcher.fetch(day) {
+                Ok(Fetched::Cached) => println!("day {}: cached", day),
+                Ok(Fetched::Downloaded) => {
+                    println!("day {}: fetched to {}", day, fetcher.path(day).display())
+                }
+                Err(err) => eprintln!("day {}: {}", day, err),
+            }
+        }
+    }
 
-        let day_12_task_2 = aoc2020::day_12::task_2(&data_day_12);
-        println!("AOC 2020, day 12, task 2: {}", day_12_task_2);
-        assert_eq!(day_12_task_2, 48739);
+    let missing = fetcher.missing(first..=last);
+    if missing.is_empty() {
+        println!("All inputs are in {}", fetcher.dir().display());
+        0
     } else {
-        eprintln!("AOC 2020, day 8: Unable to read data for day 8. Skipping.");
+        let days = missing.iter().map(u32::to_string).collect::<Vec<_>>();
+        println!(
+            "Missing inputs in {}: days {}",
+            fetcher.dir().display(),
+            days.join(", ")
+        );
+        1
     }
+}
 
-    // Day 13
-    match std::fs::read_to_string("data/day13.txt") {
-        Ok(content) => {
-            let day_13_task_1 = aoc2020::day_13::task_1(&content);
-            println!("\nAOC 2020, day 13, task 1: {}", day_13_task_1);
-            assert_eq!(day_13_task_1, 5257);
+/// Look up the year selected on the command line among the [`YEARS`].
+fn parse_year(year: &str) -> Result<&'static Year, String> {
+    let known = || {
+        let years = YEARS.iter().map(|y| y.year.to_string()).collect::<Vec<_>>();
+        years.join(", ")
+    };
+    match year.trim().parse::<u32>() {
+        Ok(year) => YEARS
+            .iter()
+            .copied()
+            .find(|y| y.year == year)
+            .ok_or_else(|| format!("there are no solutions for {}, only for {}", year, known())),
+        Err(_) => Err(format!(
+            "`{}` is not a year, expected one of {}",
+            year,
+            known()
+        )),
+    }
+}
 
-            let day_13_task_2 = aoc2020::day_13::task_2(&content);
-            println!("AOC 2020, day 13, task 2: {}", day_13_task_2);
-            assert_eq!(day_13_task_2, 538703333547789);
+/// Parse the day selection from its string representation on the command line.
+fn parse_days(days: &str) -> Result<(u32, u32), String> {
+    let parse_day = |d: &str| match d.trim().parse::<u32>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("`{}` is not a day between 1 and 25", d)),
+    };
+    match days.split_once('-') {
+        Some((first, last)) => {
+            let (first, last) = (parse_day(first)?, parse_day(last)?);
+            if first > last {
+                return Err(format!("`{}` is an empty range of days", days));
+            }
+            Ok((first, last))
         }
-        Err(_) => eprintln!("AOC 2020, day 13: Unable to read data for day 13. Skipping."),
+        None => parse_day(days).map(|day| (day, day)),
     }
+}
 
-    // Day 14
-    if let Ok(data_day_14) = read_data::<String, _>("data/day14.txt") {
-        let day_14_task_1 = aoc2020::day_14::task_1(&data_day_14);
-        println!("\nAOC 2020, day 14, task 1: {}", day_14_task_1);
-        assert_eq!(day_14_task_1, 11884151942312);
+/// Parse the timeout from its number of seconds on the command line.
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    match seconds
+        .trim()
+        .parse::<f64>()
+        .map(Duration::try_from_secs_f64)
+    {
+        Ok(Ok(timeout)) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!(
+            "`{}` is not a timeout, expected a positive number of seconds",
+            seconds
+        )),
+    }
+}
 
-        let day_14_task_2 = aoc2020::day_14::task_2(&data_day_14);
-        println!("AOC 2020, day 14, task 2: {}", day_14_task_2);
-        assert_eq!(day_14_task_2, 2625449018811);
+/// The selected profiles, or none if a custom `--input` is used.
+fn select_profiles(cli: &Cli, year: &Year) -> aoc_core::Result<Vec<Profile>> {
+    if cli.input.is_some() {
+        Ok(vec![])
+    } else if cli.all_profiles {
+        Profile::discover(year.data_dir())
+    } else if cli.profile.is_empty() {
+        Ok(vec![Profile::named(
+            year.data_dir(),
+            profile::DEFAULT_PROFILE,
+        )?])
     } else {
-        eprintln!("AOC 2020, day 14: Unable to read data for day 14. Skipping.");
+        cli.profile
+            .iter()
+            .map(|name| Profile::named(year.data_dir(), name))
+            .collect()
     }
+}
 
-    // Day 15
-    let data_day_15 = [18, 11, 9, 0, 5, 1];
-
-    let day_15_task_1 = aoc2020::day_15::task_1_2(&data_day_15, 2020);
-    println!("\nAOC 2020, day 15, task 1: {}", day_15_task_1);
-    assert_eq!(day_15_task_1, 959);
+/// Load the manifest of known answers the answers for `profile` are checked against.
+///
+/// A profile without a manifest, as well as a custom `--input`, means that no
+/// answers are known.
+fn load_answers(cli: &Cli, profile: Option<&Profile>) -> aoc_core::Result<Answers> {
+    match (&cli.answers, profile) {
+        (Some(path), _) => Answers::read(path),
+        (None, Some(profile)) => profile.answers(),
+        (None, None) => Ok(Answers::default()),
+    }
+}
 
-    let day_15_task_2 = aoc2020::day_15::task_1_2(&data_day_15, 30000000);
-    println!("AOC 2020, day 15, task 2: {}", day_15_task_2);
-    assert_eq!(day_15_task_2, 116590);
+/// A job along with what is needed to report its result.
+struct Entry<'a> {
+    profile: Option<&'a Profile>,
+    answers: &'a Answers,
+    path: String,
+}
 
-    // Day 16
-    if let Ok(data_day_16) = read_data::<String, _>("data/day16.txt") {
-        let day_16_task_1 = aoc2020::day_16::task_1(&data_day_16);
-        println!("\nAOC 2020, day 16, task 1: {}", day_16_task_1);
-        assert_eq!(day_16_task_1, 23954);
+/// Print the table of the outcomes of all jobs, with one row per day and profile.
+fn print_summary(rows: &[(Option<&Profile>, u32, [String; 2])], with_profiles: bool) {
+    println!("\nSummary\n-------");
+    let profile_width = rows
+        .iter()
+        .filter_map(|(profile, _, _)| profile.map(|p| p.name().len()))
+        .max()
+        .unwrap_or(0)
+        .max(7);
+    if with_profiles {
+        print!("{:<1$}  ", "profile", profile_width);
+    }
+    println!("{:>3}  {:<24}  task 2", "day", "task 1");
+    for (profile, day, cells) in rows {
+        if with_profiles {
+            print!("{:<1$}  ", profile.map_or("", |p| p.name()), profile_width);
+        }
+        println!("{:>3}  {:<24}  {}", day, cells[0], cells[1]);
+    }
+}
 
-        let day_16_task_2 = aoc2020::day_16::task_2(&data_day_16);
-        println!("AOC 2020, day 16, task 2: {}", day_16_task_2);
-        assert_eq!(day_16_task_2, 453459307723);
-    } else {
-        eprintln!("AOC 2020, day 16: Unable to read data for day 16. Skipping.");
+fn main() {
+    let cli = Cli::parse();
+    let year = cli
+        .year
+        .unwrap_or_else(|| YEARS.iter().max_by_key(|y| y.year).unwrap());
+    match &cli.command {
+        Some(Command::Fetch(args)) => std::process::exit(run_fetch(args, year)),
+        Some(Command::Submit(args)) => std::process::exit(run_submit(args, year)),
+        Some(Command::Scaffold(args)) => std::process::exit(run_scaffold(args, year)),
+        Some(Command::Render(args)) => std::process::exit(run_render(args, year)),
+        Some(Command::Animate(args)) => std::process::exit(run_animate(args, year)),
+        Some(Command::Trace(args)) => std::process::exit(run_trace(args, year)),
+        Some(Command::Generate(args)) => std::process::exit(run_generate(args, year)),
+        None => {}
+    }
+    let (first, last) = cli.day.unwrap_or((1, 25));
+    if cli.input.is_some() && first != last {
+        eprintln!("error: `--input` requires a single day to be selected with `--day`");
+        std::process::exit(2);
+    }
+    let profiles = match select_profiles(&cli, year) {
+        Ok(profiles) => profiles,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(2);
+        }
+    };
+    if cli.bench && profiles.len() > 1 {
+        eprintln!("error: `--bench` requires a single profile");
+        std::process::exit(2);
     }
+    let baseline = match cli.baseline.as_ref().map(Baseline::read).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("error: unable to load the baseline: {}", err);
+            std::process::exit(2);
+        }
+    };
+    let runs = if profiles.is_empty() {
+        vec![None]
+    } else {
+        profiles.iter().map(Some).collect()
+    };
+    let parts = match cli.part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => vec![Part::One, Part::Two],
+    };
 
-    // Day 17
-    if let Ok(data_day_17) = std::fs::read_to_string("data/day17.txt") {
-        let day_17_task_1 = aoc2020::day_17::task_1(&data_day_17, 6);
-        println!("\nAOC 2020, day 17, task 1: {}", day_17_task_1);
-        assert_eq!(day_17_task_1, 252);
+    let text = cli.format == Format::Text;
+    let headers = text && !cli.quiet;
+    if headers {
+        print!("\nAdvent of Code {}\n--------------------\n", year.year);
+    }
 
-        let day_17_task_2 = aoc2020::day_17::task_2(&data_day_17, 6);
-        println!("AOC 2020, day 17, task 2: {}", day_17_task_2);
-        assert_eq!(day_17_task_2, 2160);
-    } else {
-        eprintln!("AOC 2020, day 17: Unable to read data for day 17. Skipping.");
+    let mut answers = vec![];
+    for profile in &runs {
+        match load_answers(&cli, *profile) {
+            Ok(known) => answers.push(known),
+            Err(err) => {
+                eprintln!("error: unable to load the known answers: {}", err);
+                std::process::exit(2);
+            }
+        }
     }
 
-    // Day 18
-    if let Ok(data_day_18) = read_data::<String, _>("data/day18.txt") {
-        let day_18_task_1 = aoc2020::day_18::task_1(&data_day_18);
-        println!("\nAOC 2020, day 18, task 1: {}", day_18_task_1);
-        assert_eq!(day_18_task_1, 36382392389406);
+    let (mut jobs, mut entries) = (vec![], vec![]);
+    for (profile, answers) in runs.iter().zip(&answers) {
+        for day in year
+            .days()
+            .iter()
+            .filter(|d| d.day >= first && d.day <= last)
+        {
+            let path = match (profile, &cli.input) {
+                (Some(profile), _) => profile.input_path(day.day).display().to_string(),
+                (None, input) => input
+                    .clone()
+                    .unwrap_or_else(|| year.dir().join(day.input_path()).display().to_string()),
+            };
+            // Benchmarks need the parsing timed on its own, and stdin can
+            // only be streamed to a single part.
+            let stream = !cli.bench
+                && parts.iter().all(|&part| day.streams(part))
+                && (path != "-" || parts.len() == 1);
+            let input = if stream {
+                let opened = match path.as_str() {
+                    "-" => Ok(()),
+                    path => std::fs::File::open(path).map(drop),
+                };
+                opened
+                    .map(|()| Input::Stream(PathBuf::from(&path)))
+                    .map_err(AocError::from)
+            } else {
+                aoc_core::read_input(&path).map(|content| Input::Data(content.into()))
+            };
+            let input = match input {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!(
+                        "AOC {0}, day {1}: Unable to read data for day {1} ({2}). Skipping.",
+                        year.year, day.day, err
+                    );
+                    continue;
+                }
+            };
+            for &part in &parts {
+                jobs.push(Job {
+                    day,
+                    part,
+                    input: input.clone(),
+                });
+                entries.push(Entry {
+                    profile: *profile,
+                    answers,
+                    path: path.clone(),
+                });
+            }
+        }
+    }
 
-        let day_18_task_2 = aoc2020::day_18::task_2(&data_day_18);
-        println!("AOC 2020, day 18, task 2: {}", day_18_task_2);
-        assert_eq!(day_18_task_2, 381107029777968);
-    } else {
-        eprintln!("AOC 2020, day 18: Unable to read data for day 18. Skipping.");
+    let threads = match (cli.bench, cli.jobs) {
+        (true, _) => 1,
+        (false, Some(jobs)) => jobs as usize,
+        (false, None) => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let mut runner = Runner::new(threads);
+    if let Some(timeout) = cli.timeout {
+        runner = runner.timeout(timeout);
+    }
+    if cli.bench {
+        runner = runner.runs(cli.runs as usize);
     }
 
-    // Day 19
-    if let Ok(data_day_19) = std::fs::read_to_string("data/day19.txt") {
-        let day_19_task_1 = aoc2020::day_19::task_1(&data_day_19);
-        println!("\nAOC 2020, day 19, task 1: {}", day_19_task_1);
-        assert_eq!(day_19_task_1, 176);
+    let (mut passed, mut failed, mut unknown, mut errors) = (0, 0, 0, 0);
+    let (mut measured, mut regressions) = (Baseline::default(), 0);
+    let mut records = vec![];
+    let mut rows: Vec<(Option<&Profile>, u32, [String; 2])> = vec![];
+    let days = jobs
+        .iter()
+        .map(|job| (job.day.day, job.part))
+        .collect::<Vec<_>>();
+    runner.run(jobs, |index, result| {
+        let (day, part) = days[index];
+        let entry = &entries[index];
+        let attribute = |record: Record| match entry.profile {
+            Some(profile) => record.with_profile(profile),
+            None => record,
+        };
 
-        println!("AOC 2020, day 19, task 2: To be done");
-    // let day_19_task_2 = aoc2020::day_19::task_2(&data_day_19);
-    // println!("AOC 2020, day 19, task 2: {}", day_19_task_2);
-    // assert_eq!(day_19_task_2, );
-    } else {
-        eprintln!("AOC 2020, day 19: Unable to read data for day 19. Skipping.");
-    }
-
-    // Day 20
-    println!("\nAOC 2020, day 20, task 1: To be done");
-    println!("AOC 2020, day 20, task 2: To be done");
-    // if let Ok(data_day_20) = std::fs::read_to_string("data/day20.txt") {
-    // let day_20_task_1 = aoc2020::day_20::task_1(&data_day_20, 10);
-    // println!("\nAOC 2020, day 20, task 1: {}", day_20_task_1);
-    // assert_eq!(day_20_task_1, );
-
-    // let day_20_task_2 = aoc2020::day_20::task_2(&data_day_20);
-    // println!("AOC 2020, day 20, task 2: {}", day_20_task_2);
-    // assert_eq!(day_20_task_2, );
-    // } else {
-    //     eprintln!("AOC 2020, day 20: Unable to read data for day 20. Skipping.");
-    // }
-
-    // Day 21
-    if let Ok(data_day_21) = read_data::<String, _>("data/day21.txt") {
-        let day_21_task_1 = aoc2020::day_21::task_1(&data_day_21);
-        println!("\nAOC 2020, day 21, task 1: {}", day_21_task_1);
-        assert_eq!(day_21_task_1, 1930);
-
-        let day_21_task_2 = aoc2020::day_21::task_2(&data_day_21);
-        println!("AOC 2020, day 21, task 2: {}", day_21_task_2);
-        assert_eq!(
-            day_21_task_2,
-            "spcqmzfg,rpf,dzqlq,pflk,bltrbvz,xbdh,spql,bltzkxx"
-        );
-    } else {
-        eprintln!("AOC 2020, day 21: Unable to read data for day 21. Skipping.");
-    }
+        let new_profile = rows.last().is_none_or(|row| row.0 != entry.profile);
+        let new_day = new_profile || rows.last().is_none_or(|row| row.1 != day);
+        if new_day {
+            if let Some(profile) = entry.profile.filter(|_| new_profile && runs.len() > 1) {
+                if headers {
+                    println!(
+                        "\nProfile {}\n{}",
+                        profile,
+                        "-".repeat(8 + profile.name().len())
+                    );
+                }
+            }
+            if headers {
+                println!();
+            }
+            if cli.verbose {
+                println!("AOC {}, day {}, input: {}", year.year, day, entry.path);
+            }
+            rows.push((entry.profile, day, [String::new(), String::new()]));
+        }
+        let cell = &mut rows.last_mut().unwrap().2[match part {
+            Part::One => 0,
+            Part::Two => 1,
+        }];
 
-    // Day 22
-    if let Ok(data_day_22) = std::fs::read_to_string("data/day22.txt") {
-        let day_22_task_1 = aoc2020::day_22::task_1(&data_day_22);
-        println!("\nAOC 2020, day 22, task 1: {}", day_22_task_1);
-        assert_eq!(day_22_task_1, 31809);
+        let expected = entry.answers.expected(day, part);
+        let measurement = match result {
+            Ok(measurement) => measurement,
+            Err(failure) => {
+                eprintln!("AOC {}, day {}, task {}: {}", year.year, day, part, failure);
+                errors += 1;
+                *cell = Outcome::from(&failure).to_string();
+                records.push(attribute(Record::failure(day, part, expected, &failure)));
+                return;
+            }
+        };
+        let answer = &measurement.answer;
+        let elapsed = measurement.median().total();
 
-        let day_22_task_2 = aoc2020::day_22::task_2(&data_day_22);
-        println!("AOC 2020, day 22, task 2: {}", day_22_task_2);
-        assert_eq!(day_22_task_2, 32835);
-    } else {
-        eprintln!("AOC 2020, day 22: Unable to read data for day 22. Skipping.");
-    }
+        let status = entry.answers.check(day, part, answer);
+        let verdict = match status {
+            Status::Pass => {
+                passed += 1;
+                status.to_string()
+            }
+            Status::Fail => {
+                failed += 1;
+                format!("{}, expected {}", status, expected.unwrap_or_default())
+            }
+            Status::Unknown => {
+                unknown += 1;
+                status.to_string()
+            }
+        };
+        *cell = format!("{} ({:.1?})", status, elapsed);
+        records.push(attribute(Record::new(
+            day,
+            part,
+            answer.clone(),
+            expected,
+            status,
+            elapsed,
+        )));
 
-    // Day 23
-    let day_23_task_1 = aoc2020::day_23::task_1(&[5, 2, 3, 7, 6, 4, 8, 1, 9], 100);
-    println!("\nAOC 2020, day 23, task 1: {}", day_23_task_1);
-    assert_eq!(day_23_task_1, 49576328);
+        if cli.quiet {
+            println!("{}", answer);
+        } else if cli.verbose {
+            println!(
+                "AOC {}, day {}, task {}: {} [{}] ({:.3?})",
+                year.year, day, part, answer, verdict, elapsed
+            );
+        } else if text {
+            println!(
+                "AOC {}, day {}, task {}: {} [{}]",
+                year.year, day, part, answer, verdict
+            );
+        }
 
-    let day_23_task_2 = aoc2020::day_23::task_2(&[5, 2, 3, 7, 6, 4, 8, 1, 9], 10_000_000);
-    println!("AOC 2020, day 23, task 2: {}", day_23_task_2);
-    assert_eq!(day_23_task_2, 511780369955);
+        if cli.bench {
+            let median = measurement.median();
+            let comparison = baseline
+                .as_ref()
+                .map(|b| b.compare(day, part, median, cli.tolerance));
+            if let Some(Comparison::Regressed(_)) = comparison {
+                regressions += 1;
+            }
+            if text {
+                println!(
+                    "    parse {}, solve {} (min / median / max){}",
+                    measurement.parse,
+                    measurement.solve,
+                    comparison.map_or_else(String::new, |c| format!(" [{}]", c))
+                );
+            }
+            measured.insert(day, part, median);
+        }
+    });
 
-    // Day 24
-    if let Ok(data_day_24) = read_data::<String, _>("data/day24.txt") {
-        let day_24_task_1 = aoc2020::day_24::task_1(&data_day_24);
-        println!("\nAOC 2020, day 24, task 1: {}", day_24_task_1);
-        assert_eq!(day_24_task_1, 479);
+    if let Some(path) = &cli.save_baseline {
+        if let Err(err) = measured.write(path) {
+            eprintln!("error: unable to save the baseline: {}", err);
+            errors += 1;
+        }
+    }
 
-        let day_24_task_2 = aoc2020::day_24::task_2(&data_day_24);
-        println!("AOC 2020, day 24, task 2: {}", day_24_task_2);
-        assert_eq!(day_24_task_2, 4135);
-    } else {
-        eprintln!("AOC 2020, day 24: Unable to read data for day 24. Skipping.");
+    let written = match cli.format {
+        Format::Text => Ok(()),
+        Format::Json => report::write_json(std::io::stdout().lock(), &records),
+        Format::Csv => report::write_csv(std::io::stdout().lock(), &records),
+    };
+    if let Err(err) = written {
+        eprintln!("error: unable to write the results: {}", err);
+        errors += 1;
     }
 
-    // Day 25
-    if let Ok(data_day_25) = read_data::<usize, _>("data/day25.txt") {
-        let day_25_task_1 = aoc2020::day_25::task_1(&data_day_25);
-        println!("\nAOC 2020, day 25, task 1: {}", day_25_task_1);
-        assert_eq!(day_25_task_1, 18329280);
-    } else {
-        eprintln!("AOC 2020, day 25: Unable to read data for day 25. Skipping.");
+    if headers {
+        print_summary(&rows, runs.len() > 1);
+        println!(
+            "\n{} passed, {} failed, {} unknown, {} errors",
+            passed, failed, unknown, errors
+        );
+    }
+    if baseline.is_some() && text {
+        println!("{} regressions", regressions);
+    }
+    if failed > 0 || errors > 0 || regressions > 0 {
+        std::process::exit(1);
     }
 }
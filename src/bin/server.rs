@@ -0,0 +1,88 @@
+use aoc_core::serve::{Limits, Service};
+use aoc_core::Year;
+use clap::Parser;
+use std::time::Duration;
+use tiny_http::Server;
+
+/// The years whose solutions can be served.
+static YEARS: &[&Year] = &[&aoc2020::YEAR];
+
+/// Serve the solutions to the Advent of Code challenges over HTTP.
+///
+/// The puzzle input is posted as the body of `POST /day/{n}/part/{p}`, and
+/// the answer is returned as JSON along with the time spent on parsing and
+/// solving, or the error if there is none.
+#[derive(Debug, Parser)]
+#[command(name = "aoc-server", version)]
+struct Cli {
+    /// The address to listen on.
+    #[arg(
+        short,
+        long,
+        value_name = "HOST:PORT",
+        default_value = "127.0.0.1:2020"
+    )]
+    address: String,
+
+    /// The year of the challenges. Defaults to the latest year with solutions.
+    #[arg(short, long)]
+    year: Option<u32>,
+
+    /// Refuse puzzle inputs larger than this number of bytes.
+    #[arg(long, value_name = "BYTES", default_value_t = Limits::default().max_input)]
+    max_input: usize,
+
+    /// Give up on parts that do not finish within this number of seconds.
+    #[arg(short, long, value_name = "SECONDS",
+          default_value_t = Limits::default().timeout.as_secs_f64())]
+    timeout: f64,
+
+    /// The number of requests to answer in parallel.
+    #[arg(short, long, value_name = "N", default_value_t = 4,
+          value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+
+    /// Refuse requests while this number of parts is running, including the
+    /// ones that timed out but did not finish yet.
+    #[arg(long, value_name = "N", default_value_t = Limits::default().max_solvers as u32,
+          value_parser = clap::value_parser!(u32).range(1..))]
+    max_solvers: u32,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let year = match cli.year {
+        Some(year) => match YEARS.iter().find(|y| y.year == year) {
+            Some(year) => year,
+            None => {
+                eprintln!("error: there are no solutions for {}", year);
+                std::process::exit(2);
+            }
+        },
+        None => YEARS.iter().max_by_key(|y| y.year).unwrap(),
+    };
+    let limits = Limits {
+        max_input: cli.max_input,
+        timeout: match Duration::try_from_secs_f64(cli.timeout) {
+            Ok(timeout) => timeout,
+            Err(err) => {
+                eprintln!("error: `{}` is not a timeout: {}", cli.timeout, err);
+                std::process::exit(2);
+            }
+        },
+        max_solvers: cli.max_solvers as usize,
+    };
+    let server = match Server::http(&cli.address) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("error: cannot listen on {}: {}", cli.address, err);
+            std::process::exit(1);
+        }
+    };
+    eprintln!(
+        "Serving the solutions of {} on http://{}",
+        year.year,
+        server.server_addr()
+    );
+    Service::new(year, limits).serve(&server, cli.jobs as usize);
+}